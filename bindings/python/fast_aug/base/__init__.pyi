@@ -11,6 +11,27 @@ class BaseAugmenter:
     def __init__(self) -> None:
         pass
 
+    @staticmethod
+    def from_config(config: dict[str, Any] | str, seed: int | None = None):
+        """
+        Create augmenter (or pipeline of augmenters) from the config
+        "Record" config creates augmenter of dict records, e.g. {"type": "Record", "fields": {"text": {...}}}
+        :param config: Config as dict or json/yaml string, e.g. {"type": "WordsRandomSwap", "word_params": 0.3}
+        :param seed: Optional seed for the random generator, for reproducible augmentation
+        :returns: Augmenter
+        """
+        pass
+
+    @staticmethod
+    def from_config_file(path: str, seed: int | None = None):
+        """
+        Create augmenter (or pipeline of augmenters) from the json or yaml config file
+        :param path: Path to the config file, `.yaml` and `.yml` are loaded as yaml, others as json
+        :param seed: Optional seed for the random generator, for reproducible augmentation
+        :returns: Augmenter
+        """
+        pass
+
     def augment(self, data: Any):
         """
         Augment data
@@ -19,10 +40,40 @@ class BaseAugmenter:
         """
         pass
 
+    def augment_with_changes(self, data: Any):
+        """
+        Augment data and get the log of changes made by augmenters, e.g. to debug pipelines
+        :param data: Data to augment - single data point
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
     def augment_batch(self, data: list[Any]):
         """
-        Augment data given a batch of data
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
         :param data: Data to augment - vector of data points
         :returns: Augmented data
         """
         pass
+
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
@@ -5,6 +5,7 @@ from .. import flow
 ChanceAugmenter = flow.ChanceAugmenter
 SelectorAugmenter = flow.SelectorAugmenter
 SequentialAugmenter = flow.SequentialAugmenter
+RecordAugmenter = flow.RecordAugmenter
 
 __all__ = flow.__all__
 __doc__ = flow.__doc__
@@ -10,9 +10,11 @@ class ChanceAugmenter(BaseAugmenter):
     Given other augmenter apply it with a given probability
     :param augmenter: The augmenter to apply with a given probability
     :param probability: The probability of applying the augmenter
+    :param seed: Optional seed for the random generator, for reproducible augmentation
+     - only the seed of the outermost augmenter is used, inner augmenters share its random generator
     """
 
-    def __init__(self, augmenter: BaseAugmenter, probability: float) -> None:
+    def __init__(self, augmenter: BaseAugmenter, probability: float, seed: int | None = None) -> None:
         pass
 
     def augment(self, data: Any):
@@ -23,22 +25,59 @@ class ChanceAugmenter(BaseAugmenter):
         """
         pass
 
+    def augment_with_changes(self, data: Any):
+        """
+        Augment data and get the log of changes made by augmenters, e.g. to debug pipelines
+        :param data: Data to augment - single data point
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
     def augment_batch(self, data: list[Any]):
         """
-        Augment data given a batch of data
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
         :param data: Data to augment - vector of data points
         :returns: Augmented data
         """
         pass
 
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
 class SelectorAugmenter(BaseAugmenter):
     """
     Given a list of augmenters, apply one of them randomly
     :param augmenters: The list of augmenters to choose from
     :param weights: Optional weights for each augmenter
+    :param seed: Optional seed for the random generator, for reproducible augmentation
+     - only the seed of the outermost augmenter is used, inner augmenters share its random generator
     """
 
-    def __init__(self, augmenters: list[BaseAugmenter], weights: list[float] | None = None) -> None:
+    def __init__(
+        self,
+        augmenters: list[BaseAugmenter],
+        weights: list[float] | None = None,
+        seed: int | None = None,
+    ) -> None:
         pass
 
     def augment(self, data: Any):
@@ -49,21 +88,120 @@ class SelectorAugmenter(BaseAugmenter):
         """
         pass
 
+    def augment_with_changes(self, data: Any):
+        """
+        Augment data and get the log of changes made by augmenters, e.g. to debug pipelines
+        :param data: Data to augment - single data point
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
     def augment_batch(self, data: list[Any]):
         """
-        Augment data given a batch of data
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
         :param data: Data to augment - vector of data points
         :returns: Augmented data
         """
         pass
 
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
 class SequentialAugmenter(BaseAugmenter):
     """
     Given a list of augmenters, apply them sequentially
     :param augmenters: The list of augmenters to apply sequentially
+    :param seed: Optional seed for the random generator, for reproducible augmentation
+     - only the seed of the outermost augmenter is used, inner augmenters share its random generator
+    """
+
+    def __init__(self, augmenters: list[BaseAugmenter], seed: int | None = None) -> None:
+        pass
+
+    def augment(self, data: Any):
+        """
+        Augment data
+        :param data: Data to augment - single data point
+        :returns: Augmented data
+        """
+        pass
+
+    def augment_with_changes(self, data: Any):
+        """
+        Augment data and get the log of changes made by augmenters, e.g. to debug pipelines
+        :param data: Data to augment - single data point
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
+    def augment_batch(self, data: list[Any]):
+        """
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
+        :param data: Data to augment - vector of data points
+        :returns: Augmented data
+        """
+        pass
+
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
+class RecordAugmenter(BaseAugmenter):
+    """
+    Given a dict of field names and augmenters, augment records (dicts) field by field, e.g. premise and hypothesis
+    Accepts a dict, a batch as dict of lists (`datasets.map(batched=True)`) or a list of dicts (`augment_batch`)
+    Other fields of the records are kept as is
+    :param fields: Dict of field names and text augmenters to apply to the fields, applied in the dict order
+    :param shared_seed: Augment all fields of a record with the same random seed, e.g. apply ChanceAugmenter to all fields or to none
+    :param consistent_substitutions: Words changed in-place in a field are changed the same way in the following fields
+    :param seed: Optional seed for the random generator, for reproducible augmentation
+     - only the seed of the outermost augmenter is used, inner augmenters share its random generator
     """
 
-    def __init__(self, augmenters: list[BaseAugmenter]) -> None:
+    def __init__(
+        self,
+        fields: dict[str, BaseAugmenter],
+        shared_seed: bool = False,
+        consistent_substitutions: bool = False,
+        seed: int | None = None,
+    ) -> None:
         pass
 
     def augment(self, data: Any):
@@ -74,10 +212,40 @@ class SequentialAugmenter(BaseAugmenter):
         """
         pass
 
+    def augment_with_changes(self, data: Any):
+        """
+        Augment data and get the log of changes made by augmenters, e.g. to debug pipelines
+        :param data: Data to augment - single data point
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
     def augment_batch(self, data: list[Any]):
         """
-        Augment data given a batch of data
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
         :param data: Data to augment - vector of data points
         :returns: Augmented data
         """
         pass
+
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
@@ -7,6 +7,10 @@ CharsRandomDeleteAugmenter = text.CharsRandomDeleteAugmenter
 CharsRandomInsertAugmenter = text.CharsRandomInsertAugmenter
 CharsRandomSubstituteAugmenter = text.CharsRandomSubstituteAugmenter
 CharsRandomSwapAugmenter = text.CharsRandomSwapAugmenter
+PunctuationAugmenter = text.PunctuationAugmenter
+SentencesRandomCropAugmenter = text.SentencesRandomCropAugmenter
+SentencesRandomDeleteAugmenter = text.SentencesRandomDeleteAugmenter
+SentencesRandomSwapAugmenter = text.SentencesRandomSwapAugmenter
 WordsRandomDeleteAugmenter = text.WordsRandomDeleteAugmenter
 WordsRandomInsertAugmenter = text.WordsRandomInsertAugmenter
 WordsRandomSubstituteAugmenter = text.WordsRandomSubstituteAugmenter
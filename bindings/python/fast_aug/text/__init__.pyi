@@ -1,6 +1,8 @@
 # Generated content DO NOT EDIT
 from __future__ import annotations
 
+from typing import Literal
+
 from ..base import BaseAugmenter
 
 class BaseTextAugmenter(BaseAugmenter):
@@ -19,14 +21,44 @@ class BaseTextAugmenter(BaseAugmenter):
         """
         pass
 
+    def augment_with_changes(self, data: str):
+        """
+        Augment the data and get the log of changes made by augmenters
+        :param data: A String to augment
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
     def augment_batch(self, data: list[str]):
         """
-        Augment data given a batch of data
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
         :param data: Vector of strings to augment
         :returns: Augmented data
         """
         pass
 
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
 class CharsRandomDeleteAugmenter(BaseTextAugmenter):
     """
     Randomly delete chars in the random words
@@ -36,6 +68,7 @@ class CharsRandomDeleteAugmenter(BaseTextAugmenter):
     :param char_params: The parameters for the char augmentation
      - probability or (probability, min_elements, max_elements)
     :param stopwords: Optional set of stopwords to ignore
+    :param seed: Optional seed for the random generator, for reproducible augmentation
     """
 
     def __init__(
@@ -43,6 +76,7 @@ class CharsRandomDeleteAugmenter(BaseTextAugmenter):
         word_params: float | tuple[float, int | None, int | None],
         char_params: float | tuple[float, int | None, int | None],
         stopwords: set[str] | None = None,
+        seed: int | None = None,
     ) -> None:
         pass
 
@@ -54,14 +88,44 @@ class CharsRandomDeleteAugmenter(BaseTextAugmenter):
         """
         pass
 
+    def augment_with_changes(self, data: str):
+        """
+        Augment the data and get the log of changes made by augmenters
+        :param data: A String to augment
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
     def augment_batch(self, data: list[str]):
         """
-        Augment data given a batch of data
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
         :param data: Vector of strings to augment
         :returns: Augmented data
         """
         pass
 
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
 class CharsRandomInsertAugmenter(BaseTextAugmenter):
     """
     Randomly augment chars in the random words
@@ -72,6 +136,7 @@ class CharsRandomInsertAugmenter(BaseTextAugmenter):
         - probability or (probability, min_elements, max_elements)
     :param locale: The locale string to use for alphabet
     :param stopwords: Optional set of stopwords to ignore
+    :param seed: Optional seed for the random generator, for reproducible augmentation
     """
 
     def __init__(
@@ -80,6 +145,7 @@ class CharsRandomInsertAugmenter(BaseTextAugmenter):
         char_params: float | tuple[float, int | None, int | None],
         locale: str,
         stopwords: set[str] | None = None,
+        seed: int | None = None,
     ) -> None:
         pass
 
@@ -91,14 +157,44 @@ class CharsRandomInsertAugmenter(BaseTextAugmenter):
         """
         pass
 
+    def augment_with_changes(self, data: str):
+        """
+        Augment the data and get the log of changes made by augmenters
+        :param data: A String to augment
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
     def augment_batch(self, data: list[str]):
         """
-        Augment data given a batch of data
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
         :param data: Vector of strings to augment
         :returns: Augmented data
         """
         pass
 
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
 class CharsRandomSubstituteAugmenter(BaseTextAugmenter):
     """
     Randomly substitute chars in the random words
@@ -109,6 +205,7 @@ class CharsRandomSubstituteAugmenter(BaseTextAugmenter):
       - probability or (probability, min_elements, max_elements)
     :param locale: The locale string to use for alphabet
     :param stopwords: Optional set of stopwords to ignore
+    :param seed: Optional seed for the random generator, for reproducible augmentation
     """
 
     def __init__(
@@ -117,6 +214,7 @@ class CharsRandomSubstituteAugmenter(BaseTextAugmenter):
         char_params: float | tuple[float, int | None, int | None],
         locale: str,
         stopwords: set[str] | None = None,
+        seed: int | None = None,
     ) -> None:
         pass
 
@@ -128,14 +226,44 @@ class CharsRandomSubstituteAugmenter(BaseTextAugmenter):
         """
         pass
 
+    def augment_with_changes(self, data: str):
+        """
+        Augment the data and get the log of changes made by augmenters
+        :param data: A String to augment
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
     def augment_batch(self, data: list[str]):
         """
-        Augment data given a batch of data
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
         :param data: Vector of strings to augment
         :returns: Augmented data
         """
         pass
 
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
 class CharsRandomSwapAugmenter(BaseTextAugmenter):
     """
     Randomly swap chars in the random words
@@ -145,6 +273,11 @@ class CharsRandomSwapAugmenter(BaseTextAugmenter):
     :param char_params: The parameters for the char augmentation
      - probability or (probability, min_elements, max_elements)
     :param stopwords: Optional set of stopwords to ignore
+    :param seed: Optional seed for the random generator, for reproducible augmentation
+    :param swap_mode: How chars are swapped, one of
+      - "random" - swap pairs of random chars
+      - "adjacent" - swap random chars with their right neighbours
+      - "middle" - swap pairs of random chars, keeping the first and the last char in place
     """
 
     def __init__(
@@ -152,6 +285,263 @@ class CharsRandomSwapAugmenter(BaseTextAugmenter):
         word_params: float | tuple[float, int | None, int | None],
         char_params: float | tuple[float, int | None, int | None],
         stopwords: set[str] | None = None,
+        seed: int | None = None,
+        swap_mode: Literal["random", "adjacent", "middle"] = "random",
+    ) -> None:
+        pass
+
+    def augment(self, data: str):
+        """
+        Augment the data
+        :param data: A String to augment
+        :returns: Augmented data
+        """
+        pass
+
+    def augment_with_changes(self, data: str):
+        """
+        Augment the data and get the log of changes made by augmenters
+        :param data: A String to augment
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
+    def augment_batch(self, data: list[str]):
+        """
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
+        :param data: Vector of strings to augment
+        :returns: Augmented data
+        """
+        pass
+
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
+class PunctuationAugmenter(BaseTextAugmenter):
+    """
+    Insert, delete or substitute punctuation marks of the locale, e.g. ". , ; : ! ?" for "en"
+
+    :param action: The action to apply, one of
+      - "insert" - insert random marks before random words (AEDA), number of marks is calculated from the number of words
+      - "delete" - delete random punctuation marks
+      - "substitute" - substitute random punctuation marks with other marks
+    :param punctuation_params: The parameters for the punctuation augmentation
+      - probability or (probability, min_elements, max_elements)
+    :param locale: The locale string to use for punctuation marks
+    :param seed: Optional seed for the random generator, for reproducible augmentation
+    """
+
+    def __init__(
+        self,
+        action: Literal["insert", "delete", "substitute"],
+        punctuation_params: float | tuple[float, int | None, int | None],
+        locale: str = "en",
+        seed: int | None = None,
+    ) -> None:
+        pass
+
+    def augment(self, data: str):
+        """
+        Augment the data
+        :param data: A String to augment
+        :returns: Augmented data
+        """
+        pass
+
+    def augment_with_changes(self, data: str):
+        """
+        Augment the data and get the log of changes made by augmenters
+        :param data: A String to augment
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
+    def augment_batch(self, data: list[str]):
+        """
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
+        :param data: Vector of strings to augment
+        :returns: Augmented data
+        """
+        pass
+
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
+class SentencesRandomCropAugmenter(BaseTextAugmenter):
+    """
+    Crop text to a random window of consecutive sentences, other sentences are deleted
+
+    :param sentence_params: The parameters for the number of sentences in the window (to keep), at least one sentence is kept
+      - probability or (probability, min_elements, max_elements)
+    :param seed: Optional seed for the random generator, for reproducible augmentation
+    """
+
+    def __init__(
+        self,
+        sentence_params: float | tuple[float, int | None, int | None],
+        seed: int | None = None,
+    ) -> None:
+        pass
+
+    def augment(self, data: str):
+        """
+        Augment the data
+        :param data: A String to augment
+        :returns: Augmented data
+        """
+        pass
+
+    def augment_with_changes(self, data: str):
+        """
+        Augment the data and get the log of changes made by augmenters
+        :param data: A String to augment
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
+    def augment_batch(self, data: list[str]):
+        """
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
+        :param data: Vector of strings to augment
+        :returns: Augmented data
+        """
+        pass
+
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
+class SentencesRandomDeleteAugmenter(BaseTextAugmenter):
+    """
+    Randomly delete sentences
+
+    :param sentence_params: The parameters for the sentence augmentation
+      - probability or (probability, min_elements, max_elements)
+    :param seed: Optional seed for the random generator, for reproducible augmentation
+    """
+
+    def __init__(
+        self,
+        sentence_params: float | tuple[float, int | None, int | None],
+        seed: int | None = None,
+    ) -> None:
+        pass
+
+    def augment(self, data: str):
+        """
+        Augment the data
+        :param data: A String to augment
+        :returns: Augmented data
+        """
+        pass
+
+    def augment_with_changes(self, data: str):
+        """
+        Augment the data and get the log of changes made by augmenters
+        :param data: A String to augment
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
+    def augment_batch(self, data: list[str]):
+        """
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
+        :param data: Vector of strings to augment
+        :returns: Augmented data
+        """
+        pass
+
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
+class SentencesRandomSwapAugmenter(BaseTextAugmenter):
+    """
+    Randomly swap sentences, whitespace between sentences is kept in place
+
+    :param sentence_params: The parameters for the sentence augmentation
+      - probability or (probability, min_elements, max_elements)
+    :param seed: Optional seed for the random generator, for reproducible augmentation
+    """
+
+    def __init__(
+        self,
+        sentence_params: float | tuple[float, int | None, int | None],
+        seed: int | None = None,
     ) -> None:
         pass
 
@@ -163,14 +553,44 @@ class CharsRandomSwapAugmenter(BaseTextAugmenter):
         """
         pass
 
+    def augment_with_changes(self, data: str):
+        """
+        Augment the data and get the log of changes made by augmenters
+        :param data: A String to augment
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
     def augment_batch(self, data: list[str]):
         """
-        Augment data given a batch of data
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
         :param data: Vector of strings to augment
         :returns: Augmented data
         """
         pass
 
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
 class WordsRandomDeleteAugmenter(BaseTextAugmenter):
     """
     Randomly delete words
@@ -178,10 +598,14 @@ class WordsRandomDeleteAugmenter(BaseTextAugmenter):
     :param word_params: The parameters for the word augmentation
       - probability or (probability, min_elements, max_elements)
     :param stopwords: Optional set of stopwords to ignore
+    :param seed: Optional seed for the random generator, for reproducible augmentation
     """
 
     def __init__(
-        self, word_params: float | tuple[float, int | None, int | None], stopwords: set[str] | None = None
+        self,
+        word_params: float | tuple[float, int | None, int | None],
+        stopwords: set[str] | None = None,
+        seed: int | None = None,
     ) -> None:
         pass
 
@@ -193,14 +617,44 @@ class WordsRandomDeleteAugmenter(BaseTextAugmenter):
         """
         pass
 
+    def augment_with_changes(self, data: str):
+        """
+        Augment the data and get the log of changes made by augmenters
+        :param data: A String to augment
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
     def augment_batch(self, data: list[str]):
         """
-        Augment data given a batch of data
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
         :param data: Vector of strings to augment
         :returns: Augmented data
         """
         pass
 
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
 class WordsRandomInsertAugmenter(BaseTextAugmenter):
     """
     Randomly insert words from the given vocabulary
@@ -209,6 +663,7 @@ class WordsRandomInsertAugmenter(BaseTextAugmenter):
         - probability or (probability, min_elements, max_elements)
     :param vocabulary: The vocabulary to use for insertion
     :param stopwords: Optional set of stopwords to ignore
+    :param seed: Optional seed for the random generator, for reproducible augmentation
     """
 
     def __init__(
@@ -216,6 +671,7 @@ class WordsRandomInsertAugmenter(BaseTextAugmenter):
         word_params: float | tuple[float, int | None, int | None],
         vocabulary: list[str],
         stopwords: set[str] | None = None,
+        seed: int | None = None,
     ) -> None:
         pass
 
@@ -227,14 +683,44 @@ class WordsRandomInsertAugmenter(BaseTextAugmenter):
         """
         pass
 
+    def augment_with_changes(self, data: str):
+        """
+        Augment the data and get the log of changes made by augmenters
+        :param data: A String to augment
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
     def augment_batch(self, data: list[str]):
         """
-        Augment data given a batch of data
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
         :param data: Vector of strings to augment
         :returns: Augmented data
         """
         pass
 
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
 class WordsRandomSubstituteAugmenter(BaseTextAugmenter):
     """
     Randomly substitute words from the given vocabulary
@@ -243,6 +729,7 @@ class WordsRandomSubstituteAugmenter(BaseTextAugmenter):
        - probability or (probability, min_elements, max_elements)
     :param vocabulary: The vocabulary to use for substitution
     :param stopwords: Optional set of stopwords to ignore
+    :param seed: Optional seed for the random generator, for reproducible augmentation
     """
 
     def __init__(
@@ -250,6 +737,7 @@ class WordsRandomSubstituteAugmenter(BaseTextAugmenter):
         word_params: float | tuple[float, int | None, int | None],
         vocabulary: list[str],
         stopwords: set[str] | None = None,
+        seed: int | None = None,
     ) -> None:
         pass
 
@@ -261,14 +749,44 @@ class WordsRandomSubstituteAugmenter(BaseTextAugmenter):
         """
         pass
 
+    def augment_with_changes(self, data: str):
+        """
+        Augment the data and get the log of changes made by augmenters
+        :param data: A String to augment
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
     def augment_batch(self, data: list[str]):
         """
-        Augment data given a batch of data
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
         :param data: Vector of strings to augment
         :returns: Augmented data
         """
         pass
 
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
+
 class WordsRandomSwapAugmenter(BaseTextAugmenter):
     """
     Randomly swap words
@@ -276,10 +794,14 @@ class WordsRandomSwapAugmenter(BaseTextAugmenter):
     :param word_params: The parameters for the word augmentation
        - probability or (probability, min_elements, max_elements)
     :param stopwords: Optional set of stopwords to ignore
+    :param seed: Optional seed for the random generator, for reproducible augmentation
     """
 
     def __init__(
-        self, word_params: float | tuple[float, int | None, int | None], stopwords: set[str] | None = None
+        self,
+        word_params: float | tuple[float, int | None, int | None],
+        stopwords: set[str] | None = None,
+        seed: int | None = None,
     ) -> None:
         pass
 
@@ -291,10 +813,40 @@ class WordsRandomSwapAugmenter(BaseTextAugmenter):
         """
         pass
 
+    def augment_with_changes(self, data: str):
+        """
+        Augment the data and get the log of changes made by augmenters
+        :param data: A String to augment
+        :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+        """
+        pass
+
     def augment_batch(self, data: list[str]):
         """
-        Augment data given a batch of data
+        Augment data given a batch of data, in parallel with GIL released
+        Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
         :param data: Vector of strings to augment
         :returns: Augmented data
         """
         pass
+
+    def set_seed(self, seed: int | None = None):
+        """
+        Reset the random generator of the augmenter
+        :param seed: Seed for the random generator, if None - seeded from OS entropy
+        """
+        pass
+
+    def get_rng_state(self):
+        """
+        Get the state of the random generator, e.g. to pickle it in a data-loader worker
+        :returns: Random generator state as bytes, to restore with `set_rng_state`
+        """
+        pass
+
+    def set_rng_state(self, state: bytes):
+        """
+        Restore the state of the random generator, augmentation continues where the state was saved
+        :param state: Random generator state as bytes, returned by `get_rng_state`
+        """
+        pass
@@ -1,18 +1,26 @@
 use std::sync::Arc;
 
-use fast_aug_rust::text::Doc;
+use fast_aug_rust::config::AugmenterConfig;
+use fast_aug_rust::flow::{Record, RecordAugmenter};
+use fast_aug_rust::text::{AugmentWithChanges, Doc};
 use fast_aug_rust::BaseAugmenter;
 use pyo3::exceptions::PyNotImplementedError;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyList};
+use pyo3::types::{PyAny, PyBytes, PyDict, PyList, PyString};
+use pyo3::IntoPyObjectExt;
 
-use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::errors::to_py_err;
 
 #[derive(Clone)]
 #[allow(dead_code)]
 pub(crate) enum AugmenterTypes {
     Int(Arc<dyn BaseAugmenter<i32, i32> + Send + Sync>),
     Text(Arc<dyn BaseAugmenter<String, Doc> + Send + Sync>),
+    Record(Arc<RecordAugmenter>),
 }
 
 /// Abstract Base Class for Augmentation
@@ -21,7 +29,19 @@ pub(crate) enum AugmenterTypes {
 #[derive(Clone)]
 pub struct PyBaseAugmenter {
     pub(crate) inner: AugmenterTypes,
-    pub(crate) rng: SmallRng,
+    pub(crate) rng: ChaCha8Rng,
+}
+
+/// Size of the serialized rng state: seed (32 bytes), stream (u64) and word position (u128)
+const RNG_STATE_SIZE: usize = 32 + 8 + 16;
+
+/// Create augmenter rng from the seed, or from OS entropy if seed is not given
+/// ChaCha is used (not SmallRng) as its position can be saved and restored
+pub(crate) fn rng_from_seed(seed: Option<u64>) -> ChaCha8Rng {
+    match seed {
+        Some(seed) => ChaCha8Rng::seed_from_u64(seed),
+        None => ChaCha8Rng::from_entropy(),
+    }
 }
 
 #[pymethods]
@@ -32,6 +52,75 @@ impl PyBaseAugmenter {
         Err(PyNotImplementedError::new_err("Not implemented"))
     }
 
+    /// Create augmenter (or pipeline of augmenters) from the config
+    /// "Record" config creates augmenter of dict records, e.g. {"type": "Record", "fields": {"text": {...}}}
+    /// :param config: Config as dict or json/yaml string, e.g. {"type": "WordsRandomSwap", "word_params": 0.3}
+    /// :param seed: Optional seed for the random generator, for reproducible augmentation
+    /// :returns: Augmenter
+    #[staticmethod]
+    #[pyo3(
+        signature = (config, seed=None),
+        text_signature = "(config: dict[str, Any] | str, seed: int | None = None)"
+    )]
+    pub fn from_config(py: Python, config: &Bound<'_, PyAny>, seed: Option<u64>) -> PyResult<Self> {
+        // Dicts are converted to json, strings are parsed as yaml (superset of json)
+        let config = if config.is_instance_of::<PyString>() {
+            AugmenterConfig::from_yaml_str(&config.extract::<String>()?)
+        } else {
+            let json = py.import("json")?.call_method1("dumps", (config,))?;
+            AugmenterConfig::from_json_str(&json.extract::<String>()?)
+        };
+        Self::build_from_config(config.map_err(to_py_err)?, seed)
+    }
+
+    /// Create augmenter (or pipeline of augmenters) from the json or yaml config file
+    /// :param path: Path to the config file, `.yaml` and `.yml` are loaded as yaml, others as json
+    /// :param seed: Optional seed for the random generator, for reproducible augmentation
+    /// :returns: Augmenter
+    #[staticmethod]
+    #[pyo3(signature = (path, seed=None), text_signature = "(path: str, seed: int | None = None)")]
+    pub fn from_config_file(path: &str, seed: Option<u64>) -> PyResult<Self> {
+        Self::build_from_config(AugmenterConfig::from_file(path).map_err(to_py_err)?, seed)
+    }
+
+    /// Reset the random generator of the augmenter
+    /// :param seed: Seed for the random generator, if None - seeded from OS entropy
+    #[pyo3(signature = (seed=None), text_signature = "(self, seed: int | None = None)")]
+    pub fn set_seed(&mut self, seed: Option<u64>) {
+        self.rng = rng_from_seed(seed);
+    }
+
+    /// Get the state of the random generator, e.g. to pickle it in a data-loader worker
+    /// :returns: Random generator state as bytes, to restore with `set_rng_state`
+    #[pyo3(text_signature = "(self)")]
+    pub fn get_rng_state<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let mut state = Vec::with_capacity(RNG_STATE_SIZE);
+        state.extend_from_slice(&self.rng.get_seed());
+        state.extend_from_slice(&self.rng.get_stream().to_le_bytes());
+        state.extend_from_slice(&self.rng.get_word_pos().to_le_bytes());
+        PyBytes::new(py, &state)
+    }
+
+    /// Restore the state of the random generator, augmentation continues where the state was saved
+    /// :param state: Random generator state as bytes, returned by `get_rng_state`
+    #[pyo3(text_signature = "(self, state: bytes)")]
+    pub fn set_rng_state(&mut self, state: &[u8]) -> PyResult<()> {
+        if state.len() != RNG_STATE_SIZE {
+            return Err(PyValueError::new_err(format!(
+                "rng state must be {} bytes, got {}",
+                RNG_STATE_SIZE,
+                state.len()
+            )));
+        }
+        let (seed, rest) = state.split_at(32);
+        let (stream, word_pos) = rest.split_at(8);
+        let mut rng = ChaCha8Rng::from_seed(seed.try_into().unwrap());
+        rng.set_stream(u64::from_le_bytes(stream.try_into().unwrap()));
+        rng.set_word_pos(u128::from_le_bytes(word_pos.try_into().unwrap()));
+        self.rng = rng;
+        Ok(())
+    }
+
     /// Augment data
     /// :param data: Data to augment - single data point
     /// :returns: Augmented data
@@ -41,16 +130,52 @@ impl PyBaseAugmenter {
         match &self.inner {
             // String input
             AugmenterTypes::Text(augmenter) => {
-                let data = data.extract::<String>().unwrap();
+                let data = data.extract::<String>()?;
                 let augmented_data = augmenter.augment(data, &mut self.rng);
-                Ok(augmented_data.into_py(py))
+                augmented_data.into_py_any(py)
+            }
+            // Dict input - single record or batch of records as dict of lists (e.g. `datasets.map(batched=True)`)
+            AugmenterTypes::Record(augmenter) => {
+                let data = data.downcast::<PyDict>()?;
+                if is_batched_record(augmenter, data)? {
+                    let records = records_from_batch(augmenter, data)?;
+                    let seed = self.rng.gen::<u64>();
+                    let augmented_data = py.allow_threads(|| augmenter.augment_batch_par(records, seed));
+                    batch_from_records(augmenter, data, augmented_data)
+                } else {
+                    let record = record_from_dict(augmenter, data)?;
+                    let augmented_data = augmenter.augment(record, &mut self.rng);
+                    dict_from_record(data, augmented_data)
+                }
             }
             // Not implemented for other types
             _ => Err(PyNotImplementedError::new_err("Not implemented")),
         }
     }
 
-    /// Augment data given a batch of data
+    /// Augment data and get the log of changes made by augmenters, e.g. to debug pipelines
+    /// :param data: Data to augment - single data point
+    /// :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+    #[pyo3(text_signature = "(self, data: Any)")]
+    pub fn augment_with_changes(&mut self, py: Python, data: &Bound<'_, PyAny>) -> PyResult<(PyObject, PyObject)> {
+        match &self.inner {
+            // String input
+            AugmenterTypes::Text(augmenter) => {
+                let data = data.extract::<String>()?;
+                let (augmented_data, changes) = augmenter.augment_with_changes(data, &mut self.rng);
+                // Changes are passed as json to get plain python dicts
+                let changes =
+                    serde_json::to_string(&changes).map_err(|error| PyValueError::new_err(error.to_string()))?;
+                let changes = py.import("json")?.call_method1("loads", (changes,))?;
+                Ok((augmented_data.into_py_any(py)?, changes.unbind()))
+            }
+            // Not implemented for other types
+            _ => Err(PyNotImplementedError::new_err("Not implemented")),
+        }
+    }
+
+    /// Augment data given a batch of data, in parallel with GIL released
+    /// Each data point uses own random stream seeded from the augmenter rng, so results do not depend on threads number
     /// :param data: Data to augment - vector of data points
     /// :returns: Augmented data
     #[pyo3(text_signature = "(self, data: list[Any])")]
@@ -61,10 +186,29 @@ impl PyBaseAugmenter {
             AugmenterTypes::Text(augmenter) => {
                 let data = data
                     .iter()
-                    .map(|x| x.extract::<String>().unwrap())
-                    .collect::<Vec<String>>();
-                let augmented_data = augmenter.augment_batch(data, &mut self.rng);
-                Ok(augmented_data.into_py(py))
+                    .map(|x| x.extract::<String>())
+                    .collect::<PyResult<Vec<String>>>()?;
+                let seed = self.rng.gen::<u64>();
+                let augmented_data = py.allow_threads(|| augmenter.augment_batch_par(data, seed));
+                augmented_data.into_py_any(py)
+            }
+            // List of dicts input
+            AugmenterTypes::Record(augmenter) => {
+                let data = data
+                    .iter()
+                    .map(|x| x.downcast_into::<PyDict>().map_err(PyErr::from))
+                    .collect::<PyResult<Vec<_>>>()?;
+                let records = data
+                    .iter()
+                    .map(|x| record_from_dict(augmenter, x))
+                    .collect::<PyResult<Vec<Record>>>()?;
+                let seed = self.rng.gen::<u64>();
+                let augmented_data = py.allow_threads(|| augmenter.augment_batch_par(records, seed));
+                data.iter()
+                    .zip(augmented_data)
+                    .map(|(x, record)| dict_from_record(x, record))
+                    .collect::<PyResult<Vec<_>>>()?
+                    .into_py_any(py)
             }
             // Not implemented for other types
             _ => Err(PyNotImplementedError::new_err("Not implemented")),
@@ -72,6 +216,102 @@ impl PyBaseAugmenter {
     }
 }
 
+impl PyBaseAugmenter {
+    /// Build text augmenter from the config, or record augmenter from the `Record` config
+    fn build_from_config(config: AugmenterConfig, seed: Option<u64>) -> PyResult<Self> {
+        let inner = match config {
+            AugmenterConfig::Record { .. } => {
+                AugmenterTypes::Record(Arc::new(config.build_record().map_err(to_py_err)?))
+            }
+            _ => AugmenterTypes::Text(config.build().map_err(to_py_err)?),
+        };
+        Ok(PyBaseAugmenter {
+            inner,
+            rng: rng_from_seed(seed),
+        })
+    }
+}
+
+/// Check if the dict is a batch of records, i.e. augmented fields are lists
+fn is_batched_record(augmenter: &RecordAugmenter, data: &Bound<'_, PyDict>) -> PyResult<bool> {
+    for field in augmenter.field_names() {
+        if let Some(value) = data.get_item(field)? {
+            return Ok(value.is_instance_of::<PyList>());
+        }
+    }
+    Ok(false)
+}
+
+/// Extract augmented fields of the dict, fields which are not strings (e.g. None) are left as is
+fn record_from_dict(augmenter: &RecordAugmenter, data: &Bound<'_, PyDict>) -> PyResult<Record> {
+    let mut record = Record::new();
+    for field in augmenter.field_names() {
+        if let Some(value) = data.get_item(field)? {
+            if let Ok(value) = value.extract::<String>() {
+                record.insert(field.clone(), value);
+            }
+        }
+    }
+    Ok(record)
+}
+
+/// Copy of the dict with augmented fields replaced
+fn dict_from_record(data: &Bound<'_, PyDict>, record: Record) -> PyResult<PyObject> {
+    let dict = data.copy()?;
+    for (field, value) in record {
+        dict.set_item(field, value)?;
+    }
+    Ok(dict.into_any().unbind())
+}
+
+/// Split dict of lists into records, all augmented fields must be lists of the same length
+fn records_from_batch(augmenter: &RecordAugmenter, data: &Bound<'_, PyDict>) -> PyResult<Vec<Record>> {
+    let mut records: Option<Vec<Record>> = None;
+    for field in augmenter.field_names() {
+        let values = match data.get_item(field)? {
+            Some(values) => values.extract::<Vec<Option<String>>>()?,
+            None => continue,
+        };
+        let records = records.get_or_insert_with(|| vec![Record::new(); values.len()]);
+        if records.len() != values.len() {
+            return Err(PyValueError::new_err(format!(
+                "all fields of the batch must have the same length, field '{}' has {} values, expected {}",
+                field,
+                values.len(),
+                records.len()
+            )));
+        }
+        for (record, value) in records.iter_mut().zip(values) {
+            if let Some(value) = value {
+                record.insert(field.clone(), value);
+            }
+        }
+    }
+    Ok(records.unwrap_or_default())
+}
+
+/// Copy of the dict of lists with augmented fields replaced
+fn batch_from_records(
+    augmenter: &RecordAugmenter,
+    data: &Bound<'_, PyDict>,
+    records: Vec<Record>,
+) -> PyResult<PyObject> {
+    let dict = data.copy()?;
+    for field in augmenter.field_names() {
+        let values = match data.get_item(field)? {
+            Some(values) => values.extract::<Vec<Option<String>>>()?,
+            None => continue,
+        };
+        let values = records
+            .iter()
+            .zip(values)
+            .map(|(record, value)| record.get(field).cloned().or(value))
+            .collect::<Vec<_>>();
+        dict.set_item(field, values)?;
+    }
+    Ok(dict.into_any().unbind())
+}
+
 /// Base Classes Module
 #[pymodule]
 pub fn base(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
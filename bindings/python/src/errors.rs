@@ -0,0 +1,13 @@
+use fast_aug_rust::Error;
+use pyo3::exceptions::{PyIOError, PyValueError};
+use pyo3::PyErr;
+
+/// Convert fast_aug Error to the respective Python exception
+/// Invalid arguments are raised as ValueError, model loading errors as OSError (or its subclasses)
+pub(crate) fn to_py_err(error: Error) -> PyErr {
+    match error {
+        Error::Io(error) => PyErr::from(error),
+        Error::Embeddings(_) => PyIOError::new_err(error.to_string()),
+        _ => PyValueError::new_err(error.to_string()),
+    }
+}
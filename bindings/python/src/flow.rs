@@ -1,18 +1,19 @@
-use fast_aug_rust::flow::{ChanceAugmenter, SelectorAugmenter, SequentialAugmenter};
+use fast_aug_rust::flow::{ChanceAugmenter, RecordAugmenter, SelectorAugmenter, SequentialAugmenter};
 use std::sync::Arc;
 
-use crate::base::{AugmenterTypes, PyBaseAugmenter};
+use crate::base::{rng_from_seed, AugmenterTypes, PyBaseAugmenter};
+use crate::errors::to_py_err;
 
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 
-use pyo3::types::PyList;
-use rand::rngs::SmallRng;
-use rand::SeedableRng;
+use pyo3::types::{PyDict, PyList};
 
 /// Given other augmenter apply it with a given probability
 /// :param augmenter: The augmenter to apply with a given probability
 /// :param probability: The probability of applying the augmenter
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
+///     - only the seed of the outermost augmenter is used, inner augmenters share its random generator
 #[pyclass(extends=PyBaseAugmenter)]
 #[pyo3(name = "ChanceAugmenter")]
 pub struct PyChanceAugmenter;
@@ -20,21 +21,19 @@ pub struct PyChanceAugmenter;
 #[pymethods]
 impl PyChanceAugmenter {
     #[new]
-    #[pyo3(text_signature = "(self, augmenter: BaseAugmenter, probability: float)")]
-    fn py_new(augmenter: &PyBaseAugmenter, probability: f64) -> PyResult<PyClassInitializer<Self>> {
-        let rng = SmallRng::from_entropy();
-
-        // Process parameters
-        if !(0.0..=1.0).contains(&probability) {
-            return Err(PyValueError::new_err("probability must be between 0 and 1"));
-        }
+    #[pyo3(
+        signature = (augmenter, probability, seed=None),
+        text_signature = "(self, augmenter: BaseAugmenter, probability: float, seed: int | None = None)"
+    )]
+    fn py_new(augmenter: &PyBaseAugmenter, probability: f64, seed: Option<u64>) -> PyResult<PyClassInitializer<Self>> {
+        let rng = rng_from_seed(seed);
 
         // Create Rust object of AugmenterTypes
         // TODO: other types than String
         let rust_augmenter = match &augmenter.inner {
-            AugmenterTypes::Text(augmenter) => {
-                AugmenterTypes::Text(Arc::new(ChanceAugmenter::new(augmenter.clone(), probability)))
-            }
+            AugmenterTypes::Text(augmenter) => AugmenterTypes::Text(Arc::new(
+                ChanceAugmenter::try_new(augmenter.clone(), probability).map_err(to_py_err)?,
+            )),
             _ => return Err(PyTypeError::new_err("augmenter must be a text augmenter")),
         };
 
@@ -50,6 +49,8 @@ impl PyChanceAugmenter {
 /// Given a list of augmenters, apply one of them randomly
 /// :param augmenters: The list of augmenters to choose from
 /// :param weights: Optional weights for each augmenter
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
+///     - only the seed of the outermost augmenter is used, inner augmenters share its random generator
 #[pyclass(extends=PyBaseAugmenter)]
 #[pyo3(name = "SelectorAugmenter")]
 pub struct PySelectorAugmenter;
@@ -58,21 +59,20 @@ pub struct PySelectorAugmenter;
 impl PySelectorAugmenter {
     #[new]
     #[pyo3(
-        signature = (augmenters, weights=None),
-        text_signature = "(self, augmenters: list[BaseAugmenter], weights: list[float] | None = None)"
+        signature = (augmenters, weights=None, seed=None),
+        text_signature = "(self, augmenters: list[BaseAugmenter], weights: list[float] | None = None, seed: int | None = None)"
     )]
-    fn py_new(augmenters: &Bound<'_, PyList>, weights: Option<Vec<f32>>) -> PyResult<PyClassInitializer<Self>> {
-        let rng = SmallRng::from_entropy();
+    fn py_new(
+        augmenters: &Bound<'_, PyList>,
+        weights: Option<Vec<f32>>,
+        seed: Option<u64>,
+    ) -> PyResult<PyClassInitializer<Self>> {
+        let rng = rng_from_seed(seed);
 
         // Process parameters
         if augmenters.len() <= 1 {
             return Err(PyValueError::new_err("augmenters must have at least 2 augmenters"));
         }
-        if let Some(weights) = &weights {
-            if weights.len() != augmenters.len() {
-                return Err(PyValueError::new_err("weights must be the same length as augmenters"));
-            }
-        }
         let augmenters = augmenters
             .iter()
             .map(|x| x.extract::<PyBaseAugmenter>())
@@ -89,11 +89,13 @@ impl PySelectorAugmenter {
                 let augmenters = augmenters
                     .into_iter()
                     .map(|x| match x.inner {
-                        AugmenterTypes::Text(augmenter) => augmenter,
-                        _ => panic!("Augmenter is not a TextAugmenter"),
+                        AugmenterTypes::Text(augmenter) => Ok(augmenter),
+                        _ => Err(PyTypeError::new_err("augmenters must be a list of text augmenters")),
                     })
-                    .collect::<Vec<_>>();
-                AugmenterTypes::Text(Arc::new(SelectorAugmenter::new(augmenters, weights)))
+                    .collect::<PyResult<Vec<_>>>()?;
+                AugmenterTypes::Text(Arc::new(
+                    SelectorAugmenter::try_new(augmenters, weights).map_err(to_py_err)?,
+                ))
             }
             _ => return Err(PyTypeError::new_err("augmenters must be a list of text augmenters")),
         };
@@ -109,6 +111,8 @@ impl PySelectorAugmenter {
 
 /// Given a list of augmenters, apply them sequentially
 /// :param augmenters: The list of augmenters to apply sequentially
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
+///     - only the seed of the outermost augmenter is used, inner augmenters share its random generator
 #[pyclass(extends=PyBaseAugmenter)]
 #[pyo3(name = "SequentialAugmenter")]
 pub struct PySequentialAugmenter;
@@ -116,9 +120,12 @@ pub struct PySequentialAugmenter;
 #[pymethods]
 impl PySequentialAugmenter {
     #[new]
-    #[pyo3(text_signature = "(self, augmenters: list[BaseAugmenter])")]
-    fn py_new(augmenters: &Bound<'_, PyList>) -> PyResult<PyClassInitializer<Self>> {
-        let rng = SmallRng::from_entropy();
+    #[pyo3(
+        signature = (augmenters, seed=None),
+        text_signature = "(self, augmenters: list[BaseAugmenter], seed: int | None = None)"
+    )]
+    fn py_new(augmenters: &Bound<'_, PyList>, seed: Option<u64>) -> PyResult<PyClassInitializer<Self>> {
+        let rng = rng_from_seed(seed);
 
         // Process parameters
         if augmenters.len() <= 1 {
@@ -139,11 +146,11 @@ impl PySequentialAugmenter {
                 let augmenters = augmenters
                     .into_iter()
                     .map(|x| match x.inner {
-                        AugmenterTypes::Text(augmenter) => augmenter,
-                        _ => panic!("Augmenter is not a TextAugmenter"),
+                        AugmenterTypes::Text(augmenter) => Ok(augmenter),
+                        _ => Err(PyTypeError::new_err("augmenters must be a list of text augmenters")),
                     })
-                    .collect::<Vec<_>>();
-                AugmenterTypes::Text(Arc::new(SequentialAugmenter::new(augmenters)))
+                    .collect::<PyResult<Vec<_>>>()?;
+                AugmenterTypes::Text(Arc::new(SequentialAugmenter::try_new(augmenters).map_err(to_py_err)?))
             }
             _ => return Err(PyTypeError::new_err("augmenters must be a list of text augmenters")),
         };
@@ -157,12 +164,71 @@ impl PySequentialAugmenter {
     }
 }
 
+/// Given a dict of field names and augmenters, augment records (dicts) field by field, e.g. premise and hypothesis
+/// Accepts a dict, a batch as dict of lists (`datasets.map(batched=True)`) or a list of dicts (`augment_batch`)
+/// Other fields of the records are kept as is
+/// :param fields: Dict of field names and text augmenters to apply to the fields, applied in the dict order
+/// :param shared_seed: Augment all fields of a record with the same random seed, e.g. apply ChanceAugmenter to all fields or to none
+/// :param consistent_substitutions: Words changed in-place in a field are changed the same way in the following fields
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
+///     - only the seed of the outermost augmenter is used, inner augmenters share its random generator
+#[pyclass(extends=PyBaseAugmenter)]
+#[pyo3(name = "RecordAugmenter")]
+pub struct PyRecordAugmenter;
+
+#[pymethods]
+impl PyRecordAugmenter {
+    #[new]
+    #[pyo3(
+        signature = (fields, shared_seed=false, consistent_substitutions=false, seed=None),
+        text_signature = "(self, fields: dict[str, BaseAugmenter], shared_seed: bool = False, consistent_substitutions: bool = False, seed: int | None = None)"
+    )]
+    fn py_new(
+        fields: &Bound<'_, PyDict>,
+        shared_seed: bool,
+        consistent_substitutions: bool,
+        seed: Option<u64>,
+    ) -> PyResult<PyClassInitializer<Self>> {
+        let rng = rng_from_seed(seed);
+
+        // Process parameters
+        let fields = fields
+            .iter()
+            .map(|(field, augmenter)| {
+                let field = field
+                    .extract::<String>()
+                    .map_err(|_| PyTypeError::new_err("fields must be a dict with str keys"))?;
+                let augmenter = augmenter
+                    .extract::<PyBaseAugmenter>()
+                    .map_err(|_| PyTypeError::new_err("fields must be a dict of BaseAugmenter"))?;
+                match augmenter.inner {
+                    AugmenterTypes::Text(augmenter) => Ok((field, augmenter)),
+                    _ => Err(PyTypeError::new_err("fields must be a dict of text augmenters")),
+                }
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        // Create Rust object of AugmenterTypes
+        let rust_augmenter = AugmenterTypes::Record(Arc::new(
+            RecordAugmenter::try_new(fields, shared_seed, consistent_substitutions).map_err(to_py_err)?,
+        ));
+
+        // Create Python object with respective parent classes
+        Ok(PyClassInitializer::from(PyBaseAugmenter {
+            inner: rust_augmenter,
+            rng,
+        })
+        .add_subclass(PyRecordAugmenter {}))
+    }
+}
+
 /// Flow Module - Pipelines, Random Selection, etc.
 #[pymodule]
 pub fn flow(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyChanceAugmenter>()?;
     m.add_class::<PySelectorAugmenter>()?;
     m.add_class::<PySequentialAugmenter>()?;
+    m.add_class::<PyRecordAugmenter>()?;
 
     Ok(())
 }
@@ -5,6 +5,7 @@ use pyo3::prelude::*;
 use pyo3::wrap_pymodule;
 
 mod base;
+mod errors;
 mod flow;
 mod models;
 mod text;
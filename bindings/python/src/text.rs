@@ -1,21 +1,21 @@
-use fast_aug_rust::text::TextAugmentParameters;
 use fast_aug_rust::text::{
     CharsRandomDeleteAugmenter, CharsRandomInsertAugmenter, CharsRandomSubstituteAugmenter, CharsRandomSwapAugmenter,
+    SwapMode,
 };
+use fast_aug_rust::text::{PunctuationAugmenter, TextAction, TextAugmentParameters};
+use fast_aug_rust::text::{SentencesRandomCropAugmenter, SentencesRandomDeleteAugmenter, SentencesRandomSwapAugmenter};
 use fast_aug_rust::text::{
     WordsRandomDeleteAugmenter, WordsRandomInsertAugmenter, WordsRandomSubstituteAugmenter, WordsRandomSwapAugmenter,
 };
 use std::collections::HashSet;
-use std::panic;
 use std::sync::Arc;
 
-use crate::base::{AugmenterTypes, PyBaseAugmenter};
+use crate::base::{rng_from_seed, AugmenterTypes, PyBaseAugmenter};
+use crate::errors::to_py_err;
 use fast_aug_rust::models::text::AlphabetModel;
 use pyo3::exceptions::{PyNotImplementedError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::{PyAny, PyList};
-use rand::rngs::SmallRng;
-use rand::SeedableRng;
 
 #[derive(FromPyObject)]
 enum PyConvertTextAugmentParameters {
@@ -59,6 +59,21 @@ impl PyBaseTextAugmenter {
         super_base.augment(py, data)
     }
 
+    /// Augment the data and get the log of changes made by augmenters
+    /// :param data: A String to augment
+    /// :returns: Augmented data and list of changes (dicts with augmenter, operation, original_index, new_index, old_text, new_text)
+    #[pyo3(text_signature = "(self, data: str)")]
+    pub fn augment_with_changes(
+        mut self_: PyRefMut<'_, Self>,
+        py: Python,
+        data: &Bound<'_, PyAny>,
+    ) -> PyResult<(PyObject, PyObject)> {
+        // Get base class
+        let super_base = self_.as_mut();
+        // Call base class method
+        super_base.augment_with_changes(py, data)
+    }
+
     /// Augment data given a batch of data
     /// :param data: Vector of strings to augment
     /// :returns: Augmented data
@@ -79,6 +94,7 @@ impl PyBaseTextAugmenter {
 ///     - probability or (probability, min_elements, max_elements)
 /// :param locale: The locale string to use for alphabet
 /// :param stopwords: Optional set of stopwords to ignore
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
 #[pyclass(extends=PyBaseTextAugmenter)]
 #[pyo3(name = "CharsRandomInsertAugmenter")]
 pub struct PyCharsRandomInsertAugmenter;
@@ -87,28 +103,26 @@ pub struct PyCharsRandomInsertAugmenter;
 impl PyCharsRandomInsertAugmenter {
     #[new]
     #[pyo3(
-        signature = (word_params, char_params, locale, stopwords=None),
-        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], char_params: float | tuple[float, int | None, int | None], locale: str, stopwords: set[str] | None = None)"
+        signature = (word_params, char_params, locale, stopwords=None, seed=None),
+        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], char_params: float | tuple[float, int | None, int | None], locale: str, stopwords: set[str] | None = None, seed: int | None = None)"
     )]
     fn py_new(
         word_params: PyConvertTextAugmentParameters,
         char_params: PyConvertTextAugmentParameters,
         locale: String,
         stopwords: Option<HashSet<String>>,
+        seed: Option<u64>,
     ) -> PyResult<PyClassInitializer<Self>> {
-        let rng = SmallRng::from_entropy(); // TODO: make from seed
+        let rng = rng_from_seed(seed);
 
         // Parse locale, if error return PyValueError
-        let alphabet_model = panic::catch_unwind(|| AlphabetModel::from_locale_str(&locale))
-            .map_err(|_| PyValueError::new_err("Invalid locale"))?;
+        let alphabet_model = AlphabetModel::try_from_locale_str(&locale).map_err(to_py_err)?;
 
         // Create Rust object of AugmenterTypes
-        let rust_augmenter = AugmenterTypes::Text(Arc::new(CharsRandomInsertAugmenter::new(
-            word_params.into(),
-            char_params.into(),
-            alphabet_model,
-            stopwords,
-        )));
+        let rust_augmenter = AugmenterTypes::Text(Arc::new(
+            CharsRandomInsertAugmenter::try_new(word_params.into(), char_params.into(), alphabet_model, stopwords)
+                .map_err(to_py_err)?,
+        ));
 
         // Create Python object with respective parent classes
         Ok(PyClassInitializer::from(PyBaseAugmenter {
@@ -128,6 +142,7 @@ impl PyCharsRandomInsertAugmenter {
 ///   - probability or (probability, min_elements, max_elements)
 /// :param locale: The locale string to use for alphabet
 /// :param stopwords: Optional set of stopwords to ignore
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
 #[pyclass(extends=PyBaseTextAugmenter)]
 #[pyo3(name = "CharsRandomSubstituteAugmenter")]
 pub struct PyCharsRandomSubstituteAugmenter;
@@ -136,28 +151,26 @@ pub struct PyCharsRandomSubstituteAugmenter;
 impl PyCharsRandomSubstituteAugmenter {
     #[new]
     #[pyo3(
-        signature = (word_params, char_params, locale, stopwords=None),
-        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], char_params: float | tuple[float, int | None, int | None], locale: str, stopwords: set[str] | None = None)"
+        signature = (word_params, char_params, locale, stopwords=None, seed=None),
+        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], char_params: float | tuple[float, int | None, int | None], locale: str, stopwords: set[str] | None = None, seed: int | None = None)"
     )]
     fn py_new(
         word_params: PyConvertTextAugmentParameters,
         char_params: PyConvertTextAugmentParameters,
         locale: String,
         stopwords: Option<HashSet<String>>,
+        seed: Option<u64>,
     ) -> PyResult<PyClassInitializer<Self>> {
-        let rng = SmallRng::from_entropy(); // TODO: make from seed
+        let rng = rng_from_seed(seed);
 
         // Parse locale, if error return PyValueError
-        let alphabet_model = panic::catch_unwind(|| AlphabetModel::from_locale_str(&locale))
-            .map_err(|_| PyValueError::new_err("Invalid locale"))?;
+        let alphabet_model = AlphabetModel::try_from_locale_str(&locale).map_err(to_py_err)?;
 
         // Create Rust object of AugmenterTypes
-        let rust_augmenter = AugmenterTypes::Text(Arc::new(CharsRandomSubstituteAugmenter::new(
-            word_params.into(),
-            char_params.into(),
-            alphabet_model,
-            stopwords,
-        )));
+        let rust_augmenter = AugmenterTypes::Text(Arc::new(
+            CharsRandomSubstituteAugmenter::try_new(word_params.into(), char_params.into(), alphabet_model, stopwords)
+                .map_err(to_py_err)?,
+        ));
 
         // Create Python object with respective parent classes
         Ok(PyClassInitializer::from(PyBaseAugmenter {
@@ -176,6 +189,11 @@ impl PyCharsRandomSubstituteAugmenter {
 /// :param char_params: The parameters for the char augmentation
 ///  - probability or (probability, min_elements, max_elements)
 /// :param stopwords: Optional set of stopwords to ignore
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
+/// :param swap_mode: How chars are swapped, one of
+///   - "random" - swap pairs of random chars
+///   - "adjacent" - swap random chars with their right neighbours
+///   - "middle" - swap pairs of random chars, keeping the first and the last char in place
 #[pyclass(extends=PyBaseTextAugmenter)]
 #[pyo3(name = "CharsRandomSwapAugmenter")]
 pub struct PyCharsRandomSwapAugmenter;
@@ -184,22 +202,35 @@ pub struct PyCharsRandomSwapAugmenter;
 impl PyCharsRandomSwapAugmenter {
     #[new]
     #[pyo3(
-        signature = (word_params, char_params, stopwords=None),
-        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], char_params: float | tuple[float, int | None, int | None], stopwords: set[str] | None = None)"
+        signature = (word_params, char_params, stopwords=None, seed=None, swap_mode="random"),
+        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], char_params: float | tuple[float, int | None, int | None], stopwords: set[str] | None = None, seed: int | None = None, swap_mode: str = 'random')"
     )]
     fn py_new(
         word_params: PyConvertTextAugmentParameters,
         char_params: PyConvertTextAugmentParameters,
         stopwords: Option<HashSet<String>>,
+        seed: Option<u64>,
+        swap_mode: &str,
     ) -> PyResult<PyClassInitializer<Self>> {
-        let rng = SmallRng::from_entropy(); // TODO: make from seed
+        let rng = rng_from_seed(seed);
+        let swap_mode = match swap_mode {
+            "random" => SwapMode::Random,
+            "adjacent" => SwapMode::Adjacent,
+            "middle" => SwapMode::Middle,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "swap_mode must be one of 'random', 'adjacent', 'middle', got '{}'",
+                    swap_mode
+                )))
+            }
+        };
 
         // Create Rust object of AugmenterTypes
-        let rust_augmenter = AugmenterTypes::Text(Arc::new(CharsRandomSwapAugmenter::new(
-            word_params.into(),
-            char_params.into(),
-            stopwords,
-        )));
+        let rust_augmenter = AugmenterTypes::Text(Arc::new(
+            CharsRandomSwapAugmenter::try_new(word_params.into(), char_params.into(), stopwords)
+                .map_err(to_py_err)?
+                .with_swap_mode(swap_mode),
+        ));
 
         // Create Python object with respective parent classes
         Ok(PyClassInitializer::from(PyBaseAugmenter {
@@ -218,6 +249,7 @@ impl PyCharsRandomSwapAugmenter {
 /// :param char_params: The parameters for the char augmentation
 ///  - probability or (probability, min_elements, max_elements)
 /// :param stopwords: Optional set of stopwords to ignore
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
 #[pyclass(extends=PyBaseTextAugmenter)]
 #[pyo3(name = "CharsRandomDeleteAugmenter")]
 pub struct PyCharsRandomDeleteAugmenter;
@@ -226,22 +258,22 @@ pub struct PyCharsRandomDeleteAugmenter;
 impl PyCharsRandomDeleteAugmenter {
     #[new]
     #[pyo3(
-        signature = (word_params, char_params, stopwords=None),
-        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], char_params: float | tuple[float, int | None, int | None], stopwords: set[str] | None = None)"
+        signature = (word_params, char_params, stopwords=None, seed=None),
+        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], char_params: float | tuple[float, int | None, int | None], stopwords: set[str] | None = None, seed: int | None = None)"
     )]
     fn py_new(
         word_params: PyConvertTextAugmentParameters,
         char_params: PyConvertTextAugmentParameters,
         stopwords: Option<HashSet<String>>,
+        seed: Option<u64>,
     ) -> PyResult<PyClassInitializer<Self>> {
-        let rng = SmallRng::from_entropy(); // TODO: make from seed
+        let rng = rng_from_seed(seed);
 
         // Create Rust object of AugmenterTypes
-        let rust_augmenter = AugmenterTypes::Text(Arc::new(CharsRandomDeleteAugmenter::new(
-            word_params.into(),
-            char_params.into(),
-            stopwords,
-        )));
+        let rust_augmenter = AugmenterTypes::Text(Arc::new(
+            CharsRandomDeleteAugmenter::try_new(word_params.into(), char_params.into(), stopwords)
+                .map_err(to_py_err)?,
+        ));
 
         // Create Python object with respective parent classes
         Ok(PyClassInitializer::from(PyBaseAugmenter {
@@ -259,6 +291,7 @@ impl PyCharsRandomDeleteAugmenter {
 ///     - probability or (probability, min_elements, max_elements)
 /// :param vocabulary: The vocabulary to use for insertion
 /// :param stopwords: Optional set of stopwords to ignore
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
 #[pyclass(extends=PyBaseTextAugmenter)]
 #[pyo3(name = "WordsRandomInsertAugmenter")]
 pub struct PyWordsRandomInsertAugmenter;
@@ -267,27 +300,21 @@ pub struct PyWordsRandomInsertAugmenter;
 impl PyWordsRandomInsertAugmenter {
     #[new]
     #[pyo3(
-        signature = (word_params, vocabulary, stopwords=None),
-        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], vocabulary: list[str], stopwords: set[str] | None = None)"
+        signature = (word_params, vocabulary, stopwords=None, seed=None),
+        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], vocabulary: list[str], stopwords: set[str] | None = None, seed: int | None = None)"
     )]
     fn py_new(
         word_params: PyConvertTextAugmentParameters,
         vocabulary: Vec<String>,
         stopwords: Option<HashSet<String>>,
+        seed: Option<u64>,
     ) -> PyResult<PyClassInitializer<Self>> {
-        let rng = SmallRng::from_entropy(); // TODO: make from seed
-
-        // Check if vocabulary is empty
-        if vocabulary.is_empty() {
-            return Err(PyValueError::new_err("Vocabulary cannot be empty"));
-        }
+        let rng = rng_from_seed(seed);
 
         // Create Rust object of AugmenterTypes
-        let rust_augmenter = AugmenterTypes::Text(Arc::new(WordsRandomInsertAugmenter::new(
-            word_params.into(),
-            vocabulary,
-            stopwords,
-        )));
+        let rust_augmenter = AugmenterTypes::Text(Arc::new(
+            WordsRandomInsertAugmenter::try_new(word_params.into(), vocabulary, stopwords).map_err(to_py_err)?,
+        ));
 
         // Create Python object with respective parent classes
         Ok(PyClassInitializer::from(PyBaseAugmenter {
@@ -305,6 +332,7 @@ impl PyWordsRandomInsertAugmenter {
 ///    - probability or (probability, min_elements, max_elements)
 /// :param vocabulary: The vocabulary to use for substitution
 /// :param stopwords: Optional set of stopwords to ignore
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
 #[pyclass(extends=PyBaseTextAugmenter)]
 #[pyo3(name = "WordsRandomSubstituteAugmenter")]
 pub struct PyWordsRandomSubstituteAugmenter;
@@ -313,27 +341,21 @@ pub struct PyWordsRandomSubstituteAugmenter;
 impl PyWordsRandomSubstituteAugmenter {
     #[new]
     #[pyo3(
-        signature = (word_params, vocabulary, stopwords=None),
-        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], vocabulary: list[str], stopwords: set[str] | None = None)"
+        signature = (word_params, vocabulary, stopwords=None, seed=None),
+        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], vocabulary: list[str], stopwords: set[str] | None = None, seed: int | None = None)"
     )]
     fn py_new(
         word_params: PyConvertTextAugmentParameters,
         vocabulary: Vec<String>,
         stopwords: Option<HashSet<String>>,
+        seed: Option<u64>,
     ) -> PyResult<PyClassInitializer<Self>> {
-        let rng = SmallRng::from_entropy(); // TODO: make from seed
-
-        // Check if vocabulary is empty
-        if vocabulary.is_empty() {
-            return Err(PyValueError::new_err("Vocabulary cannot be empty"));
-        }
+        let rng = rng_from_seed(seed);
 
         // Create Rust object of AugmenterTypes
-        let rust_augmenter = AugmenterTypes::Text(Arc::new(WordsRandomSubstituteAugmenter::new(
-            word_params.into(),
-            vocabulary,
-            stopwords,
-        )));
+        let rust_augmenter = AugmenterTypes::Text(Arc::new(
+            WordsRandomSubstituteAugmenter::try_new(word_params.into(), vocabulary, stopwords).map_err(to_py_err)?,
+        ));
 
         // Create Python object with respective parent classes
         Ok(PyClassInitializer::from(PyBaseAugmenter {
@@ -350,6 +372,7 @@ impl PyWordsRandomSubstituteAugmenter {
 /// :param word_params: The parameters for the word augmentation
 ///    - probability or (probability, min_elements, max_elements)
 /// :param stopwords: Optional set of stopwords to ignore
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
 #[pyclass(extends=PyBaseTextAugmenter)]
 #[pyo3(name = "WordsRandomSwapAugmenter")]
 pub struct PyWordsRandomSwapAugmenter;
@@ -358,18 +381,20 @@ pub struct PyWordsRandomSwapAugmenter;
 impl PyWordsRandomSwapAugmenter {
     #[new]
     #[pyo3(
-        signature = (word_params, stopwords=None),
-        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], stopwords: set[str] | None = None)"
+        signature = (word_params, stopwords=None, seed=None),
+        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], stopwords: set[str] | None = None, seed: int | None = None)"
     )]
     fn py_new(
         word_params: PyConvertTextAugmentParameters,
         stopwords: Option<HashSet<String>>,
+        seed: Option<u64>,
     ) -> PyResult<PyClassInitializer<Self>> {
-        let rng = SmallRng::from_entropy(); // TODO: make from seed
+        let rng = rng_from_seed(seed);
 
         // Create Rust object of AugmenterTypes
-        let rust_augmenter =
-            AugmenterTypes::Text(Arc::new(WordsRandomSwapAugmenter::new(word_params.into(), stopwords)));
+        let rust_augmenter = AugmenterTypes::Text(Arc::new(
+            WordsRandomSwapAugmenter::try_new(word_params.into(), stopwords).map_err(to_py_err)?,
+        ));
 
         // Create Python object with respective parent classes
         Ok(PyClassInitializer::from(PyBaseAugmenter {
@@ -386,6 +411,7 @@ impl PyWordsRandomSwapAugmenter {
 /// :param word_params: The parameters for the word augmentation
 ///   - probability or (probability, min_elements, max_elements)
 /// :param stopwords: Optional set of stopwords to ignore
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
 #[pyclass(extends=PyBaseTextAugmenter)]
 #[pyo3(name = "WordsRandomDeleteAugmenter")]
 pub struct PyWordsRandomDeleteAugmenter;
@@ -394,18 +420,20 @@ pub struct PyWordsRandomDeleteAugmenter;
 impl PyWordsRandomDeleteAugmenter {
     #[new]
     #[pyo3(
-        signature = (word_params, stopwords=None),
-        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], stopwords: set[str] | None = None)"
+        signature = (word_params, stopwords=None, seed=None),
+        text_signature = "(self, word_params: float | tuple[float, int | None, int | None], stopwords: set[str] | None = None, seed: int | None = None)"
     )]
     fn py_new(
         word_params: PyConvertTextAugmentParameters,
         stopwords: Option<HashSet<String>>,
+        seed: Option<u64>,
     ) -> PyResult<PyClassInitializer<Self>> {
-        let rng = SmallRng::from_entropy(); // TODO: make from seed
+        let rng = rng_from_seed(seed);
 
         // Create Rust object of AugmenterTypes
-        let rust_augmenter =
-            AugmenterTypes::Text(Arc::new(WordsRandomDeleteAugmenter::new(word_params.into(), stopwords)));
+        let rust_augmenter = AugmenterTypes::Text(Arc::new(
+            WordsRandomDeleteAugmenter::try_new(word_params.into(), stopwords).map_err(to_py_err)?,
+        ));
 
         // Create Python object with respective parent classes
         Ok(PyClassInitializer::from(PyBaseAugmenter {
@@ -417,6 +445,175 @@ impl PyWordsRandomDeleteAugmenter {
     }
 }
 
+/// Randomly swap sentences, whitespace between sentences is kept in place
+///
+/// :param sentence_params: The parameters for the sentence augmentation
+///   - probability or (probability, min_elements, max_elements)
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
+#[pyclass(extends=PyBaseTextAugmenter)]
+#[pyo3(name = "SentencesRandomSwapAugmenter")]
+pub struct PySentencesRandomSwapAugmenter;
+
+#[pymethods]
+impl PySentencesRandomSwapAugmenter {
+    #[new]
+    #[pyo3(
+        signature = (sentence_params, seed=None),
+        text_signature = "(self, sentence_params: float | tuple[float, int | None, int | None], seed: int | None = None)"
+    )]
+    fn py_new(
+        sentence_params: PyConvertTextAugmentParameters,
+        seed: Option<u64>,
+    ) -> PyResult<PyClassInitializer<Self>> {
+        let rng = rng_from_seed(seed);
+
+        // Create Rust object of AugmenterTypes
+        let rust_augmenter = AugmenterTypes::Text(Arc::new(
+            SentencesRandomSwapAugmenter::try_new(sentence_params.into()).map_err(to_py_err)?,
+        ));
+
+        // Create Python object with respective parent classes
+        Ok(PyClassInitializer::from(PyBaseAugmenter {
+            inner: rust_augmenter,
+            rng,
+        })
+        .add_subclass(PyBaseTextAugmenter {})
+        .add_subclass(PySentencesRandomSwapAugmenter {}))
+    }
+}
+
+/// Randomly delete sentences
+///
+/// :param sentence_params: The parameters for the sentence augmentation
+///   - probability or (probability, min_elements, max_elements)
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
+#[pyclass(extends=PyBaseTextAugmenter)]
+#[pyo3(name = "SentencesRandomDeleteAugmenter")]
+pub struct PySentencesRandomDeleteAugmenter;
+
+#[pymethods]
+impl PySentencesRandomDeleteAugmenter {
+    #[new]
+    #[pyo3(
+        signature = (sentence_params, seed=None),
+        text_signature = "(self, sentence_params: float | tuple[float, int | None, int | None], seed: int | None = None)"
+    )]
+    fn py_new(
+        sentence_params: PyConvertTextAugmentParameters,
+        seed: Option<u64>,
+    ) -> PyResult<PyClassInitializer<Self>> {
+        let rng = rng_from_seed(seed);
+
+        // Create Rust object of AugmenterTypes
+        let rust_augmenter = AugmenterTypes::Text(Arc::new(
+            SentencesRandomDeleteAugmenter::try_new(sentence_params.into()).map_err(to_py_err)?,
+        ));
+
+        // Create Python object with respective parent classes
+        Ok(PyClassInitializer::from(PyBaseAugmenter {
+            inner: rust_augmenter,
+            rng,
+        })
+        .add_subclass(PyBaseTextAugmenter {})
+        .add_subclass(PySentencesRandomDeleteAugmenter {}))
+    }
+}
+
+/// Crop text to a random window of consecutive sentences, other sentences are deleted
+///
+/// :param sentence_params: The parameters for the number of sentences in the window (to keep), at least one sentence is kept
+///   - probability or (probability, min_elements, max_elements)
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
+#[pyclass(extends=PyBaseTextAugmenter)]
+#[pyo3(name = "SentencesRandomCropAugmenter")]
+pub struct PySentencesRandomCropAugmenter;
+
+#[pymethods]
+impl PySentencesRandomCropAugmenter {
+    #[new]
+    #[pyo3(
+        signature = (sentence_params, seed=None),
+        text_signature = "(self, sentence_params: float | tuple[float, int | None, int | None], seed: int | None = None)"
+    )]
+    fn py_new(
+        sentence_params: PyConvertTextAugmentParameters,
+        seed: Option<u64>,
+    ) -> PyResult<PyClassInitializer<Self>> {
+        let rng = rng_from_seed(seed);
+
+        // Create Rust object of AugmenterTypes
+        let rust_augmenter = AugmenterTypes::Text(Arc::new(
+            SentencesRandomCropAugmenter::try_new(sentence_params.into()).map_err(to_py_err)?,
+        ));
+
+        // Create Python object with respective parent classes
+        Ok(PyClassInitializer::from(PyBaseAugmenter {
+            inner: rust_augmenter,
+            rng,
+        })
+        .add_subclass(PyBaseTextAugmenter {})
+        .add_subclass(PySentencesRandomCropAugmenter {}))
+    }
+}
+
+/// Insert, delete or substitute punctuation marks of the locale, e.g. ". , ; : ! ?" for "en"
+///
+/// :param action: The action to apply, one of
+///   - "insert" - insert random marks before random words (AEDA), number of marks is calculated from the number of words
+///   - "delete" - delete random punctuation marks
+///   - "substitute" - substitute random punctuation marks with other marks
+/// :param punctuation_params: The parameters for the punctuation augmentation
+///   - probability or (probability, min_elements, max_elements)
+/// :param locale: The locale string to use for punctuation marks
+/// :param seed: Optional seed for the random generator, for reproducible augmentation
+#[pyclass(extends=PyBaseTextAugmenter)]
+#[pyo3(name = "PunctuationAugmenter")]
+pub struct PyPunctuationAugmenter;
+
+#[pymethods]
+impl PyPunctuationAugmenter {
+    #[new]
+    #[pyo3(
+        signature = (action, punctuation_params, locale="en", seed=None),
+        text_signature = "(self, action: str, punctuation_params: float | tuple[float, int | None, int | None], locale: str = 'en', seed: int | None = None)"
+    )]
+    fn py_new(
+        action: &str,
+        punctuation_params: PyConvertTextAugmentParameters,
+        locale: &str,
+        seed: Option<u64>,
+    ) -> PyResult<PyClassInitializer<Self>> {
+        let rng = rng_from_seed(seed);
+        let action = match action {
+            "insert" => TextAction::Insert,
+            "delete" => TextAction::Delete,
+            "substitute" => TextAction::Substitute,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "action must be one of 'insert', 'delete', 'substitute', got '{}'",
+                    action
+                )))
+            }
+        };
+
+        // Parse locale, if error return PyValueError
+        let alphabet_model = AlphabetModel::try_from_locale_str(locale).map_err(to_py_err)?;
+
+        // Create Rust object of AugmenterTypes
+        let rust_augmenter = AugmenterTypes::Text(Arc::new(
+            PunctuationAugmenter::try_new(action, punctuation_params.into(), alphabet_model).map_err(to_py_err)?,
+        ));
+
+        // Create Python object with respective parent classes
+        Ok(PyClassInitializer::from(PyBaseAugmenter {
+            inner: rust_augmenter,
+            rng,
+        })
+        .add_subclass(PyBaseTextAugmenter {})
+        .add_subclass(PyPunctuationAugmenter {}))
+    }
+}
+
 /// Text Augmentation Module
 #[pymodule]
 pub fn text(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -429,6 +626,10 @@ pub fn text(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyWordsRandomSubstituteAugmenter>()?;
     m.add_class::<PyWordsRandomSwapAugmenter>()?;
     m.add_class::<PyWordsRandomDeleteAugmenter>()?;
+    m.add_class::<PySentencesRandomSwapAugmenter>()?;
+    m.add_class::<PySentencesRandomDeleteAugmenter>()?;
+    m.add_class::<PySentencesRandomCropAugmenter>()?;
+    m.add_class::<PyPunctuationAugmenter>()?;
 
     Ok(())
 }
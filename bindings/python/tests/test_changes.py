@@ -0,0 +1,45 @@
+from __future__ import annotations
+
+import pytest
+
+from fast_aug.base import BaseAugmenter
+from fast_aug.flow import ChanceAugmenter, SequentialAugmenter
+from fast_aug.text import WordsRandomDeleteAugmenter, WordsRandomSwapAugmenter
+
+
+TEXT = "The quick brown fox jumps over the lazy dog"
+
+
+@pytest.mark.parametrize(
+    "augmenter, expected_operation",
+    [
+        (WordsRandomSwapAugmenter(0.5), "swap"),
+        (WordsRandomDeleteAugmenter(0.5), "delete"),
+        (BaseAugmenter.from_config({"type": "WordsRandomDelete", "word_params": 0.5}), "delete"),
+    ],
+)
+def test_augment_with_changes(augmenter: BaseAugmenter, expected_operation: str) -> None:
+    output, changes = augmenter.augment_with_changes(TEXT)
+
+    assert isinstance(output, str)
+    assert len(changes) > 0
+    for change in changes:
+        assert change["operation"] == expected_operation
+        assert set(change) == {"augmenter", "operation", "original_index", "new_index", "old_text", "new_text"}
+
+
+def test_augment_with_changes_pipeline() -> None:
+    augmenter = SequentialAugmenter([WordsRandomSwapAugmenter(0.5), WordsRandomDeleteAugmenter(0.5)])
+
+    _, changes = augmenter.augment_with_changes(TEXT)
+
+    assert {change["augmenter"] for change in changes} == {"WordsRandomSwapAugmenter", "WordsRandomDeleteAugmenter"}
+
+
+def test_augment_with_changes_not_applied() -> None:
+    augmenter = ChanceAugmenter(WordsRandomSwapAugmenter(1.0), 0.0)
+
+    output, changes = augmenter.augment_with_changes(TEXT)
+
+    assert output == TEXT
+    assert changes == []
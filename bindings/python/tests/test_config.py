@@ -0,0 +1,83 @@
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+import pytest
+
+from fast_aug.base import BaseAugmenter
+
+
+PIPELINE_CONFIG = {
+    "type": "Sequential",
+    "augmenters": [
+        {"type": "CharsRandomSwap", "word_params": 0.3, "char_params": [0.3, 1, 3]},
+        {
+            "type": "Selector",
+            "weights": [0.7, 0.3],
+            "augmenters": [
+                {"type": "WordsRandomDelete", "word_params": {"p": 0.2}, "stopwords": ["not"]},
+                {"type": "CharsRandomInsert", "word_params": 0.3, "char_params": 0.3, "locale": "en"},
+            ],
+        },
+    ],
+}
+
+
+@pytest.mark.parametrize(
+    "config",
+    [
+        PIPELINE_CONFIG,
+        json.dumps(PIPELINE_CONFIG),
+        "type: WordsRandomSwap\nword_params: 0.3",
+    ],
+)
+def test_from_config(config: dict[str, Any] | str) -> None:
+    augmenter = BaseAugmenter.from_config(config)
+
+    assert isinstance(augmenter.augment("Some text, not a long one!"), str)
+    assert len(augmenter.augment_batch(["Some text!", "Other text!"])) == 2
+
+
+def test_from_config_file(tmp_path: Path) -> None:
+    path = tmp_path / "config.json"
+    path.write_text(json.dumps(PIPELINE_CONFIG))
+
+    augmenter = BaseAugmenter.from_config_file(str(path))
+
+    assert isinstance(augmenter.augment("Some text!"), str)
+
+
+def test_from_config_record() -> None:
+    config = {
+        "type": "Record",
+        "fields": {"premise": {"type": "WordsRandomSubstitute", "word_params": 1.0, "vocabulary": ["dog"]}},
+        "consistent_substitutions": True,
+    }
+    augmenter = BaseAugmenter.from_config(config, seed=42)
+    record = {"premise": "A cat sleeps", "hypothesis": "A cat", "label": 0}
+
+    output = augmenter.augment(record)
+
+    assert output == {**record, "premise": "Dog dog dog"}
+
+
+@pytest.mark.parametrize(
+    "config",
+    [
+        {"type": "WordsRandomSwap", "word_params": 1.5},
+        {"type": "NotExistingAugmenter"},
+        {"type": "Sequential", "augmenters": []},
+        {"type": "Record", "fields": {}},
+        {"type": "CharsRandomInsert", "word_params": 0.3, "char_params": 0.3, "locale": "en_US_US"},
+    ],
+)
+def test_from_config_error(config: dict[str, Any]) -> None:
+    with pytest.raises(ValueError):
+        BaseAugmenter.from_config(config)
+
+
+def test_from_config_file_not_existing() -> None:
+    with pytest.raises(OSError):
+        BaseAugmenter.from_config_file("not_existing_config.json")
@@ -47,7 +47,6 @@ def test_init_wrong_augmenters(augmenters: Any) -> None:
         [0.5, 0.5],
         [0.1, 1.0],
         [1, 1000],
-        [-0.1, 1.1],
     ],
 )
 def test_init_weights(weights: list[float] | None) -> None:
@@ -64,6 +63,8 @@ def test_init_weights(weights: list[float] | None) -> None:
         [0.4, object],
         [0.5, "invalid_type"],
         [None, 0.3],
+        [-0.1, 1.1],
+        [0.0, 0.0],
     ],
 )
 def test_init_wrong_selector(weights: Any) -> None:
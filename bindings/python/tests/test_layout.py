@@ -32,11 +32,17 @@ def test_text_module_layout() -> None:
             "WordsRandomDeleteAugmenter",
             "WordsRandomSwapAugmenter",
             "WordsRandomSubstituteAugmenter",
+            "SentencesRandomSwapAugmenter",
+            "SentencesRandomDeleteAugmenter",
+            "SentencesRandomCropAugmenter",
+            "PunctuationAugmenter",
         ]
     )
     assert fast_aug.text.__doc__ and len(fast_aug.text.__doc__) > 0, "text module docstring is empty"
 
 
 def test_flow_module_layout() -> None:
-    assert sorted(fast_aug.flow.__all__) == sorted(["ChanceAugmenter", "SelectorAugmenter", "SequentialAugmenter"])
+    assert sorted(fast_aug.flow.__all__) == sorted(
+        ["ChanceAugmenter", "SelectorAugmenter", "SequentialAugmenter", "RecordAugmenter"]
+    )
     assert fast_aug.flow.__doc__ and len(fast_aug.flow.__doc__) > 0, "flow module docstring is empty"
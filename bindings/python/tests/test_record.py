@@ -0,0 +1,84 @@
+from __future__ import annotations
+
+import pytest
+
+from fast_aug.flow import ChanceAugmenter, RecordAugmenter
+from fast_aug.text import CharsRandomSwapAugmenter, WordsRandomSubstituteAugmenter, WordsRandomSwapAugmenter
+
+
+RECORD = {"premise": "A cat sleeps on the sofa", "hypothesis": "The cat is sleeping", "label": 0}
+
+
+def test_record_augment() -> None:
+    augmenter = RecordAugmenter({"premise": WordsRandomSubstituteAugmenter(1.0, ["dog"])}, seed=42)
+
+    output = augmenter.augment(RECORD)
+
+    assert output == {**RECORD, "premise": "dog dog dog dog dog dog"}
+    assert RECORD["premise"] == "A cat sleeps on the sofa", "input should not be modified"
+
+
+def test_record_augment_batched_dict() -> None:
+    augmenter = RecordAugmenter({"premise": WordsRandomSubstituteAugmenter(1.0, ["dog"])}, seed=42)
+    batch = {"premise": ["A cat", "A bird", None], "hypothesis": ["A cat", "A bird", "A fish"], "label": [0, 1, 2]}
+
+    output = augmenter.augment(batch)
+
+    assert output == {**batch, "premise": ["dog dog", "dog dog", None]}
+
+
+def test_record_augment_batched_dict_length_mismatch() -> None:
+    augmenter = RecordAugmenter(
+        {"premise": CharsRandomSwapAugmenter(0.5, 0.5), "hypothesis": CharsRandomSwapAugmenter(0.5, 0.5)}
+    )
+
+    with pytest.raises(ValueError):
+        augmenter.augment({"premise": ["A cat", "A bird"], "hypothesis": ["A cat"]})
+
+
+def test_record_augment_batch() -> None:
+    augmenter = RecordAugmenter({"hypothesis": WordsRandomSubstituteAugmenter(1.0, ["dog"])}, seed=42)
+
+    output = augmenter.augment_batch([RECORD, {"hypothesis": "A fish", "label": 1}, {"label": 2}])
+
+    assert output == [
+        {**RECORD, "hypothesis": "dog dog dog dog"},
+        {"hypothesis": "dog dog", "label": 1},
+        {"label": 2},
+    ]
+
+
+def test_record_consistent_substitutions() -> None:
+    augmenter = RecordAugmenter(
+        {
+            "premise": WordsRandomSubstituteAugmenter(1.0, ["dog", "bird", "fish"]),
+            "hypothesis": CharsRandomSwapAugmenter(0.0, 0.0),
+        },
+        consistent_substitutions=True,
+    )
+
+    for _ in range(10):
+        output = augmenter.augment({"premise": "cat", "hypothesis": "The cat sleeps"})
+        assert output["hypothesis"] == f"The {output['premise']} sleeps"
+
+
+def test_record_shared_seed() -> None:
+    chance_swap = ChanceAugmenter(WordsRandomSwapAugmenter(1.0), 0.5)
+    augmenter = RecordAugmenter({"premise": chance_swap, "hypothesis": chance_swap}, shared_seed=True)
+
+    for output in augmenter.augment_batch([{"premise": "A B", "hypothesis": "A B"}] * 10):
+        assert output["premise"] == output["hypothesis"]
+
+
+def test_record_same_seed_same_output() -> None:
+    def factory() -> RecordAugmenter:
+        return RecordAugmenter({"premise": CharsRandomSwapAugmenter(0.5, 0.5)}, seed=42)
+
+    assert factory().augment_batch([RECORD] * 10) == factory().augment_batch([RECORD] * 10)
+
+
+def test_record_invalid_fields() -> None:
+    with pytest.raises(TypeError):
+        RecordAugmenter({"premise": "not an augmenter"})  # type: ignore[dict-item]
+    with pytest.raises(ValueError):
+        RecordAugmenter({})
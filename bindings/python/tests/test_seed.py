@@ -0,0 +1,74 @@
+from __future__ import annotations
+
+import pickle
+from collections.abc import Callable
+
+import pytest
+
+from fast_aug.base import BaseAugmenter
+from fast_aug.flow import ChanceAugmenter, SelectorAugmenter, SequentialAugmenter
+from fast_aug.text import CharsRandomInsertAugmenter, CharsRandomSwapAugmenter, WordsRandomSwapAugmenter
+
+
+TEXTS = [f"Some text number {i}, not a long one but with enough words!" for i in range(20)]
+
+
+AUGMENTER_FACTORIES: list[Callable[[int | None], BaseAugmenter]] = [
+    lambda seed: CharsRandomSwapAugmenter(0.5, 0.5, seed=seed),
+    lambda seed: CharsRandomInsertAugmenter(0.5, 0.5, "en", seed=seed),
+    lambda seed: WordsRandomSwapAugmenter(0.5, seed=seed),
+    lambda seed: ChanceAugmenter(WordsRandomSwapAugmenter(0.5), 0.5, seed=seed),
+    lambda seed: SelectorAugmenter([WordsRandomSwapAugmenter(0.5), CharsRandomSwapAugmenter(0.5, 0.5)], seed=seed),
+    lambda seed: SequentialAugmenter([WordsRandomSwapAugmenter(0.5), CharsRandomSwapAugmenter(0.5, 0.5)], seed=seed),
+    lambda seed: BaseAugmenter.from_config({"type": "WordsRandomSwap", "word_params": 0.5}, seed=seed),
+]
+
+
+@pytest.mark.parametrize("factory", AUGMENTER_FACTORIES)
+def test_same_seed_same_output(factory: Callable[[int | None], BaseAugmenter]) -> None:
+    first, second = factory(42), factory(42)
+
+    assert [first.augment(text) for text in TEXTS] == [second.augment(text) for text in TEXTS]
+    assert first.augment_batch(TEXTS) == second.augment_batch(TEXTS)
+
+
+@pytest.mark.parametrize("factory", AUGMENTER_FACTORIES)
+def test_different_seed_different_output(factory: Callable[[int | None], BaseAugmenter]) -> None:
+    assert factory(1).augment_batch(TEXTS) != factory(2).augment_batch(TEXTS)
+
+
+def test_inner_augmenter_seed_ignored() -> None:
+    first = SequentialAugmenter([WordsRandomSwapAugmenter(0.5, seed=1), CharsRandomSwapAugmenter(0.5, 0.5)], seed=42)
+    second = SequentialAugmenter([WordsRandomSwapAugmenter(0.5, seed=2), CharsRandomSwapAugmenter(0.5, 0.5)], seed=42)
+
+    assert first.augment_batch(TEXTS) == second.augment_batch(TEXTS)
+
+
+def test_set_seed() -> None:
+    augmenter = WordsRandomSwapAugmenter(0.5, seed=42)
+    expected = augmenter.augment_batch(TEXTS)
+
+    augmenter.set_seed(42)
+    assert augmenter.augment_batch(TEXTS) == expected
+
+    augmenter.set_seed()
+    augmenter.set_seed(None)
+
+
+def test_rng_state_resume() -> None:
+    augmenter = WordsRandomSwapAugmenter(0.5, seed=42)
+    augmenter.augment_batch(TEXTS)
+
+    state = pickle.loads(pickle.dumps(augmenter.get_rng_state()))
+    expected = [augmenter.augment(text) for text in TEXTS]
+
+    resumed = WordsRandomSwapAugmenter(0.5)
+    resumed.set_rng_state(state)
+    assert [resumed.augment(text) for text in TEXTS] == expected
+
+
+def test_rng_state_invalid() -> None:
+    augmenter = WordsRandomSwapAugmenter(0.5)
+
+    with pytest.raises(ValueError):
+        augmenter.set_rng_state(b"not a state")
@@ -79,6 +79,26 @@ def test_init_locale_error(locale: str | None) -> None:
         CharsRandomSubstituteAugmenter(0.3, 0.3, locale)
 
 
+@pytest.mark.parametrize("swap_mode", ["random", "adjacent", "middle"])
+def test_init_swap_mode(swap_mode: str) -> None:
+    CharsRandomSwapAugmenter(0.3, 0.3, swap_mode=swap_mode)
+
+
+def test_init_swap_mode_error() -> None:
+    with pytest.raises(ValueError):
+        CharsRandomSwapAugmenter(0.3, 0.3, swap_mode="not_existing_mode")
+
+
+def test_swap_mode_middle() -> None:
+    augmenter = CharsRandomSwapAugmenter(1.0, 1.0, swap_mode="middle")
+
+    output = augmenter.augment("Cambridge abc")
+
+    assert output != "Cambridge abc"
+    assert output[0] == "C" and output[8:] == "e abc"
+    assert sorted(output) == sorted("Cambridge abc")
+
+
 @pytest.mark.parametrize(
     "augmenter",
     [
@@ -121,3 +141,10 @@ def test_input_changes_batch(augmenter: BaseTextAugmenter) -> None:
     output = augmenter.augment_batch(texts)
     assert texts != output
     assert len(texts) == len(output)
+
+
+def test_init_positional_seed() -> None:
+    first = CharsRandomSwapAugmenter(0.5, 0.5, None, 42)
+    second = CharsRandomSwapAugmenter(0.5, 0.5, None, 42)
+
+    assert first.augment("Some longer text to swap") == second.augment("Some longer text to swap")
@@ -0,0 +1,54 @@
+from __future__ import annotations
+
+import pytest
+
+from fast_aug.text import PunctuationAugmenter
+
+
+@pytest.mark.parametrize("action", ["insert", "delete", "substitute"])
+@pytest.mark.parametrize(
+    "punctuation_params",
+    [
+        0.3,
+        (0.3, None, None),
+        (0.1, 2, None),
+    ],
+)
+def test_init(action: str, punctuation_params: float | tuple[float, int | None, int | None]) -> None:
+    PunctuationAugmenter(action, punctuation_params)
+
+
+def test_init_action_error() -> None:
+    with pytest.raises(ValueError):
+        PunctuationAugmenter("swap", 0.3)
+
+
+def test_init_locale_error() -> None:
+    with pytest.raises(Exception):
+        PunctuationAugmenter("insert", 0.3, locale="en_US_US")
+
+
+def test_insert() -> None:
+    augmenter = PunctuationAugmenter("insert", 1.0)
+
+    output = augmenter.augment("I like it")
+
+    words = output.split(" ")
+    assert len(words) == 6
+    assert words[1::2] == ["I", "like", "it"]
+    assert all(mark in ".,;:!?" for mark in words[0::2])
+
+
+def test_delete() -> None:
+    augmenter = PunctuationAugmenter("delete", 1.0)
+
+    assert augmenter.augment("Yes, I do. Do you?") == "Yes I do Do you"
+
+
+def test_substitute() -> None:
+    augmenter = PunctuationAugmenter("substitute", 1.0, locale="ja")
+
+    output = augmenter.augment("はい、そうです。")
+
+    assert output != "はい、そうです。"
+    assert output.replace("そうです", "").startswith("はい")
@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+import pytest
+
+from fast_aug.text import (
+    BaseTextAugmenter,
+    SentencesRandomCropAugmenter,
+    SentencesRandomDeleteAugmenter,
+    SentencesRandomSwapAugmenter,
+)
+
+
+TEXT = "First sentence. Second one! Is it the third? The fourth. And the last one."
+SENTENCE_AUGMENTERS = [SentencesRandomSwapAugmenter, SentencesRandomDeleteAugmenter, SentencesRandomCropAugmenter]
+SENTENCES = ["First sentence.", "Second one!", "Is it the third?", "The fourth.", "And the last one."]
+
+
+def _split_sentences(text: str) -> list[str]:
+    return [sentence for sentence in SENTENCES if sentence in text]
+
+
+@pytest.mark.parametrize(
+    "sentence_params",
+    [
+        0.3,
+        (0.3, None, None),
+        (0.4, None, 10),
+        (0.1, 2, None),
+    ],
+)
+def test_init_sentence_params(sentence_params: float | tuple[float, int | None, int | None]) -> None:
+    SentencesRandomSwapAugmenter(sentence_params)
+    SentencesRandomDeleteAugmenter(sentence_params)
+    SentencesRandomCropAugmenter(sentence_params)
+
+
+@pytest.mark.parametrize("augmenter_cls", SENTENCE_AUGMENTERS)
+def test_init_invalid_sentence_params(augmenter_cls: type[BaseTextAugmenter]) -> None:
+    with pytest.raises(ValueError):
+        augmenter_cls(1.5)
+
+
+def test_sentences_swap() -> None:
+    augmenter = SentencesRandomSwapAugmenter(1.0)
+
+    output = augmenter.augment(TEXT)
+
+    assert output != TEXT
+    assert sorted(output.split(" ")) == sorted(TEXT.split(" "))
+    assert sorted(_split_sentences(output)) == sorted(SENTENCES)
+
+
+def test_sentences_delete() -> None:
+    augmenter = SentencesRandomDeleteAugmenter((0.5, None, 2))
+
+    for output in augmenter.augment_batch([TEXT] * 10):
+        assert len(_split_sentences(output)) == 3
+        assert " ".join(_split_sentences(output)) == output
+
+
+def test_sentences_crop() -> None:
+    augmenter = SentencesRandomCropAugmenter((0.5, None, 2))
+
+    for output in augmenter.augment_batch([TEXT] * 10):
+        assert output in [" ".join(SENTENCES[i : i + 2]) for i in range(len(SENTENCES) - 1)]
+
+
+@pytest.mark.parametrize("augmenter_cls", SENTENCE_AUGMENTERS)
+def test_sentences_empty_text(augmenter_cls: type[BaseTextAugmenter]) -> None:
+    augmenter = augmenter_cls(0.5)
+
+    assert augmenter.augment("") == ""
+    assert augmenter.augment("   ") == "   "
@@ -48,15 +48,23 @@ def test_init_stopwords(stopwords: list[str] | set[str] | None) -> None:
 def test_init_vocabulary_error() -> None:
     with pytest.raises(Exception):
         WordsRandomInsertAugmenter(0.3, None)  # type: ignore
-    with pytest.raises(Exception):
+    with pytest.raises(ValueError):
         WordsRandomInsertAugmenter(0.3, [])
 
     with pytest.raises(Exception):
         WordsRandomSubstituteAugmenter(0.3, None)  # type: ignore
-    with pytest.raises(Exception):
+    with pytest.raises(ValueError):
         WordsRandomSubstituteAugmenter(0.3, [])
 
 
+@pytest.mark.parametrize("word_params", [-0.1, 1.5, (2.0, None, None)])
+def test_init_word_params_error(word_params: float | tuple[float, int | None, int | None]) -> None:
+    with pytest.raises(ValueError):
+        WordsRandomDeleteAugmenter(word_params)
+    with pytest.raises(ValueError):
+        WordsRandomSwapAugmenter(word_params)
+
+
 @pytest.mark.parametrize(
     "augmenter",
     [
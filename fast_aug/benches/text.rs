@@ -3,7 +3,7 @@ use fast_aug::text::*;
 
 mod common;
 use common::{bench_text_augmenter, get_config};
-use fast_aug::models::text::AlphabetModel;
+use fast_aug::models::text::{AlphabetModel, EmojiModel, KeyboardModel, SpellingModel, TfIdfModel, VocabModel};
 
 // Criterion entry point
 fn criterion_benchmark(c: &mut Criterion) {
@@ -26,6 +26,15 @@ fn criterion_benchmark(c: &mut Criterion) {
         "WordsRandomSubstituteAugmenter",
         &WordsRandomSubstituteAugmenter::new(TextAugmentParameters::default(), vocal.clone(), None),
     );
+    bench_text_augmenter(
+        &mut group,
+        "WordsRandomSubstituteAugmenter/vocab_en",
+        &WordsRandomSubstituteAugmenter::new(
+            TextAugmentParameters::default(),
+            VocabModel::from_locale_str("en").with_temperature(2.0),
+            None,
+        ),
+    );
     bench_text_augmenter(
         &mut group,
         "WordsRandomSwapAugmenter",
@@ -36,6 +45,59 @@ fn criterion_benchmark(c: &mut Criterion) {
         "WordsRandomDeleteAugmenter",
         &WordsRandomDeleteAugmenter::new(TextAugmentParameters::default(), None),
     );
+    bench_text_augmenter(
+        &mut group,
+        "WordsSpellingAugmenter",
+        &WordsSpellingAugmenter::new(
+            TextAugmentParameters::default(),
+            SpellingModel::from_file("../test_data/spelling_en.txt").expect("Unable to load spelling model"),
+            None,
+        ),
+    );
+    let tf_idf_model = TfIdfModel::fit(
+        std::fs::read_to_string("../test_data/tweet_eval_sentiment_test_text.txt")
+            .expect("Unable to read corpus")
+            .lines(),
+    );
+    bench_text_augmenter(
+        &mut group,
+        "WordsTfIdfAugmenter",
+        &WordsTfIdfAugmenter::new(
+            TextAction::Substitute,
+            TextAugmentParameters::default(),
+            tf_idf_model,
+            None,
+        ),
+    );
+    bench_text_augmenter(
+        &mut group,
+        "WordsEmojiAugmenter",
+        &WordsEmojiAugmenter::new(
+            TextAction::Insert,
+            TextAugmentParameters::default(),
+            EmojiModel::from_locale_str("en"),
+            None,
+        ),
+    );
+    bench_text_augmenter(
+        &mut group,
+        "EmojiNormalizer",
+        &EmojiNormalizer::new(TextAugmentParameters::default(), EmojiModel::from_locale_str("en")),
+    );
+    bench_text_augmenter(
+        &mut group,
+        "PunctuationAugmenter",
+        &PunctuationAugmenter::new(
+            TextAction::Insert,
+            TextAugmentParameters::default(),
+            AlphabetModel::from_locale_str("en"),
+        ),
+    );
+    bench_text_augmenter(
+        &mut group,
+        "WhitespaceAugmenter",
+        &WhitespaceAugmenter::new(WhitespaceMode::Split, TextAugmentParameters::default()),
+    );
     group.finish();
 
     let mut group = c.benchmark_group("chars");
@@ -65,11 +127,39 @@ fn criterion_benchmark(c: &mut Criterion) {
         "CharsRandomSwapAugmenter",
         &CharsRandomSwapAugmenter::new(TextAugmentParameters::default(), TextAugmentParameters::default(), None),
     );
+    bench_text_augmenter(
+        &mut group,
+        "CharsRandomSwapAugmenter/adjacent",
+        &CharsRandomSwapAugmenter::new(TextAugmentParameters::default(), TextAugmentParameters::default(), None)
+            .with_swap_mode(SwapMode::Adjacent),
+    );
     bench_text_augmenter(
         &mut group,
         "CharsRandomDeleteAugmenter",
         &CharsRandomDeleteAugmenter::new(TextAugmentParameters::default(), TextAugmentParameters::default(), None),
     );
+    bench_text_augmenter(
+        &mut group,
+        "CharsKeyboardAugmenter/substitute",
+        &CharsKeyboardAugmenter::new(
+            TextAction::Substitute,
+            TextAugmentParameters::default(),
+            TextAugmentParameters::default(),
+            KeyboardModel::from_locale_str("en"),
+            None,
+        ),
+    );
+    bench_text_augmenter(
+        &mut group,
+        "CharsKeyboardAugmenter/swap",
+        &CharsKeyboardAugmenter::new(
+            TextAction::Swap,
+            TextAugmentParameters::default(),
+            TextAugmentParameters::default(),
+            KeyboardModel::from_locale_str("en"),
+            None,
+        ),
+    );
     group.finish();
 }
 
@@ -1,29 +1,66 @@
-// use std::fs;
-// use std::fs::File;
-// use std::io::Write;
-// use flate2::write::GzEncoder;
-// use flate2::Compression;
-// use serde_json::Value;
-//
-//
-// /// Compress JSON file
-// /// Simply read the json and write it to a gzipped file
-// #[allow(dead_code)]
-// fn compress_json_file(input_path: &str) -> Result<(), Box<dyn std::error::Error>> {
-//     // Read the JSON file
-//     let data = fs::read_to_string(input_path)?;
-//     let json_value: Value = serde_json::from_str(&data)?;
-//
-//     // Write json to string
-//     let content = serde_json::to_string(&json_value)?;
-//
-//     // Write the JSON file to a gzipped file
-//     let output_path = format!("{}.gz", input_path);
-//     let file = File::create(output_path)?;
-//     let mut encoder = GzEncoder::new(file, Compression::default());
-//     encoder.write_all(content.as_bytes())?;
-//
-//     Ok(())
-// }
-//
-fn main() {}
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde_json::Value;
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// Locales of CLDR emoji annotations embedded into the library, see `models::text::EmojiModel`
+const EMOJI_LOCALES: [&str; 2] = ["en", "de"];
+
+/// Locales of word frequency lists embedded into the library, see `models::text::VocabModel`
+const VOCAB_LOCALES: [&str; 1] = ["en"];
+
+/// Write content to a gzipped file named after the input file in the output directory
+fn write_gzipped(content: &[u8], input_path: &Path, output_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let file_name = input_path.file_name().ok_or("input path should be a file")?;
+    let output_path = output_dir.join(format!("{}.gz", file_name.to_string_lossy()));
+    let file = File::create(output_path)?;
+    let mut encoder = GzEncoder::new(file, Compression::best());
+    encoder.write_all(content)?;
+    encoder.finish()?;
+
+    Ok(())
+}
+
+/// Compress JSON file
+/// Read the json (dropping formatting) and write it to a gzipped file in the output directory
+fn compress_json_file(input_path: &Path, output_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    // Read the JSON file
+    let data = fs::read_to_string(input_path)?;
+    let json_value: Value = serde_json::from_str(&data)?;
+
+    // Write json to string
+    let content = serde_json::to_string(&json_value)?;
+
+    // Write the JSON file to a gzipped file
+    write_gzipped(content.as_bytes(), input_path, output_dir)
+}
+
+/// Compress plain text file as is to a gzipped file in the output directory
+fn compress_text_file(input_path: &Path, output_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    write_gzipped(&fs::read(input_path)?, input_path, output_dir)
+}
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR should be set by cargo");
+    let emoji_dir = Path::new(&out_dir).join("emoji");
+    fs::create_dir_all(&emoji_dir).expect("emoji output directory should be created");
+
+    for locale in EMOJI_LOCALES {
+        let input_path = format!("data/emoji/annotations_{}.json", locale);
+        println!("cargo:rerun-if-changed={}", input_path);
+        compress_json_file(Path::new(&input_path), &emoji_dir).expect("emoji annotations should be compressed");
+    }
+
+    let vocab_dir = Path::new(&out_dir).join("vocab");
+    fs::create_dir_all(&vocab_dir).expect("vocab output directory should be created");
+
+    for locale in VOCAB_LOCALES {
+        let input_path = format!("data/vocab/vocab_{}.txt", locale);
+        println!("cargo:rerun-if-changed={}", input_path);
+        compress_text_file(Path::new(&input_path), &vocab_dir).expect("vocabulary should be compressed");
+    }
+}
@@ -0,0 +1,62 @@
+"""
+Generate embedded emoji annotations from the Unicode CLDR json data:
+https://github.com/unicode-org/cldr-json/tree/main/cldr-json/cldr-annotations-full
+
+Only the emoji listed in `EMOJI` are kept to keep the library small, keywords and names are not edited.
+Usage: python generate_annotations.py [--cldr-version 45.0.0] [--locales en de]
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import urllib.request
+from pathlib import Path
+
+
+CLDR_VERSION = "45.0.0"
+CLDR_URL = (
+    "https://raw.githubusercontent.com/unicode-org/cldr-json/{version}"
+    "/cldr-json/cldr-annotations-full/annotations/{locale}/annotations.json"
+)
+LOCALES = ["en", "de"]
+OUTPUT_FOLDER = Path(__file__).parent
+
+# Most used emoji on Twitter, without emoji presentation selectors and skin tones (ignored by EmojiModel)
+EMOJI = (
+    "😂 😭 😍 ❤ 🤔 🙄 💙 🔥 😊 😩 👍 😢 👌 😁 😎 🙏 😡 🎉 🏀 🏃 😱 💋 🦃 👏 😹 😉 🙃 🤗 😳 👇 🎄 😘 😒 📷 👀 😅 "
+    "💯 😀 😤 🚨 😄 😷 😇 💕 😥 😕 🍔 ✨ 😔 🙌 🍟 🎶 ‼ 😝 🖕 💥 👎 😞 😃 😏 💞 😑 😆 😐 😋 😈 👊 🙈 🍆 ✈ "
+    "💰 👸 💛 💚 💖 💪 🔪 😨 👅 🐍 💀 💜 💩 😮 🌞 😣 😬 🌎 ✊ ✌ 😫 🏆 💗 💦 💃 🌈 ⚡ 🔫 🍑 😪 🍀 🐶 🎃 ⚾ "
+    "🎥 ❗ 📺 🍕 🎁 🌹 🐸 💍 ⚽ 🍺 ☕ 🎂 🐱 🌙 ☀ 🎵 📚 🚗 🏠 💔"
+).split()
+
+
+def download_annotations(version: str, locale: str) -> dict:
+    with urllib.request.urlopen(CLDR_URL.format(version=version, locale=locale)) as response:
+        return json.load(response)
+
+
+def select_annotations(cldr_json: dict, emoji: list[str]) -> dict:
+    annotations = cldr_json["annotations"]["annotations"]
+    return {
+        "annotations": {
+            "identity": cldr_json["annotations"]["identity"],
+            "annotations": {e: annotations[e] for e in emoji if e in annotations},
+        }
+    }
+
+
+def main(version: str, locales: list[str]) -> None:
+    for locale in locales:
+        annotations = select_annotations(download_annotations(version, locale), EMOJI)
+        output_path = OUTPUT_FOLDER / f"annotations_{locale}.json"
+        output_path.write_text(json.dumps(annotations, ensure_ascii=False, indent=2) + "\n", encoding="utf-8")
+        print(f"{output_path}: {len(annotations['annotations']['annotations'])} emoji")
+
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("--cldr-version", default=CLDR_VERSION, help="cldr-json release tag")
+    parser.add_argument("--locales", nargs="+", default=LOCALES, help="CLDR locales to generate")
+    args = parser.parse_args()
+    main(args.cldr_version, args.locales)
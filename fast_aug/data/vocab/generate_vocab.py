@@ -0,0 +1,81 @@
+"""
+Generate embedded word frequency lists from a plain text corpus, one text per line.
+
+The word list is a hand-picked set of common English words (`WORDS`),
+counts are the number of occurrences of a word in the corpus, lowercased, with a minimum of 1.
+`vocab_en.txt` counts come from the TweetEval sentiment test split, stored in `test_data/`:
+https://github.com/cardiffnlp/tweeteval/tree/main/datasets/sentiment
+
+Usage: python generate_vocab.py [--corpus ../../../test_data/tweet_eval_sentiment_test_text.txt] [--locale en]
+"""
+
+from __future__ import annotations
+
+import argparse
+import collections
+import re
+from pathlib import Path
+
+
+OUTPUT_FOLDER = Path(__file__).parent
+CORPUS_PATH = OUTPUT_FOLDER / "../../../test_data/tweet_eval_sentiment_test_text.txt"
+LOCALE = "en"
+
+# Mentions, links and hashtags are not counted as words
+IGNORED_PATTERN = re.compile(r"@\w+|http\S+|#\w+")
+WORD_PATTERN = re.compile(r"[A-Za-z]+")
+
+WORDS = (
+    "the to a of and in i for you it on that this with be not we have he by my but they as all from at can about "
+    "will what so just like if no out your up his now me how people who or get when an do one new us there would "
+    "right she their our her know time over think only see love day need good go still them here than after want "
+    "some news bad because world him too make president say great first really back these read its next death "
+    "even into wall most young look control take also popular stop best today well college let then watch could "
+    "much team way year last other any better never win same life model work happy ever money man big real "
+    "support medical again show live help keep left very wait which sure use end call free give house country "
+    "always american war actually already party power care come hate believe change maybe old tell two long week "
+    "guy part lot night game little pay dead far job start season find own hard north play story family full "
+    "system point case city history song feel remember special talk everything run tonight try open fact move "
+    "name nice face government health plan funny wrong ask person hear high kill understand home place truth book "
+    "build food heart leader pretty probably amazing matter mind tax together woman awesome business buy finally "
+    "set friend important leave office policy report car few law line number oil single girl whole action follow "
+    "idea red soon stand step voice music once public yesterday official stay bring company issue reason fast "
+    "head late legal lose morning class force holiday school side water weekend baby die foreign huge kind others "
+    "past source turn building cut data drug green lead learn level meet piece police poor beautiful clear early "
+    "movie phone sad send sense son word almost child community cost fine member ready research speak street "
+    "write different education entire evidence boy continue drive easy excited hot month close court experience "
+    "film light moment sell tomorrow able art body common democratic fall happen hold picture program result role "
+    "seem society star air blue couple create final form player price record type view current hand land likely "
+    "market position process short wife add decision doctor eat event large mother paper pass rather sing test "
+    "available cold development effect figure ground room service small town wake area consider difference enjoy "
+    "general hour image industry later natural raise road serious allow effort expect fly information interest "
+    "main nation perhaps question space spend travel age birthday center private quite similar study dog father "
+    "laugh project technology coffee cry door often organization rate recent serve sit site sleep sun tired value "
+    "attention bed director personal pull situation winter dance dark eye kid lucky slow walk angry begin decide "
+    "hair practice reach central include minute offer patient religious remain simple sometimes tree cat certain "
+    "cultural grow population rain require tea teacher usually arm cook difficult drink field financial foot "
+    "relationship slowly suddenly suggest worker activity bright computer loud physical product provide quiet "
+    "summer table window environmental significant various appear quickly smile strange traditional swim warm"
+).split()
+
+
+def count_words(text: str) -> collections.Counter:
+    text = IGNORED_PATTERN.sub("", text)
+    return collections.Counter(word.lower() for word in WORD_PATTERN.findall(text))
+
+
+def main(corpus_path: Path, locale: str) -> None:
+    counts = count_words(corpus_path.read_text(encoding="utf-8"))
+    vocab = sorted(((word, max(counts[word], 1)) for word in WORDS), key=lambda item: (-item[1], item[0]))
+
+    output_path = OUTPUT_FOLDER / f"vocab_{locale}.txt"
+    output_path.write_text("".join(f"{word} {count}\n" for word, count in vocab), encoding="utf-8")
+    print(f"{output_path}: {len(vocab)} words")
+
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("--corpus", type=Path, default=CORPUS_PATH, help="plain text corpus, one text per line")
+    parser.add_argument("--locale", default=LOCALE, help="locale of the generated vocabulary")
+    args = parser.parse_args()
+    main(args.corpus, args.locale)
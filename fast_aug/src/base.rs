@@ -1,3 +1,6 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
 pub trait BaseAugmenter<T, K> {
     /// Public method to augment an input
     /// 1. Convert input to inner type (K)
@@ -9,16 +12,82 @@ pub trait BaseAugmenter<T, K> {
         self.convert_to_outer(output)
     }
 
+    /// Augment inputs sequentially, sharing one rng between all items
     fn augment_batch(&self, inputs: Vec<T>, rng: &mut dyn rand::RngCore) -> Vec<T> {
-        // TODO: parallelize, not just loop
-        // If less than X elements, just augment sequentially
-        // Otherwise, parallelize
         inputs.into_iter().map(|input| self.augment(input, rng)).collect()
     }
 
+    /// Augment inputs in parallel using rayon thread pool
+    /// Each item gets own rng derived from `seed` and item index (see `item_rng`),
+    /// so the output is the same regardless of the number of threads
+    #[cfg(feature = "parallel")]
+    fn augment_batch_par(&self, inputs: Vec<T>, seed: u64) -> Vec<T>
+    where
+        Self: Sync,
+        T: Send,
+    {
+        use rayon::prelude::*;
+
+        inputs
+            .into_par_iter()
+            .enumerate()
+            .map(|(index, input)| self.augment(input, &mut item_rng(seed, index)))
+            .collect()
+    }
+
     /// "Private" method to augment an input of inner type (K)
     fn augment_inner(&self, input: K, rng: &mut dyn rand::RngCore) -> K;
 
     fn convert_to_inner(&self, input: T) -> K;
     fn convert_to_outer(&self, input: K) -> T;
 }
+
+/// Create rng for the item of the batch, deterministic for the given `seed` and `index`
+/// Index is mixed into the seed with golden ratio multiplier, so neighbouring items get unrelated streams
+pub fn item_rng(seed: u64, index: usize) -> StdRng {
+    StdRng::seed_from_u64(seed ^ (index as u64).wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_item_rng_deterministic() {
+        use rand::Rng;
+
+        assert_eq!(item_rng(42, 3).gen::<u64>(), item_rng(42, 3).gen::<u64>());
+        assert_ne!(item_rng(42, 3).gen::<u64>(), item_rng(42, 4).gen::<u64>());
+        assert_ne!(item_rng(42, 3).gen::<u64>(), item_rng(43, 3).gen::<u64>());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_augment_batch_par_independent_of_threads() {
+        use crate::text::{Doc, TextAugmentParameters, WordsRandomSwapAugmenter};
+        use std::sync::Arc;
+
+        let augmenter: Arc<dyn BaseAugmenter<String, Doc> + Send + Sync> = Arc::new(WordsRandomSwapAugmenter::new(
+            TextAugmentParameters::new(0.5, None, None),
+            None,
+        ));
+        let inputs = (0..200)
+            .map(|i| format!("sample number {} with a few more words", i))
+            .collect::<Vec<String>>();
+
+        let run_with_threads = |num_threads: usize| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap()
+                .install(|| augmenter.augment_batch_par(inputs.clone(), 42))
+        };
+        let single_thread_output = run_with_threads(1);
+
+        assert_eq!(single_thread_output, run_with_threads(4));
+        assert_ne!(single_thread_output, augmenter.augment_batch_par(inputs.clone(), 7));
+        for (index, (input, output)) in inputs.into_iter().zip(&single_thread_output).enumerate() {
+            assert_eq!(&augmenter.augment(input, &mut item_rng(42, index)), output);
+        }
+    }
+}
@@ -0,0 +1,1277 @@
+use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
+use crate::flow::{ChanceAugmenter, RecordAugmenter, SelectorAugmenter, SequentialAugmenter};
+use crate::models::text::{
+    AlphabetModel, AveragedPerceptronTagger, EmojiModel, KeyboardModel, SpellingModel, TfIdfModel, VocabModel,
+    WordNetModel,
+};
+#[cfg(feature = "candle")]
+use crate::models::text::{
+    BackTranslationModel, CandleMaskedLmModel, CandleSeq2SeqModel, GenerationParameters, Seq2SeqArchitecture,
+    Seq2SeqModel,
+};
+use crate::text::{
+    Casing, CharUnit, CharsKeyboardAugmenter, CharsRandomDeleteAugmenter, CharsRandomInsertAugmenter,
+    CharsRandomSubstituteAugmenter, CharsRandomSwapAugmenter, Doc, EmbeddingsFormat, EmbeddingsWordsAugmenter,
+    EmojiNormalizer, PosFilter, PunctuationAugmenter, RandomPosAugmenter, SentencesRandomCropAugmenter,
+    SentencesRandomDeleteAugmenter, SentencesRandomSwapAugmenter, SwapMode, TextAction, TextAugmentParameters,
+    WhitespaceAugmenter, WhitespaceMode, WordsEmojiAugmenter, WordsRandomDeleteAugmenter, WordsRandomInsertAugmenter,
+    WordsRandomSubstituteAugmenter, WordsRandomSwapAugmenter, WordsSpellingAugmenter, WordsSynonymAugmenter,
+    WordsTfIdfAugmenter,
+};
+#[cfg(feature = "candle")]
+use crate::text::{ContextualWordsAugmenter, ParaphraseAugmenter};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Text augmenter built from config, shareable between threads and flow augmenters
+pub type TextAugmenterRef = Arc<dyn BaseAugmenter<String, Doc> + Send + Sync>;
+
+/// Builder of the custom (user-defined) augmenter from its raw config
+pub type CustomAugmenterBuilder =
+    Box<dyn Fn(&serde_json::Value, &AugmenterRegistry) -> Result<TextAugmenterRef> + Send + Sync>;
+
+/// Declarative config of the augmentation pipeline
+/// Augmenter type is stored in the "type" field, other fields are arguments of the respective augmenter.
+/// Models are described by locale string or path to the model file, so the config stays serializable.
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::config::AugmenterConfig;
+///
+/// let config = AugmenterConfig::from_json_str(r#"{
+///     "type": "Sequential",
+///     "augmenters": [
+///         {"type": "CharsRandomSwap", "word_params": 0.3, "char_params": [0.3, 1, 3]},
+///         {"type": "WordsRandomDelete", "word_params": {"p": 0.2}, "stopwords": ["not"]}
+///     ]
+/// }"#).unwrap();
+/// let augmenter = config.build().unwrap();
+///
+/// augmenter.augment("Some text!".to_string(), &mut rand::thread_rng());
+/// ```
+///
+/// Configs of unknown types are kept as `Custom`, configs of built-in types are parsed strictly,
+/// so invalid arguments are reported by the parser.
+///
+/// `Record` config augments structured records, fields are mapped to configs of their text augmenters
+/// (in the augmentation order). It is built with `build_record`, not with `build`, and can not be nested:
+/// ```rust
+/// use fast_aug::config::AugmenterConfig;
+///
+/// let config = AugmenterConfig::from_json_str(r#"{
+///     "type": "Record",
+///     "fields": {
+///         "premise": {"type": "WordsRandomSwap", "word_params": 0.3},
+///         "hypothesis": {"type": "WordsRandomDelete", "word_params": 0.2}
+///     },
+///     "consistent_substitutions": true
+/// }"#).unwrap();
+/// let augmenter = config.build_record().unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", tag = "type")]
+pub enum AugmenterConfig {
+    Chance {
+        augmenter: Box<AugmenterConfig>,
+        probability: f64,
+    },
+    Selector {
+        augmenters: Vec<AugmenterConfig>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        weights: Option<Vec<f32>>,
+    },
+    Sequential {
+        augmenters: Vec<AugmenterConfig>,
+    },
+    Record {
+        /// Field names mapped to configs of the text augmenters, fields are augmented in the given order
+        #[serde(with = "record_fields")]
+        fields: Vec<(String, AugmenterConfig)>,
+        #[serde(default)]
+        shared_seed: bool,
+        #[serde(default)]
+        consistent_substitutions: bool,
+    },
+    CharsRandomInsert {
+        word_params: TextAugmentParameters,
+        char_params: TextAugmentParameters,
+        #[serde(default)]
+        char_unit: CharUnit,
+        locale: String,
+        #[serde(default)]
+        casing: Casing,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    CharsRandomSubstitute {
+        word_params: TextAugmentParameters,
+        char_params: TextAugmentParameters,
+        #[serde(default)]
+        char_unit: CharUnit,
+        locale: String,
+        #[serde(default)]
+        casing: Casing,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    CharsRandomSwap {
+        word_params: TextAugmentParameters,
+        char_params: TextAugmentParameters,
+        #[serde(default)]
+        char_unit: CharUnit,
+        #[serde(default)]
+        swap_mode: SwapMode,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    CharsRandomDelete {
+        word_params: TextAugmentParameters,
+        char_params: TextAugmentParameters,
+        #[serde(default)]
+        char_unit: CharUnit,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    CharsKeyboard {
+        action: TextAction,
+        word_params: TextAugmentParameters,
+        char_params: TextAugmentParameters,
+        #[serde(default)]
+        char_unit: CharUnit,
+        locale: String,
+        #[serde(default)]
+        casing: Casing,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    WordsRandomInsert {
+        word_params: TextAugmentParameters,
+        /// Words to insert, used instead of the vocabulary loaded by `vocabulary_path` or `locale`
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        vocabulary: Vec<String>,
+        /// Path to the word frequency list, see `VocabModel::from_file`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        vocabulary_path: Option<String>,
+        /// Language tag of the vocabulary, embedded vocabulary is used if there is no vocabulary path
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        locale: Option<String>,
+        /// Sampling temperature of the vocabulary, see `VocabModel::with_temperature`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        temperature: Option<f64>,
+        #[serde(default)]
+        casing: Casing,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    WordsRandomSubstitute {
+        word_params: TextAugmentParameters,
+        /// Words to substitute with, used instead of the vocabulary loaded by `vocabulary_path` or `locale`
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        vocabulary: Vec<String>,
+        /// Path to the word frequency list, see `VocabModel::from_file`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        vocabulary_path: Option<String>,
+        /// Language tag of the vocabulary, embedded vocabulary is used if there is no vocabulary path
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        locale: Option<String>,
+        /// Sampling temperature of the vocabulary, see `VocabModel::with_temperature`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        temperature: Option<f64>,
+        #[serde(default)]
+        casing: Casing,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    WordsRandomSwap {
+        word_params: TextAugmentParameters,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    WordsRandomDelete {
+        word_params: TextAugmentParameters,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    WordsSpelling {
+        word_params: TextAugmentParameters,
+        /// Path to the misspellings dictionary, see `SpellingModel::from_file`
+        dictionary_path: String,
+        #[serde(default)]
+        casing: Casing,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    WordsSynonym {
+        word_params: TextAugmentParameters,
+        /// Path to the WordNet database directory or WN-LMF XML file, see `WordNetModel::from_path`
+        wordnet_path: String,
+        /// Substitute words with antonyms instead of synonyms
+        #[serde(default)]
+        antonyms: bool,
+        #[serde(default)]
+        casing: Casing,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    WordsEmoji {
+        action: TextAction,
+        word_params: TextAugmentParameters,
+        /// Language tag of the embedded CLDR annotations, see `EmojiModel::from_locale_str`
+        locale: String,
+        /// Path to the CLDR annotations json file, used instead of the embedded annotations
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        annotations_path: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    Punctuation {
+        action: TextAction,
+        punctuation_params: TextAugmentParameters,
+        locale: String,
+    },
+    Whitespace {
+        mode: WhitespaceMode,
+        whitespace_params: TextAugmentParameters,
+    },
+    EmojiNormalizer {
+        emoji_params: TextAugmentParameters,
+        /// Language tag of the embedded CLDR annotations, see `EmojiModel::from_locale_str`
+        locale: String,
+        /// Path to the CLDR annotations json file, used instead of the embedded annotations
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        annotations_path: Option<String>,
+    },
+    WordsEmbeddings {
+        word_params: TextAugmentParameters,
+        embeddings_path: String,
+        format: EmbeddingsFormat,
+        top_k: usize,
+        #[serde(default)]
+        casing: Casing,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    WordsTfIdf {
+        action: TextAction,
+        word_params: TextAugmentParameters,
+        /// Path to the json model, see `TfIdfModel::load_json`
+        model_path: String,
+        #[serde(default)]
+        casing: Casing,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    SentencesRandomSwap {
+        sentence_params: TextAugmentParameters,
+    },
+    SentencesRandomDelete {
+        sentence_params: TextAugmentParameters,
+    },
+    SentencesRandomCrop {
+        sentence_params: TextAugmentParameters,
+    },
+    RandomPos {
+        augmenter: Box<AugmenterConfig>,
+        /// Path to the averaged perceptron tagger json file, see `AveragedPerceptronTagger::load_json`
+        tagger_path: String,
+        #[serde(default)]
+        pos_filter: PosFilter,
+    },
+    #[cfg(feature = "candle")]
+    Paraphrase {
+        /// Path to the seq2seq checkpoint directory, see `CandleSeq2SeqModel::from_dir`
+        model_path: String,
+        architecture: Seq2SeqArchitecture,
+        /// Path to the checkpoint translating back to the source language, texts are back-translated if given
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        backward_model_path: Option<String>,
+        #[serde(default)]
+        generation_params: GenerationParameters,
+        #[serde(default = "default_batch_size")]
+        batch_size: usize,
+    },
+    #[cfg(feature = "candle")]
+    WordsContextual {
+        action: TextAction,
+        word_params: TextAugmentParameters,
+        /// Path to the masked language model checkpoint directory, see `CandleMaskedLmModel::from_dir`
+        model_path: String,
+        top_k: usize,
+        top_p: f32,
+        #[serde(default)]
+        casing: Casing,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stopwords: Option<HashSet<String>>,
+    },
+    /// Augmenter of any other type, built by the builder registered in AugmenterRegistry
+    #[serde(skip)]
+    Custom(serde_json::Value),
+}
+
+#[cfg(feature = "candle")]
+fn default_batch_size() -> usize {
+    8
+}
+
+/// Check if the config type is one of the built-in augmenters
+fn is_builtin_type(type_name: &str) -> bool {
+    let is_candle_type = matches!(type_name, "Paraphrase" | "WordsContextual");
+    matches!(
+        type_name,
+        "Chance"
+            | "Selector"
+            | "Sequential"
+            | "Record"
+            | "CharsRandomInsert"
+            | "CharsRandomSubstitute"
+            | "CharsRandomSwap"
+            | "CharsRandomDelete"
+            | "CharsKeyboard"
+            | "WordsRandomInsert"
+            | "WordsRandomSubstitute"
+            | "WordsRandomSwap"
+            | "WordsRandomDelete"
+            | "WordsSpelling"
+            | "WordsSynonym"
+            | "WordsEmoji"
+            | "Punctuation"
+            | "Whitespace"
+            | "EmojiNormalizer"
+            | "WordsEmbeddings"
+            | "WordsTfIdf"
+            | "SentencesRandomSwap"
+            | "SentencesRandomDelete"
+            | "SentencesRandomCrop"
+            | "RandomPos"
+    ) || (cfg!(feature = "candle") && is_candle_type)
+}
+
+/// (De)serialize record fields as a map, keeping the order of the fields
+mod record_fields {
+    use super::AugmenterConfig;
+    use serde::de::{MapAccess, Visitor};
+    use serde::{Deserializer, Serializer};
+    use std::fmt;
+
+    pub fn serialize<S: Serializer>(fields: &[(String, AugmenterConfig)], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(fields.iter().map(|(field, config)| (field, config)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<(String, AugmenterConfig)>, D::Error> {
+        struct FieldsVisitor;
+
+        impl<'de> Visitor<'de> for FieldsVisitor {
+            type Value = Vec<(String, AugmenterConfig)>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map of field names to augmenter configs")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut fields = Vec::with_capacity(map.size_hint().unwrap_or(0));
+                while let Some(field) = map.next_entry()? {
+                    fields.push(field);
+                }
+                Ok(fields)
+            }
+        }
+
+        deserializer.deserialize_map(FieldsVisitor)
+    }
+}
+
+impl Serialize for AugmenterConfig {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            AugmenterConfig::Custom(value) => value.serialize(serializer),
+            _ => AugmenterConfig::serialize(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AugmenterConfig {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let type_name = value.get("type").and_then(|type_name| type_name.as_str());
+        if type_name.is_some_and(is_builtin_type) {
+            AugmenterConfig::deserialize(value).map_err(serde::de::Error::custom)
+        } else {
+            Ok(AugmenterConfig::Custom(value))
+        }
+    }
+}
+
+impl AugmenterConfig {
+    /// Parse config from json string
+    pub fn from_json_str(content: &str) -> Result<Self> {
+        Ok(serde_json::from_str(content)?)
+    }
+
+    /// Parse config from yaml string
+    pub fn from_yaml_str(content: &str) -> Result<Self> {
+        serde_yaml::from_str(content).map_err(|error| Error::Config(error.to_string()))
+    }
+
+    /// Serialize config to json string
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Serialize config to yaml string
+    pub fn to_yaml_string(&self) -> Result<String> {
+        serde_yaml::to_string(self).map_err(|error| Error::Config(error.to_string()))
+    }
+
+    /// Load config from file, format is selected by file extension
+    /// `.yaml` and `.yml` files are loaded as yaml, all others as json
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml") | Some("yml") => AugmenterConfig::from_yaml_str(&content),
+            _ => AugmenterConfig::from_json_str(&content),
+        }
+    }
+
+    /// Build augmenter using default registry (built-in augmenters only)
+    pub fn build(&self) -> Result<TextAugmenterRef> {
+        AugmenterRegistry::new().build(self)
+    }
+
+    /// Build record augmenter from the `Record` config using default registry (built-in augmenters only)
+    pub fn build_record(&self) -> Result<RecordAugmenter> {
+        AugmenterRegistry::new().build_record(self)
+    }
+}
+
+/// Registry to build augmenters from configs
+/// Built-in augmenters are always available, custom augmenter types can be registered with `register`
+///
+/// # Examples
+/// ```rust
+/// use std::sync::Arc;
+/// use fast_aug::config::{AugmenterConfig, AugmenterRegistry};
+/// use fast_aug::text::{TextAugmentParameters, WordsRandomSwapAugmenter};
+///
+/// let mut registry = AugmenterRegistry::new();
+/// registry.register("AlwaysSwap", Box::new(|_config, _registry| {
+///     Ok(Arc::new(WordsRandomSwapAugmenter::new(TextAugmentParameters::new(1.0, None, None), None)))
+/// }));
+///
+/// let config = AugmenterConfig::from_json_str(r#"{"type": "Chance", "augmenter": {"type": "AlwaysSwap"}, "probability": 0.5}"#).unwrap();
+/// let augmenter = registry.build(&config).unwrap();
+/// ```
+#[derive(Default)]
+pub struct AugmenterRegistry {
+    custom_builders: HashMap<String, CustomAugmenterBuilder>,
+}
+
+impl AugmenterRegistry {
+    pub fn new() -> Self {
+        AugmenterRegistry::default()
+    }
+
+    /// Register builder for the custom augmenter type
+    /// Built-in type names can not be overridden
+    ///
+    /// # Arguments
+    /// * `type_name` - Value of the "type" field of the config
+    /// * `builder` - Function to build augmenter from the raw config, registry is passed to build nested configs
+    pub fn register(&mut self, type_name: &str, builder: CustomAugmenterBuilder) {
+        self.custom_builders.insert(type_name.to_string(), builder);
+    }
+
+    /// Build augmenter from the config, nested augmenters are built recursively
+    pub fn build(&self, config: &AugmenterConfig) -> Result<TextAugmenterRef> {
+        let augmenter: TextAugmenterRef = match config {
+            AugmenterConfig::Chance { augmenter, probability } => {
+                Arc::new(ChanceAugmenter::try_new(self.build(augmenter)?, *probability)?)
+            }
+            AugmenterConfig::Selector { augmenters, weights } => Arc::new(SelectorAugmenter::try_new(
+                self.build_all(augmenters)?,
+                weights.clone(),
+            )?),
+            AugmenterConfig::Sequential { augmenters } => {
+                Arc::new(SequentialAugmenter::try_new(self.build_all(augmenters)?)?)
+            }
+            AugmenterConfig::Record { .. } => {
+                return Err(Error::Config(
+                    "Record augments records, not texts, build it with `build_record`".to_string(),
+                ))
+            }
+            AugmenterConfig::CharsRandomInsert {
+                word_params,
+                char_params,
+                char_unit,
+                locale,
+                casing,
+                stopwords,
+            } => Arc::new(
+                CharsRandomInsertAugmenter::try_new(
+                    word_params.clone(),
+                    char_params.clone(),
+                    AlphabetModel::try_from_locale_str(locale)?,
+                    stopwords.clone(),
+                )?
+                .with_char_unit(*char_unit)
+                .with_casing(*casing),
+            ),
+            AugmenterConfig::CharsRandomSubstitute {
+                word_params,
+                char_params,
+                char_unit,
+                locale,
+                casing,
+                stopwords,
+            } => Arc::new(
+                CharsRandomSubstituteAugmenter::try_new(
+                    word_params.clone(),
+                    char_params.clone(),
+                    AlphabetModel::try_from_locale_str(locale)?,
+                    stopwords.clone(),
+                )?
+                .with_char_unit(*char_unit)
+                .with_casing(*casing),
+            ),
+            AugmenterConfig::CharsRandomSwap {
+                word_params,
+                char_params,
+                char_unit,
+                swap_mode,
+                stopwords,
+            } => Arc::new(
+                CharsRandomSwapAugmenter::try_new(word_params.clone(), char_params.clone(), stopwords.clone())?
+                    .with_swap_mode(*swap_mode)
+                    .with_char_unit(*char_unit),
+            ),
+            AugmenterConfig::CharsRandomDelete {
+                word_params,
+                char_params,
+                char_unit,
+                stopwords,
+            } => Arc::new(
+                CharsRandomDeleteAugmenter::try_new(word_params.clone(), char_params.clone(), stopwords.clone())?
+                    .with_char_unit(*char_unit),
+            ),
+            AugmenterConfig::CharsKeyboard {
+                action,
+                word_params,
+                char_params,
+                char_unit,
+                locale,
+                casing,
+                stopwords,
+            } => Arc::new(
+                CharsKeyboardAugmenter::try_new(
+                    *action,
+                    word_params.clone(),
+                    char_params.clone(),
+                    KeyboardModel::try_from_locale_str(locale)?,
+                    stopwords.clone(),
+                )?
+                .with_char_unit(*char_unit)
+                .with_casing(*casing),
+            ),
+            AugmenterConfig::WordsRandomInsert {
+                word_params,
+                vocabulary,
+                vocabulary_path,
+                locale,
+                temperature,
+                casing,
+                stopwords,
+            } => Arc::new(
+                WordsRandomInsertAugmenter::try_new(
+                    word_params.clone(),
+                    Self::load_vocab_model(vocabulary, vocabulary_path.as_deref(), locale.as_deref(), *temperature)?,
+                    stopwords.clone(),
+                )?
+                .with_casing(*casing),
+            ),
+            AugmenterConfig::WordsRandomSubstitute {
+                word_params,
+                vocabulary,
+                vocabulary_path,
+                locale,
+                temperature,
+                casing,
+                stopwords,
+            } => Arc::new(
+                WordsRandomSubstituteAugmenter::try_new(
+                    word_params.clone(),
+                    Self::load_vocab_model(vocabulary, vocabulary_path.as_deref(), locale.as_deref(), *temperature)?,
+                    stopwords.clone(),
+                )?
+                .with_casing(*casing),
+            ),
+            AugmenterConfig::WordsRandomSwap { word_params, stopwords } => Arc::new(WordsRandomSwapAugmenter::try_new(
+                word_params.clone(),
+                stopwords.clone(),
+            )?),
+            AugmenterConfig::WordsRandomDelete { word_params, stopwords } => Arc::new(
+                WordsRandomDeleteAugmenter::try_new(word_params.clone(), stopwords.clone())?,
+            ),
+            AugmenterConfig::SentencesRandomSwap { sentence_params } => {
+                Arc::new(SentencesRandomSwapAugmenter::try_new(sentence_params.clone())?)
+            }
+            AugmenterConfig::SentencesRandomDelete { sentence_params } => {
+                Arc::new(SentencesRandomDeleteAugmenter::try_new(sentence_params.clone())?)
+            }
+            AugmenterConfig::SentencesRandomCrop { sentence_params } => {
+                Arc::new(SentencesRandomCropAugmenter::try_new(sentence_params.clone())?)
+            }
+            AugmenterConfig::WordsSpelling {
+                word_params,
+                dictionary_path,
+                casing,
+                stopwords,
+            } => Arc::new(
+                WordsSpellingAugmenter::try_new(
+                    word_params.clone(),
+                    SpellingModel::from_file(dictionary_path)?,
+                    stopwords.clone(),
+                )?
+                .with_casing(*casing),
+            ),
+            AugmenterConfig::WordsSynonym {
+                word_params,
+                wordnet_path,
+                antonyms,
+                casing,
+                stopwords,
+            } => Arc::new(
+                WordsSynonymAugmenter::try_new(
+                    word_params.clone(),
+                    WordNetModel::from_path(wordnet_path)?,
+                    stopwords.clone(),
+                    *antonyms,
+                )?
+                .with_casing(*casing),
+            ),
+            AugmenterConfig::WordsEmoji {
+                action,
+                word_params,
+                locale,
+                annotations_path,
+                stopwords,
+            } => Arc::new(WordsEmojiAugmenter::try_new(
+                *action,
+                word_params.clone(),
+                Self::load_emoji_model(locale, annotations_path.as_deref())?,
+                stopwords.clone(),
+            )?),
+            AugmenterConfig::Punctuation {
+                action,
+                punctuation_params,
+                locale,
+            } => Arc::new(PunctuationAugmenter::try_new(
+                *action,
+                punctuation_params.clone(),
+                AlphabetModel::try_from_locale_str(locale)?,
+            )?),
+            AugmenterConfig::Whitespace {
+                mode,
+                whitespace_params,
+            } => Arc::new(WhitespaceAugmenter::try_new(*mode, whitespace_params.clone())?),
+            AugmenterConfig::EmojiNormalizer {
+                emoji_params,
+                locale,
+                annotations_path,
+            } => Arc::new(EmojiNormalizer::try_new(
+                emoji_params.clone(),
+                Self::load_emoji_model(locale, annotations_path.as_deref())?,
+            )?),
+            AugmenterConfig::WordsEmbeddings {
+                word_params,
+                embeddings_path,
+                format,
+                top_k,
+                casing,
+                stopwords,
+            } => Arc::new(
+                EmbeddingsWordsAugmenter::from_path(
+                    embeddings_path,
+                    *format,
+                    word_params.clone(),
+                    stopwords.clone(),
+                    *top_k,
+                )?
+                .with_casing(*casing),
+            ),
+            AugmenterConfig::WordsTfIdf {
+                action,
+                word_params,
+                model_path,
+                casing,
+                stopwords,
+            } => Arc::new(
+                WordsTfIdfAugmenter::try_new(
+                    *action,
+                    word_params.clone(),
+                    TfIdfModel::load_json(model_path)?,
+                    stopwords.clone(),
+                )?
+                .with_casing(*casing),
+            ),
+            AugmenterConfig::RandomPos {
+                augmenter,
+                tagger_path,
+                pos_filter,
+            } => Arc::new(RandomPosAugmenter::new(
+                self.build(augmenter)?,
+                Arc::new(AveragedPerceptronTagger::load_json(tagger_path)?),
+                pos_filter.clone(),
+            )),
+            #[cfg(feature = "candle")]
+            AugmenterConfig::Paraphrase {
+                model_path,
+                architecture,
+                backward_model_path,
+                generation_params,
+                batch_size,
+            } => {
+                let load_model =
+                    |path: &str| CandleSeq2SeqModel::from_dir(path, *architecture, generation_params.clone());
+                let model: Arc<dyn Seq2SeqModel> = match backward_model_path {
+                    Some(backward_model_path) => Arc::new(BackTranslationModel::new(
+                        Arc::new(load_model(model_path)?),
+                        Arc::new(load_model(backward_model_path)?),
+                    )),
+                    None => Arc::new(load_model(model_path)?),
+                };
+                Arc::new(ParaphraseAugmenter::new(model, *batch_size))
+            }
+            #[cfg(feature = "candle")]
+            AugmenterConfig::WordsContextual {
+                action,
+                word_params,
+                model_path,
+                top_k,
+                top_p,
+                casing,
+                stopwords,
+            } => Arc::new(
+                ContextualWordsAugmenter::try_new(
+                    Arc::new(CandleMaskedLmModel::from_dir(model_path)?),
+                    *action,
+                    word_params.clone(),
+                    stopwords.clone(),
+                    *top_k,
+                    *top_p,
+                )?
+                .with_casing(*casing),
+            ),
+            AugmenterConfig::Custom(value) => self.build_custom(value)?,
+        };
+        Ok(augmenter)
+    }
+
+    /// Build record augmenter from the `Record` config, augmenters of the fields are built with `build`
+    pub fn build_record(&self, config: &AugmenterConfig) -> Result<RecordAugmenter> {
+        match config {
+            AugmenterConfig::Record {
+                fields,
+                shared_seed,
+                consistent_substitutions,
+            } => {
+                let fields = fields
+                    .iter()
+                    .map(|(field, config)| Ok((field.clone(), self.build(config)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                RecordAugmenter::try_new(fields, *shared_seed, *consistent_substitutions)
+            }
+            _ => Err(Error::Config(
+                "only Record config can be built as a record augmenter".to_string(),
+            )),
+        }
+    }
+
+    fn build_all(&self, configs: &[AugmenterConfig]) -> Result<Vec<TextAugmenterRef>> {
+        configs.iter().map(|config| self.build(config)).collect()
+    }
+
+    /// Load vocabulary from the words listed in the config if any, otherwise from the frequency list file if given,
+    /// otherwise from the embedded vocabulary for the locale
+    fn load_vocab_model(
+        vocabulary: &[String],
+        vocabulary_path: Option<&str>,
+        locale: Option<&str>,
+        temperature: Option<f64>,
+    ) -> Result<VocabModel> {
+        let locale_string = locale.unwrap_or_default().to_string();
+        let vocab_model = if !vocabulary.is_empty() {
+            VocabModel::from_words(vocabulary.to_vec(), locale_string)
+        } else {
+            match (vocabulary_path, locale) {
+                (Some(path), _) => VocabModel::from_file(path, locale_string)?,
+                (None, Some(locale)) => VocabModel::try_from_locale_str(locale)?,
+                (None, None) => VocabModel::from_words(vec![], locale_string),
+            }
+        };
+        match temperature {
+            Some(temperature) => vocab_model.try_with_temperature(temperature),
+            None => Ok(vocab_model),
+        }
+    }
+
+    /// Load emoji model from the annotations file if given, otherwise from the embedded annotations for the locale
+    fn load_emoji_model(locale: &str, annotations_path: Option<&str>) -> Result<EmojiModel> {
+        match annotations_path {
+            Some(path) => EmojiModel::from_path(path),
+            None => EmojiModel::try_from_locale_str(locale),
+        }
+    }
+
+    fn build_custom(&self, value: &serde_json::Value) -> Result<TextAugmenterRef> {
+        let type_name = value
+            .get("type")
+            .and_then(|type_name| type_name.as_str())
+            .ok_or_else(|| Error::Config(format!("missing \"type\" field in {}", value)))?;
+        match self.custom_builders.get(type_name) {
+            Some(builder) => builder(value, self),
+            None => Err(Error::Config(format!(
+                "unknown augmenter type \"{}\" or invalid arguments in {}",
+                type_name, value
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flow::Record;
+    use test_case::test_case;
+
+    fn stopwords() -> Option<HashSet<String>> {
+        Some(HashSet::from(["not".to_string()]))
+    }
+
+    fn all_builtin_configs() -> Vec<AugmenterConfig> {
+        let params = TextAugmentParameters::new(0.3, Some(1), Some(5));
+        vec![
+            AugmenterConfig::CharsRandomInsert {
+                word_params: params.clone(),
+                char_params: params.clone(),
+                char_unit: CharUnit::Grapheme,
+                locale: "en".to_string(),
+                casing: Casing::Random,
+                stopwords: stopwords(),
+            },
+            AugmenterConfig::CharsRandomSubstitute {
+                word_params: params.clone(),
+                char_params: params.clone(),
+                char_unit: CharUnit::Grapheme,
+                locale: "de".to_string(),
+                casing: Casing::Preserve,
+                stopwords: None,
+            },
+            AugmenterConfig::CharsRandomSwap {
+                word_params: params.clone(),
+                char_params: params.clone(),
+                char_unit: CharUnit::Grapheme,
+                swap_mode: SwapMode::Random,
+                stopwords: None,
+            },
+            AugmenterConfig::CharsRandomSwap {
+                word_params: params.clone(),
+                char_params: params.clone(),
+                char_unit: CharUnit::Grapheme,
+                swap_mode: SwapMode::Middle,
+                stopwords: None,
+            },
+            AugmenterConfig::CharsRandomDelete {
+                word_params: params.clone(),
+                char_params: params.clone(),
+                char_unit: CharUnit::CodePoint,
+                stopwords: None,
+            },
+            AugmenterConfig::CharsKeyboard {
+                action: TextAction::Swap,
+                word_params: params.clone(),
+                char_params: params.clone(),
+                char_unit: CharUnit::Grapheme,
+                locale: "en".to_string(),
+                casing: Casing::Preserve,
+                stopwords: None,
+            },
+            AugmenterConfig::WordsRandomInsert {
+                word_params: params.clone(),
+                vocabulary: vec!["a".to_string(), "b".to_string()],
+                vocabulary_path: None,
+                locale: None,
+                temperature: None,
+                casing: Casing::Preserve,
+                stopwords: None,
+            },
+            AugmenterConfig::WordsRandomInsert {
+                word_params: params.clone(),
+                vocabulary: vec![],
+                vocabulary_path: None,
+                locale: Some("en".to_string()),
+                temperature: Some(2.0),
+                casing: Casing::Preserve,
+                stopwords: None,
+            },
+            AugmenterConfig::WordsRandomSubstitute {
+                word_params: params.clone(),
+                vocabulary: vec!["a".to_string(), "b".to_string()],
+                vocabulary_path: None,
+                locale: None,
+                temperature: None,
+                casing: Casing::AsIs,
+                stopwords: stopwords(),
+            },
+            AugmenterConfig::WordsRandomSubstitute {
+                word_params: params.clone(),
+                vocabulary: vec![],
+                vocabulary_path: Some("data/vocab/vocab_en.txt".to_string()),
+                locale: Some("en".to_string()),
+                temperature: None,
+                casing: Casing::Preserve,
+                stopwords: stopwords(),
+            },
+            AugmenterConfig::WordsRandomSwap {
+                word_params: params.clone(),
+                stopwords: None,
+            },
+            AugmenterConfig::WordsRandomDelete {
+                word_params: params.clone(),
+                stopwords: None,
+            },
+            AugmenterConfig::SentencesRandomSwap {
+                sentence_params: params.clone(),
+            },
+            AugmenterConfig::SentencesRandomDelete {
+                sentence_params: params.clone(),
+            },
+            AugmenterConfig::SentencesRandomCrop {
+                sentence_params: params.clone(),
+            },
+            AugmenterConfig::WordsSpelling {
+                word_params: params.clone(),
+                dictionary_path: "../test_data/spelling_en.txt".to_string(),
+                casing: Casing::Preserve,
+                stopwords: None,
+            },
+            AugmenterConfig::WordsSynonym {
+                word_params: params.clone(),
+                wordnet_path: "../test_data/wordnet_en".to_string(),
+                antonyms: false,
+                casing: Casing::Preserve,
+                stopwords: None,
+            },
+            AugmenterConfig::WordsSynonym {
+                word_params: params.clone(),
+                wordnet_path: "../test_data/wordnet_de.xml".to_string(),
+                antonyms: true,
+                casing: Casing::Preserve,
+                stopwords: stopwords(),
+            },
+            AugmenterConfig::WordsEmoji {
+                action: TextAction::Insert,
+                word_params: params.clone(),
+                locale: "en".to_string(),
+                annotations_path: None,
+                stopwords: stopwords(),
+            },
+            AugmenterConfig::Punctuation {
+                action: TextAction::Insert,
+                punctuation_params: params.clone(),
+                locale: "en".to_string(),
+            },
+            AugmenterConfig::Punctuation {
+                action: TextAction::Substitute,
+                punctuation_params: params.clone(),
+                locale: "ja".to_string(),
+            },
+            AugmenterConfig::Whitespace {
+                mode: WhitespaceMode::Split,
+                whitespace_params: params.clone(),
+            },
+            AugmenterConfig::Whitespace {
+                mode: WhitespaceMode::LineBreak,
+                whitespace_params: params.clone(),
+            },
+            AugmenterConfig::EmojiNormalizer {
+                emoji_params: params.clone(),
+                locale: "de".to_string(),
+                annotations_path: Some("data/emoji/annotations_de.json".to_string()),
+            },
+            AugmenterConfig::RandomPos {
+                augmenter: Box::new(AugmenterConfig::WordsRandomDelete {
+                    word_params: params.clone(),
+                    stopwords: None,
+                }),
+                tagger_path: "../test_data/pos_tagger_en.json".to_string(),
+                pos_filter: PosFilter::new(None, Some(HashSet::from(["DT".to_string()]))),
+            },
+            AugmenterConfig::WordsEmbeddings {
+                word_params: params.clone(),
+                embeddings_path: "../test_data/embeddings_en.txt".to_string(),
+                format: EmbeddingsFormat::TextDims,
+                top_k: 3,
+                casing: Casing::Preserve,
+                stopwords: None,
+            },
+        ]
+    }
+
+    fn pipeline_config() -> AugmenterConfig {
+        AugmenterConfig::Sequential {
+            augmenters: vec![
+                AugmenterConfig::Chance {
+                    augmenter: Box::new(AugmenterConfig::Selector {
+                        augmenters: all_builtin_configs(),
+                        weights: None,
+                    }),
+                    probability: 0.5,
+                },
+                AugmenterConfig::WordsRandomSwap {
+                    word_params: TextAugmentParameters::default(),
+                    stopwords: None,
+                },
+            ],
+        }
+    }
+
+    fn record_config() -> AugmenterConfig {
+        AugmenterConfig::Record {
+            fields: vec![
+                ("question".to_string(), pipeline_config()),
+                (
+                    "context".to_string(),
+                    AugmenterConfig::WordsRandomDelete {
+                        word_params: TextAugmentParameters::default(),
+                        stopwords: None,
+                    },
+                ),
+            ],
+            shared_seed: true,
+            consistent_substitutions: true,
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let config = pipeline_config();
+
+        let json = config.to_json_string().unwrap();
+        let loaded_config = AugmenterConfig::from_json_str(&json).unwrap();
+
+        assert_eq!(loaded_config, config);
+    }
+
+    #[test]
+    fn test_yaml_round_trip() {
+        let config = pipeline_config();
+
+        let yaml = config.to_yaml_string().unwrap();
+        let loaded_config = AugmenterConfig::from_yaml_str(&yaml).unwrap();
+
+        assert_eq!(loaded_config, config);
+    }
+
+    #[test]
+    fn test_build_all_builtin() {
+        let rng = &mut rand::thread_rng();
+        for config in all_builtin_configs() {
+            let augmenter = config.build().unwrap();
+            augmenter.augment("Some text, not a long one!".to_string(), rng);
+        }
+        pipeline_config()
+            .build()
+            .unwrap()
+            .augment("Some text!".to_string(), rng);
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let config = record_config();
+
+        let json = config.to_json_string().unwrap();
+        let yaml = config.to_yaml_string().unwrap();
+
+        assert_eq!(AugmenterConfig::from_json_str(&json).unwrap(), config);
+        assert_eq!(AugmenterConfig::from_yaml_str(&yaml).unwrap(), config);
+    }
+
+    #[test]
+    fn test_build_record() {
+        let config = AugmenterConfig::from_json_str(
+            r#"{
+                "type": "Record",
+                "fields": {
+                    "premise": {"type": "WordsRandomSwap", "word_params": 0.3},
+                    "hypothesis": {"type": "WordsRandomDelete", "word_params": 0.0}
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            &config,
+            AugmenterConfig::Record { fields, shared_seed: false, consistent_substitutions: false }
+                if fields[0].0 == "premise" && fields[1].0 == "hypothesis"
+        ));
+
+        let augmenter = config.build_record().unwrap();
+        let record = Record::from([("hypothesis".to_string(), "A cat sleeps".to_string())]);
+        let output = augmenter.augment(record, &mut rand::thread_rng());
+
+        assert_eq!(
+            augmenter.field_names().collect::<Vec<_>>(),
+            vec!["premise", "hypothesis"]
+        );
+        assert_eq!(output["hypothesis"], "A cat sleeps");
+    }
+
+    #[test_case(r#"{"type": "Record", "fields": {}}"# ; "empty fields")]
+    #[test_case(r#"{"type": "Record", "fields": {"text": {"type": "WordsRandomSwap", "word_params": 1.5}}}"# ; "invalid field augmenter")]
+    #[test_case(r#"{"type": "WordsRandomSwap", "word_params": 0.3}"# ; "not a record")]
+    fn test_build_record_invalid(content: &str) {
+        let config = AugmenterConfig::from_json_str(content).unwrap();
+        assert!(config.build_record().is_err());
+    }
+
+    #[test]
+    fn test_build_record_as_text_augmenter() {
+        let nested_config = AugmenterConfig::Sequential {
+            augmenters: vec![record_config()],
+        };
+
+        assert!(matches!(record_config().build(), Err(Error::Config(_))));
+        assert!(matches!(nested_config.build(), Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_build_tf_idf() {
+        let path = std::env::temp_dir().join("fast_aug_test_config_tf_idf_model.json");
+        TfIdfModel::fit(["the cat", "the dog"]).save_json(&path).unwrap();
+        let config = AugmenterConfig::WordsTfIdf {
+            action: TextAction::Insert,
+            word_params: TextAugmentParameters::default(),
+            model_path: path.to_str().unwrap().to_string(),
+            casing: Casing::Preserve,
+            stopwords: None,
+        };
+
+        let augmenter = config.build();
+        fs::remove_file(&path).unwrap();
+
+        assert!(augmenter.is_ok());
+    }
+
+    #[test]
+    fn test_from_yaml_str() {
+        let yaml = r#"
+type: Selector
+weights: [0.7, 0.3]
+augmenters:
+  - type: CharsKeyboard
+    action: substitute
+    word_params: 0.3
+    char_params: [0.1, 1, 2]
+    locale: en-US
+  - type: WordsRandomInsert
+    word_params: {p: 0.2, max_elements: 3}
+    vocabulary: [foo, bar]
+"#;
+        let config = AugmenterConfig::from_yaml_str(yaml).unwrap();
+
+        assert!(matches!(&config, AugmenterConfig::Selector { augmenters, .. } if augmenters.len() == 2));
+        assert!(config.build().is_ok());
+    }
+
+    #[test_case(r#"{"type": "WordsRandomSwap", "word_params": 1.5}"# ; "invalid probability")]
+    #[test_case(r#"{"type": "CharsRandomInsert", "word_params": 0.3, "char_params": 0.3, "locale": "xx-!!"}"# ; "invalid locale")]
+    #[test_case(r#"{"type": "WordsRandomInsert", "word_params": 0.3, "vocabulary": []}"# ; "empty vocabulary")]
+    #[test_case(r#"{"type": "WordsRandomInsert", "word_params": 0.3, "locale": "fr"}"# ; "no embedded vocabulary")]
+    #[test_case(r#"{"type": "WordsRandomInsert", "word_params": 0.3, "locale": "en", "temperature": 0}"# ; "zero temperature")]
+    #[test_case(r#"{"type": "Sequential", "augmenters": []}"# ; "empty augmenters")]
+    #[test_case(r#"{"type": "WordsSpelling", "word_params": 0.3, "dictionary_path": "not_existing_file.txt"}"# ; "not existing file")]
+    #[test_case(r#"{"type": "NotExistingAugmenter"}"# ; "unknown type")]
+    #[test_case(r#"{"word_params": 0.3}"# ; "missing type")]
+    #[test_case(r#"{"type": "RandomPos", "augmenter": {"type": "WordsRandomSwap", "word_params": 0.3}, "tagger_path": "not_existing_file.json"}"# ; "not existing tagger")]
+    fn test_build_invalid(content: &str) {
+        let config = AugmenterConfig::from_json_str(content).unwrap();
+        assert!(config.build().is_err());
+    }
+
+    #[test_case(r#"{"type": "WordsRandomSwap"}"#, "missing field `word_params`" ; "missing argument")]
+    #[test_case(r#"{"type": "CharsRandomSwap", "word_params": 0.3, "char_params": 0.3, "swap_mode": "nope"}"#, "unknown variant `nope`" ; "invalid argument")]
+    #[test_case(r#"{"type": "Sequential", "augmenters": [{"type": "WordsRandomDelete", "word_param": 0.3}]}"#, "missing field `word_params`" ; "invalid nested argument")]
+    fn test_parse_invalid_builtin(content: &str, expected_error: &str) {
+        let error = AugmenterConfig::from_json_str(content).unwrap_err().to_string();
+
+        assert!(error.contains(expected_error), "unexpected error: {}", error);
+        assert!(!error.contains("unknown augmenter type"), "unexpected error: {}", error);
+    }
+
+    #[test]
+    fn test_builtin_types() {
+        for config in all_builtin_configs() {
+            let value = serde_json::to_value(&config).unwrap();
+            let type_name = value["type"].as_str().unwrap();
+
+            assert!(is_builtin_type(type_name), "{} is not a built-in type", type_name);
+            assert_eq!(AugmenterConfig::from_json_str(&value.to_string()).unwrap(), config);
+        }
+        assert!(is_builtin_type("Record"));
+        assert!(!is_builtin_type("Custom"));
+    }
+
+    #[cfg(feature = "candle")]
+    #[test]
+    fn test_candle_configs() {
+        let content = r#"[
+            {"type": "Paraphrase", "model_path": "opus-mt-en-de", "architecture": "marian",
+             "backward_model_path": "opus-mt-de-en", "generation_params": {"temperature": 0.7}},
+            {"type": "WordsContextual", "action": "substitute", "word_params": 0.3,
+             "model_path": "bert-base-uncased", "top_k": 10, "top_p": 0.9}
+        ]"#;
+        let configs: Vec<AugmenterConfig> = serde_json::from_str(content).unwrap();
+
+        assert!(matches!(
+            &configs[0],
+            AugmenterConfig::Paraphrase { generation_params, batch_size: 8, .. }
+                if generation_params.temperature == Some(0.7) && generation_params.max_length == 128
+        ));
+        assert!(matches!(
+            &configs[1],
+            AugmenterConfig::WordsContextual { top_k: 10, .. }
+        ));
+        for config in configs {
+            assert!(matches!(config.build(), Err(Error::Io(_))));
+        }
+    }
+
+    #[test]
+    fn test_build_custom() {
+        let mut registry = AugmenterRegistry::new();
+        registry.register(
+            "Identity",
+            Box::new(|_, _| {
+                Ok(Arc::new(WordsRandomDeleteAugmenter::new(
+                    TextAugmentParameters::new(0.0, None, None),
+                    None,
+                )))
+            }),
+        );
+        let config = AugmenterConfig::from_json_str(
+            r#"{"type": "Sequential", "augmenters": [{"type": "Identity"}, {"type": "Identity", "arg": 1}]}"#,
+        )
+        .unwrap();
+
+        let augmenter = registry.build(&config).unwrap();
+
+        assert_eq!(
+            augmenter.augment("Some text!".to_string(), &mut rand::thread_rng()),
+            "Some text!"
+        );
+        assert!(config.build().is_err());
+    }
+}
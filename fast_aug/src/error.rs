@@ -0,0 +1,121 @@
+use std::fmt;
+
+/// Errors returned by fallible (`try_*`) constructors and model loaders
+#[derive(Debug)]
+pub enum Error {
+    /// Probability (or expected percent of elements) is out of [0, 1] range
+    InvalidProbability(f64),
+    /// Language tag can not be parsed or there is no data for the locale
+    InvalidLocale(String),
+    /// Vocabulary to sample words or chars from is empty
+    EmptyVocabulary,
+    /// Flow augmenter is created without any augmenters
+    EmptyAugmenters,
+    /// Number of weights does not match number of elements
+    MismatchedWeights { expected: usize, actual: usize },
+    /// Weights can not be used for sampling (negative, not finite or all zero)
+    InvalidWeights(String),
+    /// Number of labels (tags) does not match number of tokens
+    MismatchedLabels { expected: usize, actual: usize },
+    /// Labelled span is out of the text bounds or has start > end
+    InvalidSpan { start: usize, end: usize, text_len: usize },
+    /// Augmenter does not support the requested action
+    UnsupportedAction(String),
+    /// IO error while reading or writing model files
+    Io(std::io::Error),
+    /// Error while parsing or serializing json model files
+    Json(serde_json::Error),
+    /// Error while reading embeddings
+    Embeddings(finalfusion::error::Error),
+    /// Augmenter config can not be parsed or built
+    Config(String),
+    /// Model can not be loaded or fails to run inference (e.g. seq2seq generation)
+    Model(String),
+}
+
+/// Result type with fast_aug::Error
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidProbability(p) => write!(f, "probability must be between 0 and 1, got {}", p),
+            Error::InvalidLocale(locale) => write!(f, "invalid or unsupported locale: {}", locale),
+            Error::EmptyVocabulary => write!(f, "vocabulary can not be empty"),
+            Error::EmptyAugmenters => write!(f, "at least one augmenter is required"),
+            Error::MismatchedWeights { expected, actual } => {
+                write!(f, "expected {} weights, got {}", expected, actual)
+            }
+            Error::InvalidWeights(reason) => write!(f, "invalid weights: {}", reason),
+            Error::MismatchedLabels { expected, actual } => {
+                write!(f, "expected {} labels, got {}", expected, actual)
+            }
+            Error::InvalidSpan { start, end, text_len } => {
+                write!(f, "invalid span [{}, {}) for text of {} chars", start, end, text_len)
+            }
+            Error::UnsupportedAction(reason) => write!(f, "unsupported action: {}", reason),
+            Error::Io(error) => write!(f, "io error: {}", error),
+            Error::Json(error) => write!(f, "json error: {}", error),
+            Error::Embeddings(error) => write!(f, "embeddings error: {}", error),
+            Error::Config(reason) => write!(f, "invalid augmenter config: {}", reason),
+            Error::Model(reason) => write!(f, "model error: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            Error::Json(error) => Some(error),
+            Error::Embeddings(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Json(error)
+    }
+}
+
+impl From<finalfusion::error::Error> for Error {
+    fn from(error: finalfusion::error::Error) -> Self {
+        Error::Embeddings(error)
+    }
+}
+
+#[cfg(feature = "candle")]
+impl From<candle_core::Error> for Error {
+    fn from(error: candle_core::Error) -> Self {
+        Error::Model(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Error::InvalidProbability(1.5), "probability must be between 0 and 1, got 1.5")]
+    #[test_case(Error::InvalidLocale("xx".to_string()), "invalid or unsupported locale: xx")]
+    #[test_case(Error::MismatchedWeights { expected: 2, actual: 3 }, "expected 2 weights, got 3")]
+    #[test_case(Error::InvalidSpan { start: 3, end: 10, text_len: 5 }, "invalid span [3, 10) for text of 5 chars")]
+    fn test_display(error: Error, expected: &str) {
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[test]
+    fn test_from_io_error() {
+        let error: Error = std::io::Error::new(std::io::ErrorKind::NotFound, "file").into();
+        assert!(matches!(error, Error::Io(_)));
+        assert!(std::error::Error::source(&error).is_some());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
 use rand::Rng;
 use std::sync::Arc;
 
@@ -14,6 +15,14 @@ impl<T, K> ChanceAugmenter<T, K> {
     pub fn new(augmenter: Arc<dyn BaseAugmenter<T, K> + Send + Sync>, probability: f64) -> Self {
         ChanceAugmenter { augmenter, probability }
     }
+
+    /// Create chance augmenter, returns `Error::InvalidProbability` if probability is not between 0 and 1
+    pub fn try_new(augmenter: Arc<dyn BaseAugmenter<T, K> + Send + Sync>, probability: f64) -> Result<Self> {
+        if !(0.0..=1.0).contains(&probability) {
+            return Err(Error::InvalidProbability(probability));
+        }
+        Ok(ChanceAugmenter::new(augmenter, probability))
+    }
 }
 
 impl<T, K> BaseAugmenter<T, K> for ChanceAugmenter<T, K> {
@@ -92,4 +101,12 @@ mod tests {
         // Allow for some variance in the number of changes due to randomness
         assert!((num_changes as f64 / 1000.0 - p).abs() < 0.1);
     }
+
+    #[test_case(-0.1 ; "negative probability")]
+    #[test_case(1.1 ; "probability more than 1")]
+    #[test_case(f64::NAN ; "nan probability")]
+    fn test_try_new_invalid_probability(p: f64) {
+        let result = ChanceAugmenter::try_new(Arc::new(DummyMultiplyAugmenter), p);
+        assert!(matches!(result, Err(Error::InvalidProbability(_))));
+    }
 }
@@ -1,9 +1,11 @@
 mod base;
 mod chance;
+mod record;
 mod selector;
 mod sequential;
 
 // pub use base::BaseFlowAugmenter;
 pub use chance::ChanceAugmenter;
+pub use record::{Record, RecordAugmenter};
 pub use selector::SelectorAugmenter;
 pub use sequential::SequentialAugmenter;
@@ -0,0 +1,321 @@
+use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
+use crate::text::{Casing, Change, Doc, TextAction, Token, TokenType};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::collections::HashMap;
+use std::sync::Arc;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Record (structured example) with named text fields, e.g. premise/hypothesis or question/context
+pub type Record = HashMap<String, String>;
+
+/// Augment records field by field, each field with its own text augmenter (or pipeline)
+/// Fields are augmented in the given order, fields not configured (or missing in the record) are left unchanged.
+///
+/// Randomness can be shared across fields:
+/// * `shared_seed` - every field is augmented with rng seeded by the same per-record seed,
+///   so the same pipelines make the same random decisions (e.g. ChanceAugmenter applied to all fields or to none)
+/// * `consistent_substitutions` - words changed in-place in a field (substituted, misspelled, etc.)
+///   are changed the same way in the following fields, e.g. "cat" -> "dog" in both premise and hypothesis,
+///   words are matched ignoring case and replacements keep the casing of the matched word ("Cat" -> "Dog")
+///
+/// # Examples
+/// ```rust
+/// use std::sync::Arc;
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::flow::{Record, RecordAugmenter};
+/// use fast_aug::text::{TextAugmentParameters, WordsRandomSubstituteAugmenter};
+///
+/// let substitute = Arc::new(WordsRandomSubstituteAugmenter::new(
+///     TextAugmentParameters::new(1.0, None, None),
+///     vec!["dog".to_string()],
+///     None,
+/// ));
+/// let augmenter = RecordAugmenter::new(
+///     vec![("premise".to_string(), substitute)],
+///     false,
+///     true,
+/// );
+///
+/// let record = Record::from([
+///     ("premise".to_string(), "A cat sleeps".to_string()),
+///     ("hypothesis".to_string(), "A cat".to_string()),
+/// ]);
+/// let output = augmenter.augment(record, &mut rand::thread_rng());
+///
+/// assert_eq!(output["premise"], "Dog dog dog");
+/// assert_eq!(output["hypothesis"], "A cat");
+/// ```
+pub struct RecordAugmenter {
+    /// Pairs of field name and augmenter to apply to the field
+    /// Added Send + Sync for multi-threading safety
+    fields: Vec<(String, Arc<dyn BaseAugmenter<String, Doc> + Send + Sync>)>,
+    /// Augment all fields with rng seeded by the same per-record seed
+    shared_seed: bool,
+    /// Repeat in-place word changes of the previous fields in the following fields
+    consistent_substitutions: bool,
+}
+
+impl RecordAugmenter {
+    pub fn new(
+        fields: Vec<(String, Arc<dyn BaseAugmenter<String, Doc> + Send + Sync>)>,
+        shared_seed: bool,
+        consistent_substitutions: bool,
+    ) -> Self {
+        RecordAugmenter {
+            fields,
+            shared_seed,
+            consistent_substitutions,
+        }
+    }
+
+    /// Create record augmenter, returns `Error::EmptyAugmenters` if no fields are given
+    pub fn try_new(
+        fields: Vec<(String, Arc<dyn BaseAugmenter<String, Doc> + Send + Sync>)>,
+        shared_seed: bool,
+        consistent_substitutions: bool,
+    ) -> Result<Self> {
+        if fields.is_empty() {
+            return Err(Error::EmptyAugmenters);
+        }
+        Ok(RecordAugmenter::new(fields, shared_seed, consistent_substitutions))
+    }
+
+    /// Names of the augmented fields, in the order of augmentation
+    pub fn field_names(&self) -> impl Iterator<Item = &String> {
+        self.fields.iter().map(|(field, _)| field)
+    }
+
+    /// Apply substitutions made in the previous fields, substituted words are protected from further augmentation
+    /// Words are matched case-insensitively and the replacement takes the casing of the matched word,
+    /// multi-word replacements (e.g. "New York") are split into tokens aligned with the original span of the word
+    fn apply_substitutions(doc: &mut Doc, substitutions: &HashMap<String, String>, rng: &mut dyn rand::RngCore) {
+        let mut index = 0;
+        while index < doc.tokens.len() {
+            let token = &doc.tokens[index];
+            let new_text = match substitutions.get(&token.token().to_lowercase()) {
+                Some(new_text) if token.kind() == &TokenType::Word => {
+                    Casing::Preserve.apply(token.token(), new_text, rng)
+                }
+                _ => {
+                    index += 1;
+                    continue;
+                }
+            };
+
+            let old_text = token.token().clone();
+            let original_span = token.original_span();
+            let new_tokens = new_text
+                .split_word_bounds()
+                .map(|token| {
+                    let mut token = Token::from_str(token);
+                    if let Some((start, end)) = original_span {
+                        token.set_original_span(start, end);
+                    }
+                    token.set_protected(true);
+                    token
+                })
+                .collect::<Vec<Token>>();
+            let num_new_tokens = new_tokens.len();
+            doc.tokens.splice(index..=index, new_tokens);
+
+            doc.record_change(|_| {
+                Change::new(
+                    "RecordAugmenter",
+                    TextAction::Substitute,
+                    Some(index),
+                    Some(index),
+                    &old_text,
+                    &new_text,
+                )
+            });
+            index += num_new_tokens.max(1);
+        }
+    }
+}
+
+impl BaseAugmenter<Record, Record> for RecordAugmenter {
+    fn augment_inner(&self, mut input: Record, rng: &mut dyn rand::RngCore) -> Record {
+        let seed = if self.shared_seed { rng.next_u64() } else { 0 };
+        let mut substitutions: HashMap<String, String> = HashMap::new();
+
+        for (field, augmenter) in self.fields.iter() {
+            let text = match input.remove(field) {
+                Some(text) => text,
+                None => continue,
+            };
+
+            let mut doc = augmenter.convert_to_inner(text);
+            if self.consistent_substitutions {
+                Self::apply_substitutions(&mut doc, &substitutions, rng);
+                doc.track_changes();
+            }
+
+            let mut doc = if self.shared_seed {
+                augmenter.augment_inner(doc, &mut StdRng::seed_from_u64(seed))
+            } else {
+                augmenter.augment_inner(doc, rng)
+            };
+
+            // Remember in-place changes of the words, first change of the word wins
+            if self.consistent_substitutions {
+                for change in doc.take_changes() {
+                    let is_in_place = change.original_index.is_some() && change.original_index == change.new_index;
+                    if is_in_place && change.operation != TextAction::Swap && !change.old_text.is_empty() {
+                        substitutions
+                            .entry(change.old_text.to_lowercase())
+                            .or_insert(change.new_text);
+                    }
+                }
+            }
+
+            input.insert(field.clone(), augmenter.convert_to_outer(doc));
+        }
+
+        input
+    }
+
+    fn convert_to_inner(&self, input: Record) -> Record {
+        input
+    }
+
+    fn convert_to_outer(&self, input: Record) -> Record {
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flow::ChanceAugmenter;
+    use crate::text::{TextAugmentParameters, WordsRandomSubstituteAugmenter, WordsRandomSwapAugmenter};
+    use test_case::test_case;
+
+    type TextAugmenter = Arc<dyn BaseAugmenter<String, Doc> + Send + Sync>;
+
+    fn record(premise: &str, hypothesis: &str) -> Record {
+        Record::from([
+            ("premise".to_string(), premise.to_string()),
+            ("hypothesis".to_string(), hypothesis.to_string()),
+            ("label".to_string(), "entailment".to_string()),
+        ])
+    }
+
+    fn substitute(p: f32) -> TextAugmenter {
+        let vocabulary = ["dog", "bird", "fish", "horse", "mouse"].map(String::from).to_vec();
+        Arc::new(WordsRandomSubstituteAugmenter::new(
+            TextAugmentParameters::new(p, None, None),
+            vocabulary,
+            None,
+        ))
+    }
+
+    #[test]
+    fn test_record_augmenter_fields() {
+        let augmenter = RecordAugmenter::new(vec![("premise".to_string(), substitute(1.0))], false, false);
+
+        let output = augmenter.augment(record("A cat", "A cat"), &mut rand::thread_rng());
+
+        assert_ne!(output["premise"], "A cat");
+        assert_eq!(output["hypothesis"], "A cat");
+        assert_eq!(output["label"], "entailment");
+    }
+
+    #[test]
+    fn test_record_augmenter_missing_field() {
+        let augmenter = RecordAugmenter::new(vec![("question".to_string(), substitute(1.0))], false, false);
+
+        let output = augmenter.augment(record("A cat", "A cat"), &mut rand::thread_rng());
+
+        assert_eq!(output, record("A cat", "A cat"));
+    }
+
+    #[test_case(true ; "consistent")]
+    #[test_case(false ; "independent")]
+    fn test_record_augmenter_consistent_substitutions(consistent_substitutions: bool) {
+        let augmenter = RecordAugmenter::new(
+            vec![
+                ("premise".to_string(), substitute(1.0)),
+                ("hypothesis".to_string(), substitute(0.0)),
+            ],
+            false,
+            consistent_substitutions,
+        );
+
+        for _ in 0..10 {
+            let output = augmenter.augment(record("cat", "The cat sleeps"), &mut rand::thread_rng());
+
+            let expected_hypothesis = format!("The {} sleeps", output["premise"]);
+            assert_eq!(output["hypothesis"] == expected_hypothesis, consistent_substitutions);
+        }
+    }
+
+    #[test_case("Paris", "London", "I love paris", "I love london", (7, 12) ; "case insensitive")]
+    #[test_case("cat", "big cat", "The Cat sleeps", "The Big cat sleeps", (4, 7) ; "multi-word replacement")]
+    fn test_apply_substitutions(old_text: &str, new_text: &str, text: &str, expected: &str, span: (usize, usize)) {
+        let substitutions = HashMap::from([(old_text.to_lowercase(), new_text.to_string())]);
+        let mut doc = Doc::new(text);
+
+        RecordAugmenter::apply_substitutions(&mut doc, &substitutions, &mut rand::thread_rng());
+
+        assert_eq!(doc.to_string(), expected);
+        assert_eq!(doc.num_changes, 1);
+        let substituted = doc
+            .tokens
+            .iter()
+            .filter(|token| token.is_protected())
+            .collect::<Vec<_>>();
+        assert_eq!(substituted.len(), Doc::new(new_text).tokens.len());
+        assert!(substituted.iter().all(|token| token.original_span() == Some(span)));
+    }
+
+    #[test]
+    fn test_record_augmenter_consistent_substitutions_ignore_case() {
+        let augmenter = RecordAugmenter::new(
+            vec![
+                ("premise".to_string(), substitute(1.0)),
+                ("hypothesis".to_string(), substitute(0.0)),
+            ],
+            false,
+            true,
+        );
+
+        let output = augmenter.augment(record("Cat", "a cat"), &mut rand::thread_rng());
+
+        assert_eq!(output["hypothesis"], format!("a {}", output["premise"].to_lowercase()));
+    }
+
+    #[test]
+    fn test_record_augmenter_shared_seed() {
+        let chance_swap = Arc::new(ChanceAugmenter::new(
+            Arc::new(WordsRandomSwapAugmenter::new(
+                TextAugmentParameters::new(1.0, None, None),
+                None,
+            )) as TextAugmenter,
+            0.5,
+        ));
+        let augmenter = RecordAugmenter::new(
+            vec![
+                ("premise".to_string(), chance_swap.clone() as TextAugmenter),
+                ("hypothesis".to_string(), chance_swap),
+            ],
+            true,
+            false,
+        );
+
+        for _ in 0..10 {
+            let output = augmenter.augment(record("A B", "A B"), &mut rand::thread_rng());
+
+            assert_eq!(output["premise"], output["hypothesis"]);
+        }
+    }
+
+    #[test]
+    fn test_record_augmenter_try_new_empty() {
+        assert!(matches!(
+            RecordAugmenter::try_new(vec![], false, false),
+            Err(Error::EmptyAugmenters)
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
 use rand::distributions::{Distribution, WeightedIndex};
 use rand::Rng;
 use std::sync::Arc;
@@ -13,11 +14,33 @@ pub struct SelectorAugmenter<T, K> {
 }
 
 impl<T, K> SelectorAugmenter<T, K> {
+    /// Create selector augmenter, panics on invalid arguments, see `try_new`
     pub fn new(augmenters: Vec<Arc<dyn BaseAugmenter<T, K> + Send + Sync>>, weights: Option<Vec<f32>>) -> Self {
+        match Self::try_new(augmenters, weights) {
+            Ok(augmenter) => augmenter,
+            Err(error) => panic!("SelectorAugmenter: {}", error),
+        }
+    }
+
+    /// Create selector augmenter
+    /// Returns error if there are no augmenters, or weights do not match augmenters or can not be sampled from
+    pub fn try_new(
+        augmenters: Vec<Arc<dyn BaseAugmenter<T, K> + Send + Sync>>,
+        weights: Option<Vec<f32>>,
+    ) -> Result<Self> {
         if augmenters.is_empty() {
-            panic!("SelectorAugmenter must have at least one augmenter");
+            return Err(Error::EmptyAugmenters);
         }
-        SelectorAugmenter { augmenters, weights }
+        if let Some(weights) = &weights {
+            if weights.len() != augmenters.len() {
+                return Err(Error::MismatchedWeights {
+                    expected: augmenters.len(),
+                    actual: weights.len(),
+                });
+            }
+            WeightedIndex::new(weights).map_err(|error| Error::InvalidWeights(error.to_string()))?;
+        }
+        Ok(SelectorAugmenter { augmenters, weights })
     }
 }
 
@@ -149,4 +172,16 @@ mod tests {
 
         assert_eq!(output, 2)
     }
+
+    #[test_case(vec![], None ; "no augmenters")]
+    #[test_case(vec![Arc::new(DummyAddAugmenter)], Some(vec![0.5, 0.5]) ; "more weights than augmenters")]
+    #[test_case(vec![Arc::new(DummyAddAugmenter), Arc::new(DummyAddAugmenter)], Some(vec![1.0]) ; "less weights than augmenters")]
+    #[test_case(vec![Arc::new(DummyAddAugmenter), Arc::new(DummyAddAugmenter)], Some(vec![0.0, 0.0]) ; "zero weights")]
+    #[test_case(vec![Arc::new(DummyAddAugmenter), Arc::new(DummyAddAugmenter)], Some(vec![-1.0, 2.0]) ; "negative weight")]
+    fn test_try_new_invalid(
+        augmenters: Vec<Arc<dyn BaseAugmenter<i32, i32> + Send + Sync>>,
+        weights: Option<Vec<f32>>,
+    ) {
+        assert!(SelectorAugmenter::try_new(augmenters, weights).is_err());
+    }
 }
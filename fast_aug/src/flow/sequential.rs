@@ -1,4 +1,5 @@
 use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
 use std::sync::Arc;
 
 pub struct SequentialAugmenter<T, K> {
@@ -8,11 +9,20 @@ pub struct SequentialAugmenter<T, K> {
 }
 
 impl<T, K> SequentialAugmenter<T, K> {
+    /// Create sequential augmenter, panics if there are no augmenters, see `try_new`
     pub fn new(augmenters: Vec<Arc<dyn BaseAugmenter<T, K> + Send + Sync>>) -> Self {
+        match Self::try_new(augmenters) {
+            Ok(augmenter) => augmenter,
+            Err(error) => panic!("SequentialAugmenter: {}", error),
+        }
+    }
+
+    /// Create sequential augmenter, returns `Error::EmptyAugmenters` if there are no augmenters
+    pub fn try_new(augmenters: Vec<Arc<dyn BaseAugmenter<T, K> + Send + Sync>>) -> Result<Self> {
         if augmenters.is_empty() {
-            panic!("SequentialAugmenter must have at least one augmenter");
+            return Err(Error::EmptyAugmenters);
         }
-        SequentialAugmenter { augmenters }
+        Ok(SequentialAugmenter { augmenters })
     }
 }
 
@@ -86,4 +96,10 @@ mod tests {
 
         assert_eq!(output, 6);
     }
+
+    #[test]
+    fn test_try_new_without_augmenters() {
+        let result = SequentialAugmenter::<i32, i32>::try_new(vec![]);
+        assert!(matches!(result, Err(Error::EmptyAugmenters)));
+    }
 }
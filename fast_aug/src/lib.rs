@@ -1,6 +1,9 @@
 pub mod base;
+pub mod config;
+pub mod error;
 pub mod flow;
 pub mod models;
 pub mod text;
 
 pub use base::BaseAugmenter;
+pub use error::{Error, Result};
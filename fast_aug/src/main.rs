@@ -1 +1,474 @@
-pub fn main() {}
+use clap::{ArgGroup, Parser, ValueEnum};
+use fast_aug::base::item_rng;
+use fast_aug::config::{AugmenterConfig, TextAugmenterRef};
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+type CliResult<T> = Result<T, Box<dyn Error>>;
+
+/// Augment text files and JSONL/CSV datasets with fast_aug pipelines
+#[derive(Parser, Debug)]
+#[command(name = "fast-aug", version, about)]
+#[command(group(ArgGroup::new("pipeline_source").required(true).args(["config", "pipeline"])))]
+struct Args {
+    /// Input file, stdin if not set or "-"
+    input: Option<PathBuf>,
+
+    /// Output file, stdout if not set or "-"
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Pipeline config file (.json, .yaml or .yml)
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+
+    /// Inline pipeline config as json or yaml, e.g. '{"type": "WordsRandomSwap", "word_params": 0.3}'
+    #[arg(short, long)]
+    pipeline: Option<String>,
+
+    /// Input format, inferred from the input file extension if not set (lines by default)
+    #[arg(short, long, value_enum)]
+    format: Option<Format>,
+
+    /// Field with the text to augment, required for jsonl and csv formats
+    #[arg(long)]
+    field: Option<String>,
+
+    /// Number of augmented variants to write per input
+    #[arg(short = 'n', long, default_value_t = 1)]
+    num_variants: usize,
+
+    /// Seed for reproducible augmentation
+    #[arg(short, long)]
+    seed: Option<u64>,
+
+    /// Number of inputs to augment at once
+    #[arg(long, default_value_t = 1024)]
+    batch_size: usize,
+}
+
+/// Input (and output) format
+#[derive(Copy, Clone, Debug, PartialEq, ValueEnum)]
+enum Format {
+    /// Plain text, one input per line, augmented texts with line breaks are rejected
+    Lines,
+    /// One json object per line, text is taken from `--field`
+    Jsonl,
+    /// Csv with header, text is taken from `--field` column
+    Csv,
+}
+
+impl Format {
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("jsonl") | Some("ndjson") => Some(Format::Jsonl),
+            Some("csv") => Some(Format::Csv),
+            Some("txt") => Some(Format::Lines),
+            _ => None,
+        }
+    }
+}
+
+/// Single input record, augmented text is put back to the same field
+#[derive(Clone, Debug, PartialEq)]
+enum Record {
+    Line(String),
+    Json(serde_json::Map<String, serde_json::Value>),
+    Csv(csv::StringRecord),
+}
+
+/// Reads records of the given format and extracts text to augment
+struct RecordReader {
+    format: Format,
+    field: Option<String>,
+    lines: Option<io::Lines<Box<dyn BufRead>>>,
+    csv_reader: Option<csv::Reader<Box<dyn BufRead>>>,
+    csv_field_index: usize,
+}
+
+impl RecordReader {
+    fn new(reader: Box<dyn BufRead>, format: Format, field: Option<String>) -> CliResult<Self> {
+        if format != Format::Lines && field.is_none() {
+            return Err("--field is required for jsonl and csv formats".into());
+        }
+
+        let mut record_reader = RecordReader {
+            format,
+            field,
+            lines: None,
+            csv_reader: None,
+            csv_field_index: 0,
+        };
+        match format {
+            Format::Lines | Format::Jsonl => record_reader.lines = Some(reader.lines()),
+            Format::Csv => {
+                let mut csv_reader = csv::Reader::from_reader(reader);
+                let field = record_reader.field.as_deref().unwrap_or_default();
+                record_reader.csv_field_index = csv_reader
+                    .headers()?
+                    .iter()
+                    .position(|header| header == field)
+                    .ok_or_else(|| format!("column \"{}\" not found in csv header", field))?;
+                record_reader.csv_reader = Some(csv_reader);
+            }
+        }
+        Ok(record_reader)
+    }
+
+    fn csv_headers(&mut self) -> CliResult<Option<csv::StringRecord>> {
+        match &mut self.csv_reader {
+            Some(csv_reader) => Ok(Some(csv_reader.headers()?.clone())),
+            None => Ok(None),
+        }
+    }
+
+    /// Read next record, None at the end of input
+    fn next_record(&mut self) -> CliResult<Option<Record>> {
+        match self.format {
+            Format::Lines => match self.lines.as_mut().and_then(|lines| lines.next()) {
+                Some(line) => Ok(Some(Record::Line(line?))),
+                None => Ok(None),
+            },
+            Format::Jsonl => loop {
+                match self.lines.as_mut().and_then(|lines| lines.next()) {
+                    Some(line) => {
+                        let line = line?;
+                        if line.trim().is_empty() {
+                            continue;
+                        }
+                        match serde_json::from_str(&line)? {
+                            serde_json::Value::Object(object) => return Ok(Some(Record::Json(object))),
+                            _ => return Err(format!("jsonl line is not an object: {}", line).into()),
+                        }
+                    }
+                    None => return Ok(None),
+                }
+            },
+            Format::Csv => match self.csv_reader.as_mut().and_then(|reader| reader.records().next()) {
+                Some(record) => Ok(Some(Record::Csv(record?))),
+                None => Ok(None),
+            },
+        }
+    }
+
+    /// Get text to augment from the record
+    fn text(&self, record: &Record) -> CliResult<String> {
+        match record {
+            Record::Line(line) => Ok(line.clone()),
+            Record::Json(object) => {
+                let field = self.field.as_deref().unwrap_or_default();
+                match object.get(field) {
+                    Some(serde_json::Value::String(text)) => Ok(text.clone()),
+                    Some(_) => Err(format!("field \"{}\" is not a string", field).into()),
+                    None => Err(format!("field \"{}\" not found", field).into()),
+                }
+            }
+            Record::Csv(record) => Ok(record.get(self.csv_field_index).unwrap_or_default().to_string()),
+        }
+    }
+
+    /// Copy of the record with augmented text
+    fn with_text(&self, record: &Record, text: String) -> Record {
+        match record {
+            Record::Line(_) => Record::Line(text),
+            Record::Json(object) => {
+                let mut object = object.clone();
+                let field = self.field.clone().unwrap_or_default();
+                object.insert(field, serde_json::Value::String(text));
+                Record::Json(object)
+            }
+            Record::Csv(record) => Record::Csv(
+                record
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, value)| {
+                        if idx == self.csv_field_index {
+                            text.as_str()
+                        } else {
+                            value
+                        }
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Writes records in the same format they were read
+enum RecordWriter {
+    Lines(Box<dyn Write>),
+    Csv(Box<csv::Writer<Box<dyn Write>>>),
+}
+
+impl RecordWriter {
+    fn new(writer: Box<dyn Write>, format: Format, csv_headers: Option<csv::StringRecord>) -> CliResult<Self> {
+        match format {
+            Format::Lines | Format::Jsonl => Ok(RecordWriter::Lines(writer)),
+            Format::Csv => {
+                let mut csv_writer = csv::Writer::from_writer(writer);
+                if let Some(headers) = csv_headers {
+                    csv_writer.write_record(&headers)?;
+                }
+                Ok(RecordWriter::Csv(Box::new(csv_writer)))
+            }
+        }
+    }
+
+    fn write(&mut self, record: &Record) -> CliResult<()> {
+        match (self, record) {
+            (RecordWriter::Lines(_), Record::Line(line)) if line.contains(['\n', '\r']) => {
+                return Err(format!(
+                    "augmented text contains a line break, use jsonl or csv format to keep one output per input: {:?}",
+                    line
+                )
+                .into())
+            }
+            (RecordWriter::Lines(writer), Record::Line(line)) => writeln!(writer, "{}", line)?,
+            (RecordWriter::Lines(writer), Record::Json(object)) => {
+                serde_json::to_writer(&mut *writer, object)?;
+                writeln!(writer)?;
+            }
+            (RecordWriter::Csv(writer), Record::Csv(record)) => writer.write_record(record)?,
+            _ => return Err("record does not match output format".into()),
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> CliResult<()> {
+        match self {
+            RecordWriter::Lines(writer) => writer.flush()?,
+            RecordWriter::Csv(writer) => writer.flush()?,
+        }
+        Ok(())
+    }
+}
+
+/// Augment batch of records, each record is followed by its `num_variants` variants
+/// Each variant gets own rng derived from `seed` and its global index (see `item_rng`),
+/// so the output does not depend on the batch size
+fn augment_records(
+    augmenter: &TextAugmenterRef,
+    reader: &RecordReader,
+    records: &[Record],
+    first_record_index: usize,
+    num_variants: usize,
+    seed: u64,
+) -> CliResult<Vec<Record>> {
+    let mut augmented_records = Vec::with_capacity(records.len() * num_variants);
+    for (idx, record) in records.iter().enumerate() {
+        let text = reader.text(record)?;
+        for variant in 0..num_variants {
+            let rng = &mut item_rng(seed, (first_record_index + idx) * num_variants + variant);
+            augmented_records.push(reader.with_text(record, augmenter.augment(text.clone(), rng)));
+        }
+    }
+    Ok(augmented_records)
+}
+
+fn run(args: Args) -> CliResult<()> {
+    // Build pipeline
+    let config = match (&args.config, &args.pipeline) {
+        (Some(path), _) => AugmenterConfig::from_file(path)?,
+        (None, Some(pipeline)) => AugmenterConfig::from_yaml_str(pipeline)?,
+        (None, None) => return Err("either --config or --pipeline is required".into()),
+    };
+    let augmenter = config.build()?;
+    let seed = args.seed.unwrap_or_else(rand::random);
+
+    // Open input and output
+    let input_path = args.input.filter(|path| path.as_os_str() != "-");
+    let format = args
+        .format
+        .or_else(|| input_path.as_deref().and_then(Format::from_path))
+        .unwrap_or(Format::Lines);
+    let input: Box<dyn Read> = match &input_path {
+        Some(path) => Box::new(File::open(path)?),
+        None => Box::new(io::stdin()),
+    };
+    let output: Box<dyn Write> = match args.output.filter(|path| path.as_os_str() != "-") {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    };
+
+    let mut reader = RecordReader::new(Box::new(BufReader::new(input)), format, args.field)?;
+    let mut writer = RecordWriter::new(output, format, reader.csv_headers()?)?;
+
+    // Augment in batches
+    let batch_size = args.batch_size.max(1);
+    let mut batch = Vec::with_capacity(batch_size);
+    let mut num_records = 0;
+    loop {
+        let record = reader.next_record()?;
+        let is_last = record.is_none();
+        batch.extend(record);
+
+        if batch.len() >= batch_size || (is_last && !batch.is_empty()) {
+            for record in augment_records(&augmenter, &reader, &batch, num_records, args.num_variants, seed)? {
+                writer.write(&record)?;
+            }
+            num_records += batch.len();
+            batch.clear();
+        }
+        if is_last {
+            break;
+        }
+    }
+    writer.flush()
+}
+
+pub fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("fast-aug: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const PIPELINE: &str = r#"{"type": "WordsRandomSwap", "word_params": 1.0}"#;
+
+    fn read_all(content: &str, format: Format, field: Option<&str>) -> (RecordReader, Vec<Record>) {
+        let input: Box<dyn BufRead> = Box::new(io::Cursor::new(content.to_string().into_bytes()));
+        let mut reader = RecordReader::new(input, format, field.map(|f| f.to_string())).unwrap();
+        let mut records = Vec::new();
+        while let Some(record) = reader.next_record().unwrap() {
+            records.push(record);
+        }
+        (reader, records)
+    }
+
+    #[test_case("data.jsonl", Some(Format::Jsonl))]
+    #[test_case("data.csv", Some(Format::Csv))]
+    #[test_case("data.txt", Some(Format::Lines))]
+    #[test_case("data", None)]
+    fn test_format_from_path(path: &str, expected: Option<Format>) {
+        assert_eq!(Format::from_path(Path::new(path)), expected);
+    }
+
+    #[test_case("a b\nc d\n", Format::Lines, None, vec!["a b", "c d"] ; "lines")]
+    #[test_case("{\"text\": \"a b\", \"label\": 1}\n\n{\"text\": \"c d\"}", Format::Jsonl, Some("text"), vec!["a b", "c d"] ; "jsonl")]
+    #[test_case("label,text\n1,a b\n0,\"c, d\"\n", Format::Csv, Some("text"), vec!["a b", "c, d"] ; "csv")]
+    fn test_read_texts(content: &str, format: Format, field: Option<&str>, expected: Vec<&str>) {
+        let (reader, records) = read_all(content, format, field);
+
+        let texts = records.iter().map(|r| reader.text(r).unwrap()).collect::<Vec<String>>();
+
+        assert_eq!(texts, expected);
+    }
+
+    #[test_case("text\nb", Format::Csv, None ; "csv without field")]
+    #[test_case("text\nb", Format::Csv, Some("label") ; "csv without column")]
+    #[test_case("{}", Format::Jsonl, None ; "jsonl without field")]
+    fn test_reader_errors(content: &str, format: Format, field: Option<&str>) {
+        let input: Box<dyn BufRead> = Box::new(io::Cursor::new(content.to_string().into_bytes()));
+        assert!(RecordReader::new(input, format, field.map(|f| f.to_string())).is_err());
+    }
+
+    #[test]
+    fn test_augment_records_keeps_other_fields() {
+        let augmenter = AugmenterConfig::from_json_str(PIPELINE).unwrap().build().unwrap();
+        let (reader, records) = read_all("{\"text\": \"a b\", \"label\": 1}", Format::Jsonl, Some("text"));
+
+        let augmented = augment_records(&augmenter, &reader, &records, 0, 3, 0).unwrap();
+
+        assert_eq!(augmented.len(), 3);
+        for record in augmented {
+            match record {
+                Record::Json(object) => {
+                    assert_eq!(object["text"], "b a");
+                    assert_eq!(object["label"], 1);
+                }
+                _ => panic!("expected json record"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_augment_records_with_seed_is_reproducible() {
+        let augmenter =
+            AugmenterConfig::from_json_str(r#"{"type": "CharsRandomSwap", "word_params": 0.5, "char_params": 0.5}"#)
+                .unwrap()
+                .build()
+                .unwrap();
+        let (reader, records) = read_all("some longer text to augment\nand another one", Format::Lines, None);
+
+        let first = augment_records(&augmenter, &reader, &records, 0, 2, 42).unwrap();
+        let second = augment_records(&augmenter, &reader, &records, 0, 2, 42).unwrap();
+
+        assert_eq!(first.len(), 4);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_run_with_seed_does_not_depend_on_batch_size() {
+        let dir = std::env::temp_dir().join(format!("fast_aug_test_cli_{}", rand::random::<u64>()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input_path = dir.join("input.txt");
+        let texts = (0..20).map(|i| format!("some longer text number {} to augment", i));
+        std::fs::write(&input_path, texts.collect::<Vec<String>>().join("\n")).unwrap();
+        let pipeline = r#"{"type": "CharsRandomSwap", "word_params": 0.5, "char_params": 0.5}"#;
+
+        let outputs = ["1", "7", "1024"]
+            .map(|batch_size| {
+                let output_path = dir.join(format!("output_{}.txt", batch_size));
+                let args = Args::parse_from([
+                    "fast-aug",
+                    input_path.to_str().unwrap(),
+                    "-o",
+                    output_path.to_str().unwrap(),
+                    "-p",
+                    pipeline,
+                    "-n",
+                    "2",
+                    "--seed",
+                    "42",
+                    "--batch-size",
+                    batch_size,
+                ]);
+                run(args).unwrap();
+                std::fs::read_to_string(output_path).unwrap()
+            })
+            .to_vec();
+        std::fs::remove_dir_all(dir).unwrap();
+
+        assert_eq!(outputs[0].lines().count(), 40);
+        assert_eq!(outputs[0], outputs[1]);
+        assert_eq!(outputs[0], outputs[2]);
+    }
+
+    #[test_case("a\nb" ; "line feed")]
+    #[test_case("a\r\nb" ; "carriage return")]
+    fn test_write_lines_rejects_line_breaks(text: &str) {
+        let output: Box<dyn Write> = Box::new(io::sink());
+        let mut writer = RecordWriter::new(output, Format::Lines, None).unwrap();
+
+        assert!(writer.write(&Record::Line("a b".to_string())).is_ok());
+        assert!(writer.write(&Record::Line(text.to_string())).is_err());
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let (mut reader, records) = read_all("label,text\n1,a b\n", Format::Csv, Some("text"));
+        let record = reader.with_text(&records[0], "b, a".to_string());
+        let path = std::env::temp_dir().join("fast_aug_test_cli_output.csv");
+
+        let output: Box<dyn Write> = Box::new(File::create(&path).unwrap());
+        let mut writer = RecordWriter::new(output, Format::Csv, reader.csv_headers().unwrap()).unwrap();
+        writer.write(&record).unwrap();
+        writer.flush().unwrap();
+        drop(writer);
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(content, "label,text\n1,\"b, a\"\n");
+    }
+}
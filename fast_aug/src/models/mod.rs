@@ -1 +1,3 @@
 pub mod text;
+
+pub use text::{MaskedLmModel, Seq2SeqModel};
@@ -1,8 +1,9 @@
+use crate::error::{Error, Result};
 use icu_locid::Locale;
 use icu_properties::{exemplar_chars, sets};
 use icu_provider::DataLocale;
-use rand::seq::{IteratorRandom, SliceRandom};
-use rand::RngCore;
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
 use std::collections::HashSet;
 
 pub struct AlphabetModel {
@@ -13,6 +14,10 @@ pub struct AlphabetModel {
     pub punctuation: HashSet<char>,
     pub numbers: HashSet<char>,
     pub locale_str: String,
+    /// Sorted copies of main and main_capitalized sets to sample from
+    /// HashSet iteration order differs between instances, so sampling from it is not reproducible with a seeded rng
+    main_sorted: Vec<char>,
+    main_capitalized_sorted: Vec<char>,
 }
 
 impl AlphabetModel {
@@ -29,6 +34,10 @@ impl AlphabetModel {
             Some(main_capitalized) => main_capitalized,
             None => main.iter().map(|c| c.to_uppercase().next().unwrap()).collect(),
         };
+        let mut main_sorted: Vec<char> = main.iter().copied().collect();
+        main_sorted.sort_unstable();
+        let mut main_capitalized_sorted: Vec<char> = main_capitalized.iter().copied().collect();
+        main_capitalized_sorted.sort_unstable();
         AlphabetModel {
             main,
             main_capitalized,
@@ -37,6 +46,8 @@ impl AlphabetModel {
             punctuation,
             numbers,
             locale_str: locale_string,
+            main_sorted,
+            main_capitalized_sorted,
         }
     }
 
@@ -50,6 +61,7 @@ impl AlphabetModel {
     }
 
     /// Create alphabet using icu4x - icu_locid::Locale
+    /// Panics if there is no exemplar chars data for the locale, see `try_from_locale`
     ///
     /// # Arguments
     /// * `locale` - Locale from icu_locid::Locale
@@ -65,17 +77,25 @@ impl AlphabetModel {
     /// assert_eq!(alphabet_model.main.len(), 27);
     /// ```
     pub fn from_locale(locale: &Locale) -> Self {
-        let data_locale: DataLocale = locale.clone().into();
+        Self::try_from_locale(locale).expect("locale should be present")
+    }
 
-        // TODO: validate local is valid
+    /// Create alphabet using icu4x - icu_locid::Locale
+    /// Returns `Error::InvalidLocale` if there is no exemplar chars data for the locale
+    ///
+    /// # Arguments
+    /// * `locale` - Locale from icu_locid::Locale
+    pub fn try_from_locale(locale: &Locale) -> Result<Self> {
+        let data_locale: DataLocale = locale.clone().into();
+        let invalid_locale = |_| Error::InvalidLocale(locale.to_string());
 
-        let main = exemplar_chars::exemplars_main(&data_locale).expect("locale should be present");
-        let index = exemplar_chars::exemplars_index(&data_locale).expect("locale should be present");
-        let auxiliary = exemplar_chars::exemplars_auxiliary(&data_locale).expect("locale should be present");
-        let punctuation = exemplar_chars::exemplars_punctuation(&data_locale).expect("locale should be present");
-        let numbers = exemplar_chars::exemplars_numbers(&data_locale).expect("locale should be present");
+        let main = exemplar_chars::exemplars_main(&data_locale).map_err(invalid_locale)?;
+        let index = exemplar_chars::exemplars_index(&data_locale).map_err(invalid_locale)?;
+        let auxiliary = exemplar_chars::exemplars_auxiliary(&data_locale).map_err(invalid_locale)?;
+        let punctuation = exemplar_chars::exemplars_punctuation(&data_locale).map_err(invalid_locale)?;
+        let numbers = exemplar_chars::exemplars_numbers(&data_locale).map_err(invalid_locale)?;
 
-        AlphabetModel::new(
+        Ok(AlphabetModel::new(
             Self::unicode_set_data_to_hashset(&main),
             None,
             Self::unicode_set_data_to_hashset(&index),
@@ -83,14 +103,15 @@ impl AlphabetModel {
             Self::unicode_set_data_to_hashset(&punctuation),
             Self::unicode_set_data_to_hashset(&numbers),
             locale.to_string(),
-        )
+        ))
     }
 
     /// Create alphabet using icu4x using language tag
+    /// Panics if language tag is invalid, see `try_from_locale_str`
     ///
     /// # Arguments
     /// * `locale_str` - Language tag, with or without script and region.
-    ///     See [Unicode Language Identifier](https://unicode.org/reports/tr35/#Unicode_language_identifier)
+    ///   See [Unicode Language Identifier](https://unicode.org/reports/tr35/#Unicode_language_identifier)
     ///
     /// # Examples
     /// ```rust
@@ -101,8 +122,23 @@ impl AlphabetModel {
     /// assert_eq!(alphabet_model.main.len(), 27);
     /// ```
     pub fn from_locale_str(locale_str: &str) -> Self {
-        let locale = Locale::try_from_bytes(locale_str.as_bytes()).expect("language tag should be valid");
-        Self::from_locale(&locale)
+        Self::try_from_locale_str(locale_str).expect("language tag should be valid")
+    }
+
+    /// Create alphabet using icu4x using language tag
+    /// Returns `Error::InvalidLocale` if language tag is invalid or there is no data for it
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::models::text::AlphabetModel;
+    ///
+    /// assert!(AlphabetModel::try_from_locale_str("de-AT").is_ok());
+    /// assert!(AlphabetModel::try_from_locale_str("sr-La!!!tn-RS").is_err());
+    /// ```
+    pub fn try_from_locale_str(locale_str: &str) -> Result<Self> {
+        let locale =
+            Locale::try_from_bytes(locale_str.as_bytes()).map_err(|_| Error::InvalidLocale(locale_str.to_string()))?;
+        Self::try_from_locale(&locale)
     }
 
     /// Get random char from alphabet
@@ -117,15 +153,18 @@ impl AlphabetModel {
         }
 
         if include_main && !include_capital {
-            return *self.main.iter().choose(rng).unwrap();
+            return *self.main_sorted.choose(rng).unwrap();
         }
 
         if !include_main && include_capital {
-            return *self.main_capitalized.iter().choose(rng).unwrap();
+            return *self.main_capitalized_sorted.choose(rng).unwrap();
         }
 
-        let chars: Vec<char> = self.main.iter().chain(self.main_capitalized.iter()).copied().collect();
-        *chars.choose(rng).unwrap()
+        let index = rng.gen_range(0..self.main_sorted.len() + self.main_capitalized_sorted.len());
+        match self.main_sorted.get(index) {
+            Some(c) => *c,
+            None => self.main_capitalized_sorted[index - self.main_sorted.len()],
+        }
     }
 }
 
@@ -183,4 +222,31 @@ mod tests {
         });
         assert!(result.is_err());
     }
+
+    #[test_case("invalid")]
+    #[test_case("123")]
+    #[test_case("sr-Latn-")]
+    fn test_try_from_locale_str_with_invalid_locale(locale_str: &str) {
+        let result = AlphabetModel::try_from_locale_str(locale_str);
+        assert!(matches!(result, Err(Error::InvalidLocale(_))));
+    }
+
+    #[test_case(true, false)]
+    #[test_case(false, true)]
+    #[test_case(true, true)]
+    fn test_get_random_char_reproducible_with_seed(include_main: bool, include_capital: bool) {
+        use rand::SeedableRng;
+
+        let sample_chars = |alphabet_model: &AlphabetModel| {
+            let rng = &mut rand::rngs::StdRng::seed_from_u64(42);
+            (0..20)
+                .map(|_| alphabet_model.get_random_char(include_main, include_capital, rng))
+                .collect::<String>()
+        };
+
+        let chars = sample_chars(&AlphabetModel::from_locale_str("en"));
+
+        assert_eq!(chars, sample_chars(&AlphabetModel::from_locale_str("en")));
+        assert!(chars.chars().all(|c| c.is_alphabetic()));
+    }
 }
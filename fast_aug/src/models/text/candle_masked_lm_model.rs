@@ -0,0 +1,396 @@
+use super::masked_lm_model::MaskedLmModel;
+use crate::error::{Error, Result};
+use candle_core::{DType, Device, IndexOp, Tensor, D};
+use candle_nn::VarBuilder;
+use candle_transformers::models::bert;
+use std::fs;
+use std::path::Path;
+use tokenizers::Tokenizer;
+
+/// Mask tokens of BERT-style (WordPiece) and RoBERTa-style (BPE) tokenizers
+const MASK_TOKENS: [&str; 2] = ["[MASK]", "<mask>"];
+/// Maximum number of subword pieces of a predicted word
+const MAX_WORD_PIECES: usize = 4;
+
+/// Masked language model running local BERT-style checkpoint with candle on CPU
+///
+/// Checkpoint directory should contain files in HuggingFace format:
+/// * `config.json` - model config
+/// * `model.safetensors` - model weights, the decoder may be tied to the word embeddings
+/// * `tokenizer.json` - tokenizer with `[MASK]` or `<mask>` token
+///
+/// Model predicts subword pieces, each candidate word starts with a piece starting a word at the mask position.
+/// Pieces continuing a word (e.g. `##ing` of WordPiece or pieces without `▁`/`Ġ` prefix of SentencePiece/BPE)
+/// are appended greedily: the word piece is put in place of the mask, followed by a new mask, and the most
+/// probable piece of the new mask is appended while it continues the word (up to `MAX_WORD_PIECES` pieces).
+/// Word probability is the product of its pieces probabilities, words decoded to the same text are merged,
+/// their probabilities are summed.
+pub struct CandleMaskedLmModel {
+    network: bert::BertForMaskedLM,
+    tokenizer: Tokenizer,
+    mask_token: String,
+    mask_id: u32,
+    /// Prefix marking the first piece of a word, if the tokenizer marks word starts instead of continuations
+    word_start_prefix: Option<char>,
+    device: Device,
+}
+
+impl CandleMaskedLmModel {
+    /// Load model from the checkpoint directory
+    /// Returns error if files are missing or tokenizer has no mask token
+    ///
+    /// # Arguments
+    /// * `path` - Path to the checkpoint directory
+    pub fn from_dir<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let device = Device::Cpu;
+
+        let config: bert::Config = serde_json::from_str(&fs::read_to_string(path.join("config.json"))?)?;
+        // Safety: weights file is memory mapped, it should not be modified while the model is loaded
+        let vb =
+            unsafe { VarBuilder::from_mmaped_safetensors(&[path.join("model.safetensors")], DType::F32, &device)? };
+        // Checkpoints usually do not store tied decoder weights, and store decoder bias one level up
+        let has_decoder_weight = vb.contains_tensor("cls.predictions.decoder.weight");
+        let has_decoder_bias = vb.contains_tensor("cls.predictions.decoder.bias");
+        let vb = vb.rename_f(move |name| match name {
+            "cls.predictions.decoder.weight" if !has_decoder_weight => {
+                "bert.embeddings.word_embeddings.weight".to_string()
+            }
+            "cls.predictions.decoder.bias" if !has_decoder_bias => "cls.predictions.bias".to_string(),
+            _ => name.to_string(),
+        });
+        let network = bert::BertForMaskedLM::load(vb, &config)?;
+
+        let tokenizer_path = path.join("tokenizer.json");
+        let tokenizer = Tokenizer::from_file(&tokenizer_path)
+            .map_err(|error| Error::Model(format!("{}: {}", tokenizer_path.display(), error)))?;
+        let (mask_token, mask_id) = MASK_TOKENS
+            .iter()
+            .find_map(|token| tokenizer.token_to_id(token).map(|id| (token.to_string(), id)))
+            .ok_or_else(|| Error::Model(format!("{}: no mask token", tokenizer_path.display())))?;
+        let vocab = tokenizer.get_vocab(false);
+        let word_start_prefix = ['▁', 'Ġ']
+            .into_iter()
+            .find(|prefix| vocab.keys().any(|token| token.starts_with(*prefix)));
+
+        Ok(CandleMaskedLmModel {
+            network,
+            tokenizer,
+            mask_token,
+            mask_id,
+            word_start_prefix,
+            device,
+        })
+    }
+
+    /// Check if the vocabulary piece is a special token, e.g. "[UNK]" or "[MASK]"
+    fn is_special(&self, id: u32) -> bool {
+        id == self.mask_id
+            || self
+                .tokenizer
+                .id_to_token(id)
+                .is_none_or(|token| self.tokenizer.get_added_vocabulary().is_special_token(&token))
+    }
+
+    /// Check if the vocabulary piece starts a new word, special tokens never do
+    fn is_word_start(&self, id: u32) -> bool {
+        match (self.tokenizer.id_to_token(id), self.word_start_prefix) {
+            _ if self.is_special(id) => false,
+            (Some(token), Some(prefix)) => token.starts_with(prefix),
+            (Some(token), None) => !token.starts_with("##"),
+            (None, _) => false,
+        }
+    }
+
+    /// Predict probabilities of the pieces at the position of the input ids
+    fn predict(&self, ids: &[u32], position: usize) -> Result<Vec<f32>> {
+        let input_ids = Tensor::new(ids, &self.device)?.unsqueeze(0)?;
+        let token_type_ids = input_ids.zeros_like()?;
+        let logits = self.network.forward(&input_ids, &token_type_ids, None)?.squeeze(0)?;
+        Ok(candle_nn::ops::softmax(&logits.i(position)?, D::Minus1)?.to_vec1::<f32>()?)
+    }
+
+    /// Decode the most probable words at the masked position, merging words decoded to the same text
+    ///
+    /// # Arguments
+    /// * `ids` - Encoded text with masks
+    /// * `position` - Position of the mask to fill
+    /// * `probabilities` - Probabilities of the pieces at the masked position
+    /// * `top_k` - Maximum number of words
+    /// * `predict` - Function predicting probabilities of the pieces at the position of the ids
+    fn top_words<F>(
+        &self,
+        ids: &[u32],
+        position: usize,
+        probabilities: Vec<f32>,
+        top_k: usize,
+        predict: F,
+    ) -> Result<Vec<(String, f32)>>
+    where
+        F: Fn(&[u32], usize) -> Result<Vec<f32>>,
+    {
+        let mut piece_ids = (0..probabilities.len() as u32).collect::<Vec<u32>>();
+        piece_ids.sort_unstable_by(|a, b| probabilities[*b as usize].total_cmp(&probabilities[*a as usize]));
+
+        let mut words: Vec<(String, f32)> = Vec::with_capacity(top_k);
+        for id in piece_ids {
+            if words.len() >= top_k {
+                break;
+            }
+            if !self.is_word_start(id) {
+                continue;
+            }
+            let (pieces, probability) = self.complete_word(ids, position, id, probabilities[id as usize], &predict)?;
+            let word = self
+                .tokenizer
+                .decode(&pieces, true)
+                .map_err(|error| Error::Model(error.to_string()))?;
+            let word = word.trim();
+            if !word.chars().any(|c| c.is_alphabetic()) {
+                continue;
+            }
+            match words.iter_mut().find(|(existing, _)| existing == word) {
+                Some((_, existing_probability)) => *existing_probability += probability,
+                None => words.push((word.to_string(), probability)),
+            }
+        }
+        words.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(words)
+    }
+
+    /// Append the most probable continuation pieces to the word starting piece at the masked position
+    /// Returns pieces of the word and its probability
+    fn complete_word<F>(
+        &self,
+        ids: &[u32],
+        position: usize,
+        start_id: u32,
+        start_probability: f32,
+        predict: &F,
+    ) -> Result<(Vec<u32>, f32)>
+    where
+        F: Fn(&[u32], usize) -> Result<Vec<f32>>,
+    {
+        let mut pieces = vec![start_id];
+        let mut probability = start_probability;
+        let mut ids = ids.to_vec();
+        ids[position] = start_id;
+
+        while pieces.len() < MAX_WORD_PIECES {
+            let next_position = position + pieces.len();
+            ids.insert(next_position, self.mask_id);
+            let next_probabilities = predict(&ids, next_position)?;
+            let Some((next_id, next_probability)) = next_probabilities
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .map(|(id, probability)| (id as u32, *probability))
+            else {
+                break;
+            };
+            if self.is_special(next_id) || self.is_word_start(next_id) {
+                break;
+            }
+            ids[next_position] = next_id;
+            pieces.push(next_id);
+            probability *= next_probability;
+        }
+
+        Ok((pieces, probability))
+    }
+}
+
+impl MaskedLmModel for CandleMaskedLmModel {
+    fn fill_masks(&self, tokens: &[Option<&str>], top_k: usize) -> Result<Vec<Vec<(String, f32)>>> {
+        let text = tokens
+            .iter()
+            .map(|token| token.unwrap_or(&self.mask_token))
+            .collect::<String>();
+        let encoding = self
+            .tokenizer
+            .encode(text, true)
+            .map_err(|error| Error::Model(error.to_string()))?;
+        let mask_positions = encoding
+            .get_ids()
+            .iter()
+            .enumerate()
+            .filter(|(_, id)| **id == self.mask_id)
+            .map(|(position, _)| position)
+            .collect::<Vec<usize>>();
+        let num_masks = tokens.iter().filter(|token| token.is_none()).count();
+        if mask_positions.len() != num_masks {
+            return Err(Error::Model(format!(
+                "expected {} masks in the encoded text, got {}",
+                num_masks,
+                mask_positions.len()
+            )));
+        }
+        if mask_positions.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let ids = encoding.get_ids();
+        let input_ids = Tensor::new(ids, &self.device)?.unsqueeze(0)?;
+        let token_type_ids = input_ids.zeros_like()?;
+        let logits = self.network.forward(&input_ids, &token_type_ids, None)?.squeeze(0)?;
+
+        mask_positions
+            .into_iter()
+            .map(|position| {
+                let probabilities = candle_nn::ops::softmax(&logits.i(position)?, D::Minus1)?.to_vec1::<f32>()?;
+                self.top_words(ids, position, probabilities, top_k, |ids, position| {
+                    self.predict(ids, position)
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_nn::VarMap;
+    use std::collections::HashMap;
+    use tokenizers::decoders::wordpiece::WordPiece as WordPieceDecoder;
+    use tokenizers::models::wordlevel::WordLevel;
+    use tokenizers::pre_tokenizers::whitespace::Whitespace;
+    use tokenizers::AddedToken;
+
+    const WORDS: [&str; 14] = [
+        "[PAD]", "[UNK]", "[MASK]", "the", "quick", "brown", "fox", "jumps", "over", "lazy", "dog", "##s", ".", "cat",
+    ];
+
+    /// Write tiny BERT checkpoint with random weights and word-level tokenizer to the temporary directory
+    fn write_checkpoint() -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("fast_aug_bert_checkpoint_{}", rand::random::<u64>()));
+        fs::create_dir_all(&path).unwrap();
+
+        let config = serde_json::json!({
+            "vocab_size": WORDS.len(),
+            "hidden_size": 8,
+            "num_hidden_layers": 1,
+            "num_attention_heads": 2,
+            "intermediate_size": 16,
+            "hidden_act": "gelu",
+            "hidden_dropout_prob": 0.0,
+            "max_position_embeddings": 64,
+            "type_vocab_size": 2,
+            "initializer_range": 0.02,
+            "layer_norm_eps": 1e-12,
+            "pad_token_id": 0
+        });
+        fs::write(path.join("config.json"), config.to_string()).unwrap();
+
+        // Random weights are created by the model while loading from the empty VarMap
+        let var_map = VarMap::new();
+        let vb = VarBuilder::from_varmap(&var_map, DType::F32, &Device::Cpu);
+        let config: bert::Config = serde_json::from_value(config).unwrap();
+        bert::BertForMaskedLM::load(vb, &config).unwrap();
+        var_map.save(path.join("model.safetensors")).unwrap();
+
+        let vocab = WORDS
+            .iter()
+            .enumerate()
+            .map(|(id, word)| (word.to_string(), id as u32))
+            .collect::<HashMap<String, u32>>();
+        let model = WordLevel::builder()
+            .vocab(vocab.into_iter().collect())
+            .unk_token("[UNK]".to_string())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(model);
+        tokenizer.with_pre_tokenizer(Some(Whitespace {}));
+        tokenizer.with_decoder(Some(WordPieceDecoder::default()));
+        tokenizer.add_special_tokens(&[
+            AddedToken::from("[PAD]", true),
+            AddedToken::from("[UNK]", true),
+            AddedToken::from("[MASK]", true),
+        ]);
+        tokenizer.save(path.join("tokenizer.json"), false).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn test_fill_masks_random_checkpoint() {
+        let path = write_checkpoint();
+        let model = CandleMaskedLmModel::from_dir(&path).unwrap();
+        let tokens = [
+            Some("the"),
+            Some(" "),
+            None,
+            Some(" "),
+            Some("fox"),
+            Some(" "),
+            None,
+            Some("."),
+        ];
+
+        let candidates = model.fill_masks(&tokens, 4).unwrap();
+
+        assert_eq!(candidates.len(), 2);
+        for mask_candidates in candidates {
+            assert_eq!(mask_candidates.len(), 4);
+            assert!(mask_candidates.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+            for (word, probability) in mask_candidates {
+                // Word-start pieces can be continued with "##s" pieces, e.g. "dogs"
+                let mut stem = word.as_str();
+                while !(WORDS[3..].contains(&stem) && stem != "##s") {
+                    stem = stem
+                        .strip_suffix('s')
+                        .unwrap_or_else(|| panic!("unexpected word {}", word));
+                }
+                assert!(probability > 0.0 && probability <= 1.0);
+            }
+        }
+        fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn test_top_words_merges_pieces() {
+        let path = write_checkpoint();
+        let model = CandleMaskedLmModel::from_dir(&path).unwrap();
+        let id = |word: &str| WORDS.iter().position(|w| *w == word).unwrap();
+        let one_hot = |word: &str| {
+            let mut probabilities = vec![0.0; WORDS.len()];
+            probabilities[id(word)] = 1.0;
+            probabilities
+        };
+        // "dog" is continued with "##s", other words are followed by the next word "."
+        let predict = |ids: &[u32], position: usize| -> Result<Vec<f32>> {
+            match ids[position - 1] as usize {
+                previous if previous == id("dog") => Ok(one_hot("##s")),
+                _ => Ok(one_hot(".")),
+            }
+        };
+        let mut probabilities = vec![0.0; WORDS.len()];
+        probabilities[id("dog")] = 0.5;
+        probabilities[id("##s")] = 0.3;
+        probabilities[id("cat")] = 0.2;
+        let ids = [id("the"), id("[MASK]")].map(|id| id as u32);
+
+        let words = model.top_words(&ids, 1, probabilities, 2, predict).unwrap();
+
+        assert_eq!(words, vec![("dogs".to_string(), 0.5), ("cat".to_string(), 0.2)]);
+        fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn test_fill_masks_without_masks() {
+        let path = write_checkpoint();
+        let model = CandleMaskedLmModel::from_dir(&path).unwrap();
+
+        assert!(model
+            .fill_masks(&[Some("the"), Some(" "), Some("fox")], 4)
+            .unwrap()
+            .is_empty());
+        fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn test_from_dir_missing_files() {
+        let result = CandleMaskedLmModel::from_dir("not_existing_dir");
+
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+}
@@ -0,0 +1,368 @@
+use super::seq2seq_model::Seq2SeqModel;
+use crate::error::{Error, Result};
+use candle_core::{DType, Device, Tensor};
+use candle_nn::VarBuilder;
+use candle_transformers::generation::LogitsProcessor;
+use candle_transformers::models::{marian, t5};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+use tokenizers::Tokenizer;
+
+/// Architectures of seq2seq checkpoints supported by `CandleSeq2SeqModel`
+/// Serialized in lowercase, e.g. "marian"
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Seq2SeqArchitecture {
+    /// MarianMT translation models, e.g. Helsinki-NLP/opus-mt-en-de
+    Marian,
+    /// T5 models, e.g. paraphrasing fine-tunes of t5-small
+    T5,
+}
+
+/// Parameters of the text generation, missing fields are deserialized with default values
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GenerationParameters {
+    /// Maximum number of generated tokens
+    pub max_length: usize,
+    /// Sampling temperature, greedy decoding if not set
+    pub temperature: Option<f64>,
+    /// Nucleus sampling probability, used with temperature only
+    pub top_p: Option<f64>,
+    /// Text prepended to each input, e.g. "paraphrase: " task prefix for T5
+    pub prefix: Option<String>,
+}
+
+impl Default for GenerationParameters {
+    fn default() -> Self {
+        GenerationParameters {
+            max_length: 128,
+            temperature: None,
+            top_p: None,
+            prefix: None,
+        }
+    }
+}
+
+impl GenerationParameters {
+    /// Create new GenerationParameters
+    /// # Arguments
+    /// * `max_length` - Maximum number of generated tokens
+    /// * `temperature` - Sampling temperature, greedy decoding if not set
+    /// * `top_p` - Nucleus sampling probability
+    /// * `prefix` - Text prepended to each input
+    pub fn new(max_length: usize, temperature: Option<f64>, top_p: Option<f64>, prefix: Option<String>) -> Self {
+        GenerationParameters {
+            max_length,
+            temperature,
+            top_p,
+            prefix,
+        }
+    }
+}
+
+/// Loaded network, generation mutates its kv cache
+enum Network {
+    Marian(marian::MTModel, marian::Config),
+    T5(t5::T5ForConditionalGeneration, t5::Config),
+}
+
+/// Seq2seq model running local MarianMT or T5 checkpoint with candle on CPU
+///
+/// Checkpoint directory should contain files in HuggingFace format:
+/// * `config.json` - model config
+/// * `model.safetensors` - model weights
+/// * `tokenizer.json` - tokenizer of the inputs (and outputs)
+/// * `target_tokenizer.json` - optional tokenizer of the outputs, if it differs from the input one (Marian)
+///
+/// Texts of the batch are generated one by one, as the model is not thread-safe it is locked during generation.
+/// There is no batched inference (candle Marian and T5 encoders take no padding mask), and parallel augmentation
+/// only contends on the lock.
+pub struct CandleSeq2SeqModel {
+    network: Mutex<Network>,
+    tokenizer: Tokenizer,
+    target_tokenizer: Tokenizer,
+    generation_params: GenerationParameters,
+    device: Device,
+}
+
+impl CandleSeq2SeqModel {
+    /// Load model from the checkpoint directory
+    /// Returns error if files are missing or do not match the architecture
+    ///
+    /// # Arguments
+    /// * `path` - Path to the checkpoint directory
+    /// * `architecture` - Architecture of the checkpoint
+    /// * `generation_params` - Parameters of the text generation
+    pub fn from_dir<P: AsRef<Path>>(
+        path: P,
+        architecture: Seq2SeqArchitecture,
+        generation_params: GenerationParameters,
+    ) -> Result<Self> {
+        let path = path.as_ref();
+        let device = Device::Cpu;
+
+        let config = fs::read_to_string(path.join("config.json"))?;
+        // Safety: weights file is memory mapped, it should not be modified while the model is loaded
+        let vb =
+            unsafe { VarBuilder::from_mmaped_safetensors(&[path.join("model.safetensors")], DType::F32, &device)? };
+        let network = match architecture {
+            Seq2SeqArchitecture::Marian => {
+                let config: marian::Config = serde_json::from_str(&config)?;
+                Network::Marian(marian::MTModel::new(&config, vb)?, config)
+            }
+            Seq2SeqArchitecture::T5 => {
+                let config: t5::Config = serde_json::from_str(&config)?;
+                Network::T5(t5::T5ForConditionalGeneration::load(vb, &config)?, config)
+            }
+        };
+
+        let tokenizer = load_tokenizer(&path.join("tokenizer.json"))?;
+        let target_tokenizer_path = path.join("target_tokenizer.json");
+        let target_tokenizer = if target_tokenizer_path.exists() {
+            load_tokenizer(&target_tokenizer_path)?
+        } else {
+            tokenizer.clone()
+        };
+
+        Ok(CandleSeq2SeqModel {
+            network: Mutex::new(network),
+            tokenizer,
+            target_tokenizer,
+            generation_params,
+            device,
+        })
+    }
+
+    /// Generate output token ids for the single input
+    fn generate_ids(&self, network: &mut Network, input: &str, seed: u64) -> Result<Vec<u32>> {
+        let input = match &self.generation_params.prefix {
+            Some(prefix) => format!("{}{}", prefix, input),
+            None => input.to_string(),
+        };
+        let mut input_ids = self
+            .tokenizer
+            .encode(input, true)
+            .map_err(|error| Error::Model(error.to_string()))?
+            .get_ids()
+            .to_vec();
+        let mut logits_processor =
+            LogitsProcessor::new(seed, self.generation_params.temperature, self.generation_params.top_p);
+
+        let mut output_ids = Vec::new();
+        match network {
+            Network::Marian(model, config) => {
+                // Marian tokenizers may not add end of sequence token
+                if input_ids.last() != Some(&config.eos_token_id) {
+                    input_ids.push(config.eos_token_id);
+                }
+                model.reset_kv_cache();
+                let input_ids = Tensor::new(input_ids.as_slice(), &self.device)?.unsqueeze(0)?;
+                let encoder_output = model.encoder().forward(&input_ids, 0)?;
+
+                let mut token_ids = vec![config.decoder_start_token_id];
+                for index in 0..self.generation_params.max_length {
+                    // With kv cache only the last token is passed after the first step
+                    let context_size = if index >= 1 { 1 } else { token_ids.len() };
+                    let start_pos = token_ids.len().saturating_sub(context_size);
+                    let decoder_ids = Tensor::new(&token_ids[start_pos..], &self.device)?.unsqueeze(0)?;
+                    let logits = model.decode(&decoder_ids, &encoder_output, start_pos)?.squeeze(0)?;
+                    let logits = logits.get(logits.dim(0)? - 1)?;
+                    let token_id = logits_processor.sample(&logits)?;
+                    if token_id == config.eos_token_id || token_id == config.forced_eos_token_id {
+                        break;
+                    }
+                    token_ids.push(token_id);
+                    output_ids.push(token_id);
+                }
+            }
+            Network::T5(model, config) => {
+                model.clear_kv_cache();
+                let input_ids = Tensor::new(input_ids.as_slice(), &self.device)?.unsqueeze(0)?;
+                let encoder_output = model.encode(&input_ids)?;
+
+                let mut token_ids = vec![config.decoder_start_token_id.unwrap_or(config.pad_token_id) as u32];
+                for index in 0..self.generation_params.max_length {
+                    // With kv cache only the last token is passed after the first step
+                    let decoder_ids = if index == 0 || !config.use_cache {
+                        Tensor::new(token_ids.as_slice(), &self.device)?.unsqueeze(0)?
+                    } else {
+                        Tensor::new(&token_ids[token_ids.len() - 1..], &self.device)?.unsqueeze(0)?
+                    };
+                    let logits = model.decode(&decoder_ids, &encoder_output)?.squeeze(0)?;
+                    let token_id = logits_processor.sample(&logits)?;
+                    if token_id as usize == config.eos_token_id {
+                        break;
+                    }
+                    token_ids.push(token_id);
+                    output_ids.push(token_id);
+                }
+            }
+        }
+
+        Ok(output_ids)
+    }
+}
+
+impl Seq2SeqModel for CandleSeq2SeqModel {
+    fn generate(&self, inputs: &[String], rng: &mut dyn RngCore) -> Result<Vec<String>> {
+        let mut network = self
+            .network
+            .lock()
+            .map_err(|_| Error::Model("model is poisoned by a panic in other thread".to_string()))?;
+
+        inputs
+            .iter()
+            .map(|input| {
+                let output_ids = self.generate_ids(&mut network, input, rng.next_u64())?;
+                self.target_tokenizer
+                    .decode(&output_ids, true)
+                    .map_err(|error| Error::Model(error.to_string()))
+            })
+            .collect()
+    }
+}
+
+fn load_tokenizer(path: &Path) -> Result<Tokenizer> {
+    Tokenizer::from_file(path).map_err(|error| Error::Model(format!("{}: {}", path.display(), error)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_nn::VarMap;
+    use std::collections::HashMap;
+    use test_case::test_case;
+    use tokenizers::models::wordlevel::WordLevel;
+    use tokenizers::pre_tokenizers::whitespace::Whitespace;
+
+    const WORDS: [&str; 16] = [
+        "<pad>", "</s>", "<unk>", "the", "quick", "brown", "fox", "jumps", "over", "lazy", "dog", "a", "cat", "sleeps",
+        "on", "sofa",
+    ];
+
+    fn marian_config() -> serde_json::Value {
+        serde_json::json!({
+            "vocab_size": WORDS.len(),
+            "max_position_embeddings": 64,
+            "encoder_layers": 1,
+            "encoder_ffn_dim": 16,
+            "encoder_attention_heads": 2,
+            "decoder_layers": 1,
+            "decoder_ffn_dim": 16,
+            "decoder_attention_heads": 2,
+            "use_cache": true,
+            "is_encoder_decoder": true,
+            "activation_function": "swish",
+            "d_model": 8,
+            "decoder_start_token_id": 0,
+            "scale_embedding": true,
+            "pad_token_id": 0,
+            "eos_token_id": 1,
+            "forced_eos_token_id": 1,
+            "share_encoder_decoder_embeddings": true
+        })
+    }
+
+    fn t5_config() -> serde_json::Value {
+        serde_json::json!({
+            "vocab_size": WORDS.len(),
+            "d_model": 8,
+            "d_kv": 4,
+            "d_ff": 16,
+            "num_layers": 1,
+            "num_heads": 2,
+            "relative_attention_num_buckets": 8,
+            "dropout_rate": 0.0,
+            "layer_norm_epsilon": 1e-6,
+            "initializer_factor": 1.0,
+            "feed_forward_proj": "relu",
+            "is_encoder_decoder": true,
+            "pad_token_id": 0,
+            "eos_token_id": 1,
+            "decoder_start_token_id": 0
+        })
+    }
+
+    /// Write tiny checkpoint with random weights and word-level tokenizer to the temporary directory
+    fn write_checkpoint(architecture: Seq2SeqArchitecture) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "fast_aug_{:?}_checkpoint_{}",
+            architecture,
+            rand::random::<u64>()
+        ));
+        fs::create_dir_all(&path).unwrap();
+
+        let config = match architecture {
+            Seq2SeqArchitecture::Marian => marian_config(),
+            Seq2SeqArchitecture::T5 => t5_config(),
+        };
+        fs::write(path.join("config.json"), config.to_string()).unwrap();
+
+        // Random weights are created by the model while loading from the empty VarMap
+        let var_map = VarMap::new();
+        let vb = VarBuilder::from_varmap(&var_map, DType::F32, &Device::Cpu);
+        match architecture {
+            Seq2SeqArchitecture::Marian => {
+                let config: marian::Config = serde_json::from_value(config).unwrap();
+                marian::MTModel::new(&config, vb).unwrap();
+            }
+            Seq2SeqArchitecture::T5 => {
+                let config: t5::Config = serde_json::from_value(config).unwrap();
+                t5::T5ForConditionalGeneration::load(vb, &config).unwrap();
+            }
+        }
+        var_map.save(path.join("model.safetensors")).unwrap();
+
+        let vocab = WORDS
+            .iter()
+            .enumerate()
+            .map(|(id, word)| (word.to_string(), id as u32))
+            .collect::<HashMap<String, u32>>();
+        let model = WordLevel::builder()
+            .vocab(vocab.into_iter().collect())
+            .unk_token("<unk>".to_string())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(model);
+        tokenizer.with_pre_tokenizer(Some(Whitespace {}));
+        tokenizer.save(path.join("tokenizer.json"), false).unwrap();
+
+        path
+    }
+
+    #[test_case(Seq2SeqArchitecture::Marian ; "marian")]
+    #[test_case(Seq2SeqArchitecture::T5 ; "t5")]
+    fn test_generate_random_checkpoint(architecture: Seq2SeqArchitecture) {
+        let path = write_checkpoint(architecture);
+        let generation_params = GenerationParameters::new(5, None, None, None);
+        let model = CandleSeq2SeqModel::from_dir(&path, architecture, generation_params).unwrap();
+        let inputs = vec!["the quick brown fox".to_string(), "a cat sleeps".to_string()];
+
+        let outputs = model.generate(&inputs, &mut rand::thread_rng()).unwrap();
+        // Greedy decoding is deterministic
+        let outputs_again = model.generate(&inputs, &mut rand::thread_rng()).unwrap();
+
+        assert_eq!(outputs.len(), inputs.len());
+        assert_eq!(outputs, outputs_again);
+        for output in outputs {
+            assert!(output.split_whitespace().count() <= 5);
+            assert!(output.split_whitespace().all(|word| WORDS.contains(&word)));
+        }
+        fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn test_from_dir_missing_files() {
+        let result = CandleSeq2SeqModel::from_dir(
+            "not_existing_dir",
+            Seq2SeqArchitecture::T5,
+            GenerationParameters::default(),
+        );
+
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+}
@@ -0,0 +1,280 @@
+use crate::error::{Error, Result};
+use flate2::read::GzDecoder;
+use icu_locid::Locale;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+/// CLDR emoji annotations embedded at build time, gzipped minified json (see build.rs)
+const EMBEDDED_ANNOTATIONS: [(&str, &[u8]); 2] = [
+    (
+        "en",
+        include_bytes!(concat!(env!("OUT_DIR"), "/emoji/annotations_en.json.gz")),
+    ),
+    (
+        "de",
+        include_bytes!(concat!(env!("OUT_DIR"), "/emoji/annotations_de.json.gz")),
+    ),
+];
+
+/// Emoji presentation selectors and skin tone modifiers, emoji are annotated without them
+const EMOJI_MODIFIERS: [char; 7] = ['\u{FE0E}', '\u{FE0F}', '🏻', '🏼', '🏽', '🏾', '🏿'];
+
+/// CLDR annotations json file, e.g. `cldr-annotations-full/annotations/en/annotations.json`
+#[derive(Deserialize)]
+struct CldrAnnotationsFile {
+    #[serde(alias = "annotationsDerived")]
+    annotations: CldrAnnotations,
+}
+
+#[derive(Deserialize)]
+struct CldrAnnotations {
+    identity: CldrIdentity,
+    annotations: HashMap<String, CldrAnnotation>,
+}
+
+#[derive(Deserialize)]
+struct CldrIdentity {
+    language: String,
+    #[serde(default)]
+    script: Option<String>,
+    #[serde(default)]
+    territory: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CldrAnnotation {
+    #[serde(default)]
+    default: Vec<String>,
+    #[serde(default)]
+    tts: Vec<String>,
+}
+
+/// Emoji model holds CLDR emoji annotations - text name and keywords of each emoji
+///
+/// Emoji are looked up without presentation selectors and skin tone modifiers, e.g. "👍🏽" is annotated as "👍".
+/// Keywords are lowercased, so words are matched with keywords case-insensitively.
+pub struct EmojiModel {
+    names: HashMap<String, String>,
+    keywords: HashMap<String, Vec<String>>,
+    /// Emoji annotated with each keyword, sorted to sample reproducibly with a seeded rng
+    keyword_emojis: HashMap<String, Vec<String>>,
+    pub locale_str: String,
+}
+
+impl EmojiModel {
+    /// Create emoji model from annotations
+    ///
+    /// # Arguments
+    /// * `annotations` - Emoji with its text name (if any) and keywords
+    /// * `locale_string` - Language tag of the annotations
+    pub fn new(annotations: Vec<(String, Option<String>, Vec<String>)>, locale_string: String) -> Self {
+        let mut names = HashMap::new();
+        let mut keywords = HashMap::new();
+        let mut keyword_emojis: HashMap<String, Vec<String>> = HashMap::new();
+        for (emoji, name, emoji_keywords) in annotations {
+            let emoji = Self::normalize(&emoji);
+            let emoji_keywords = emoji_keywords.iter().fold(Vec::new(), |mut keywords, keyword| {
+                let keyword = keyword.to_lowercase();
+                if !keywords.contains(&keyword) {
+                    keywords.push(keyword);
+                }
+                keywords
+            });
+            for keyword in &emoji_keywords {
+                keyword_emojis.entry(keyword.clone()).or_default().push(emoji.clone());
+            }
+            if let Some(name) = name {
+                names.insert(emoji.clone(), name);
+            }
+            keywords.insert(emoji, emoji_keywords);
+        }
+        for emojis in keyword_emojis.values_mut() {
+            emojis.sort_unstable();
+            emojis.dedup();
+        }
+
+        EmojiModel {
+            names,
+            keywords,
+            keyword_emojis,
+            locale_str: locale_string,
+        }
+    }
+
+    /// Load emoji model from CLDR annotations json string
+    /// Both `annotations` and `annotationsDerived` files of
+    /// [cldr-json](https://github.com/unicode-org/cldr-json/tree/main/cldr-json/cldr-annotations-full) are supported
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::models::text::EmojiModel;
+    ///
+    /// let emoji_model = EmojiModel::from_json_str(r#"{"annotations": {
+    ///     "identity": {"language": "en"},
+    ///     "annotations": {"🔥": {"default": ["fire", "flame"], "tts": ["fire"]}}
+    /// }}"#).unwrap();
+    ///
+    /// assert_eq!(emoji_model.get_name("🔥"), Some(&"fire".to_string()));
+    /// assert_eq!(emoji_model.get_emojis("Flame"), vec!["🔥"]);
+    /// ```
+    pub fn from_json_str(json: &str) -> Result<Self> {
+        let file: CldrAnnotationsFile = serde_json::from_str(json)?;
+        let identity = file.annotations.identity;
+        let locale_string = [Some(identity.language), identity.script, identity.territory]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<String>>()
+            .join("-");
+
+        let annotations = file
+            .annotations
+            .annotations
+            .into_iter()
+            .map(|(emoji, annotation)| (emoji, annotation.tts.into_iter().next(), annotation.default))
+            .collect();
+        Ok(EmojiModel::new(annotations, locale_string))
+    }
+
+    /// Load emoji model from CLDR annotations json file, see `from_json_str`
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_json_str(&fs::read_to_string(path)?)
+    }
+
+    /// Load emoji model embedded into the library for the language tag (en, de)
+    /// Panics if there is no embedded annotations for the language, see `try_from_locale_str`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::models::text::EmojiModel;
+    ///
+    /// let emoji_model = EmojiModel::from_locale_str("en-US");
+    ///
+    /// assert_eq!(emoji_model.get_name("😂"), Some(&"face with tears of joy".to_string()));
+    /// ```
+    pub fn from_locale_str(locale_str: &str) -> Self {
+        Self::try_from_locale_str(locale_str).expect("locale should have embedded emoji annotations")
+    }
+
+    /// Load emoji model embedded into the library for the language tag (en, de)
+    /// Returns `Error::InvalidLocale` if the language tag is invalid or there is no embedded annotations for it
+    pub fn try_from_locale_str(locale_str: &str) -> Result<Self> {
+        let invalid_locale = || Error::InvalidLocale(locale_str.to_string());
+        let locale = Locale::try_from_bytes(locale_str.as_bytes()).map_err(|_| invalid_locale())?;
+        let (_, compressed) = EMBEDDED_ANNOTATIONS
+            .iter()
+            .find(|(language, _)| *language == locale.id.language.as_str())
+            .ok_or_else(invalid_locale)?;
+
+        let mut json = String::new();
+        GzDecoder::new(*compressed).read_to_string(&mut json)?;
+        Self::from_json_str(&json)
+    }
+
+    /// Remove presentation selectors and skin tone modifiers from the emoji
+    pub fn normalize(emoji: &str) -> String {
+        emoji.chars().filter(|c| !EMOJI_MODIFIERS.contains(c)).collect()
+    }
+
+    /// Number of annotated emoji
+    pub fn len(&self) -> usize {
+        self.keywords.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keywords.is_empty()
+    }
+
+    /// Check if the emoji is annotated
+    pub fn contains(&self, emoji: &str) -> bool {
+        self.keywords.contains_key(&Self::normalize(emoji))
+    }
+
+    /// Get text name of the emoji, None if the emoji has no name
+    pub fn get_name(&self, emoji: &str) -> Option<&String> {
+        self.names.get(&Self::normalize(emoji))
+    }
+
+    /// Get lowercased keywords of the emoji, empty if the emoji is not annotated
+    pub fn get_keywords(&self, emoji: &str) -> Vec<&String> {
+        match self.keywords.get(&Self::normalize(emoji)) {
+            Some(keywords) => keywords.iter().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Get emoji annotated with the word as a keyword (case-insensitive)
+    pub fn get_emojis(&self, word: &str) -> Vec<&String> {
+        match self.keyword_emojis.get(&word.to_lowercase()) {
+            Some(emojis) => emojis.iter().collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const ANNOTATIONS_JSON: &str = r#"{"annotationsDerived": {
+        "identity": {"language": "sr", "script": "Latn", "territory": "ME"},
+        "annotations": {
+            "❤": {"default": ["heart", "love"], "tts": ["red heart"]},
+            "😍": {"default": ["Love", "face", "smile"], "tts": ["smiling face with heart-eyes"]},
+            "✨": {"default": ["sparkle"]}
+        }
+    }}"#;
+
+    #[test]
+    fn test_from_json_str() {
+        let emoji_model = EmojiModel::from_json_str(ANNOTATIONS_JSON).unwrap();
+
+        assert_eq!(emoji_model.locale_str, "sr-Latn-ME");
+        assert_eq!(emoji_model.len(), 3);
+        assert_eq!(emoji_model.get_name("✨"), None);
+        assert_eq!(emoji_model.get_keywords("😍"), vec!["love", "face", "smile"]);
+        assert_eq!(emoji_model.get_emojis("LOVE"), vec!["❤", "😍"]);
+        assert!(emoji_model.get_emojis("fire").is_empty());
+    }
+
+    #[test_case("❤️", Some("red heart") ; "presentation selector")]
+    #[test_case("❤", Some("red heart") ; "without presentation selector")]
+    #[test_case("😍🏿", Some("smiling face with heart-eyes") ; "skin tone")]
+    #[test_case("🔥", None ; "not annotated")]
+    fn test_get_name(emoji: &str, expected: Option<&str>) {
+        let emoji_model = EmojiModel::from_json_str(ANNOTATIONS_JSON).unwrap();
+
+        assert_eq!(emoji_model.get_name(emoji).map(|name| name.as_str()), expected);
+        assert_eq!(emoji_model.contains(emoji), expected.is_some());
+    }
+
+    #[test_case("en", "👏🏼", "clapping hands" ; "en")]
+    #[test_case("en-AU", "😂", "face with tears of joy" ; "en with region")]
+    #[test_case("de", "😂", "Gesicht mit Freudentränen" ; "de")]
+    fn test_from_locale_str(locale_str: &str, emoji: &str, expected: &str) {
+        let emoji_model = EmojiModel::from_locale_str(locale_str);
+
+        assert_eq!(emoji_model.get_name(emoji), Some(&expected.to_string()));
+    }
+
+    #[test_case("fr" ; "no embedded annotations")]
+    #[test_case("not a locale" ; "invalid language tag")]
+    fn test_try_from_locale_str_invalid(locale_str: &str) {
+        assert!(matches!(
+            EmojiModel::try_from_locale_str(locale_str),
+            Err(Error::InvalidLocale(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_path() {
+        let emoji_model = EmojiModel::from_path("data/emoji/annotations_en.json").unwrap();
+
+        assert_eq!(emoji_model.locale_str, "en");
+        assert!(emoji_model.get_emojis("pizza").contains(&&"🍕".to_string()));
+        assert!(EmojiModel::from_path("data/emoji/missing.json").is_err());
+    }
+}
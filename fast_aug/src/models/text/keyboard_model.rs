@@ -0,0 +1,277 @@
+use crate::error::{Error, Result};
+use icu_locid::Locale;
+use rand::seq::SliceRandom;
+use rand::RngCore;
+use std::collections::HashMap;
+
+/// Physical keyboard layouts supported by KeyboardModel
+///
+/// KeyboardLayout::Qwerty -> Most latin script languages (en, es, it, pl, etc.)
+/// KeyboardLayout::Qwertz -> Central european languages (de, cs, hu, etc.)
+/// KeyboardLayout::Azerty -> French and Belgian layouts
+/// KeyboardLayout::Jcuken -> Russian (ЙЦУКЕН) layout
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum KeyboardLayout {
+    Qwerty,
+    Qwertz,
+    Azerty,
+    Jcuken,
+}
+
+impl KeyboardLayout {
+    /// Letter rows of the layout (unshifted layer), from top to bottom
+    pub fn rows(&self) -> &'static [&'static str] {
+        match self {
+            KeyboardLayout::Qwerty => &["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            KeyboardLayout::Qwertz => &["qwertzuiopü", "asdfghjklöä", "yxcvbnm"],
+            KeyboardLayout::Azerty => &["azertyuiop", "qsdfghjklm", "wxcvbn"],
+            KeyboardLayout::Jcuken => &["йцукенгшщзхъ", "фывапролджэ", "ячсмитьбю"],
+        }
+    }
+
+    /// Select layout commonly used for the given locale
+    /// Returns None if there is no known layout for the language
+    pub fn from_locale(locale: &Locale) -> Option<Self> {
+        let language = locale.id.language.as_str();
+        let region = locale.id.region.as_ref().map(|region| region.as_str());
+
+        match (language, region) {
+            ("fr", Some("CA")) => Some(KeyboardLayout::Qwerty),
+            ("fr", Some("CH")) => Some(KeyboardLayout::Qwertz),
+            ("fr", _) => Some(KeyboardLayout::Azerty),
+            ("de" | "cs" | "sk" | "hu" | "sl" | "hr" | "bs", _) => Some(KeyboardLayout::Qwertz),
+            ("ru", _) => Some(KeyboardLayout::Jcuken),
+            (
+                "en" | "es" | "it" | "pt" | "nl" | "pl" | "ro" | "sv" | "da" | "nb" | "nn" | "no" | "fi" | "et" | "lv"
+                | "lt" | "id" | "ms" | "tr" | "ca" | "ga" | "eu" | "gl" | "sw" | "tl",
+                _,
+            ) => Some(KeyboardLayout::Qwerty),
+            _ => None,
+        }
+    }
+}
+
+/// Keyboard model holds adjacency graph of the physical keys
+///
+/// Rows are considered to be staggered, each lower row is shifted right by half a key.
+/// So key at (row, col) is adjacent to (row, col±1), (row-1, col), (row-1, col+1), (row+1, col-1) and (row+1, col).
+/// Shift layer (capital letters) is handled separately, so capital letters are only adjacent to capital letters.
+pub struct KeyboardModel {
+    pub layout: KeyboardLayout,
+    pub adjacency: HashMap<char, Vec<char>>,
+    pub locale_str: String,
+}
+
+impl KeyboardModel {
+    /// Create keyboard model from the given layout
+    ///
+    /// # Arguments
+    /// * `layout` - Physical keyboard layout
+    /// * `locale_string` - Language tag the model is created for
+    pub fn new(layout: KeyboardLayout, locale_string: String) -> Self {
+        let lower_rows: Vec<Vec<char>> = layout.rows().iter().map(|row| row.chars().collect()).collect();
+        let upper_rows: Vec<Vec<char>> = lower_rows
+            .iter()
+            .map(|row| row.iter().map(|c| c.to_uppercase().next().unwrap()).collect())
+            .collect();
+
+        let mut adjacency = HashMap::new();
+        Self::build_adjacency(&lower_rows, &mut adjacency);
+        Self::build_adjacency(&upper_rows, &mut adjacency);
+
+        KeyboardModel {
+            layout,
+            adjacency,
+            locale_str: locale_string,
+        }
+    }
+
+    fn build_adjacency(rows: &[Vec<char>], adjacency: &mut HashMap<char, Vec<char>>) {
+        let get_key = |row: isize, col: isize| -> Option<char> {
+            if row < 0 || col < 0 {
+                return None;
+            }
+            rows.get(row as usize).and_then(|r| r.get(col as usize)).copied()
+        };
+
+        for (row_idx, row) in rows.iter().enumerate() {
+            for (col_idx, key) in row.iter().enumerate() {
+                let (r, c) = (row_idx as isize, col_idx as isize);
+                let neighbours = [
+                    (r, c - 1),
+                    (r, c + 1),
+                    (r - 1, c),
+                    (r - 1, c + 1),
+                    (r + 1, c - 1),
+                    (r + 1, c),
+                ]
+                .iter()
+                .filter_map(|&(r, c)| get_key(r, c))
+                .collect::<Vec<char>>();
+                adjacency.insert(*key, neighbours);
+            }
+        }
+    }
+
+    /// Create keyboard model using icu4x - icu_locid::Locale
+    /// Panics if keyboard layout is not known for the locale, see `try_from_locale`
+    ///
+    /// # Arguments
+    /// * `locale` - Locale from icu_locid::Locale
+    ///
+    /// # Examples
+    /// ```rust
+    /// use icu_locid::Locale;
+    /// use fast_aug::models::text::{KeyboardLayout, KeyboardModel};
+    ///
+    /// let locale = Locale::try_from_bytes(b"de-AT").unwrap();
+    /// let keyboard_model = KeyboardModel::from_locale(&locale);
+    ///
+    /// assert_eq!(keyboard_model.layout, KeyboardLayout::Qwertz);
+    /// ```
+    pub fn from_locale(locale: &Locale) -> Self {
+        Self::try_from_locale(locale).expect("keyboard layout should be known for locale")
+    }
+
+    /// Create keyboard model using icu4x - icu_locid::Locale
+    /// Returns `Error::InvalidLocale` if keyboard layout is not known for the locale
+    ///
+    /// # Arguments
+    /// * `locale` - Locale from icu_locid::Locale
+    pub fn try_from_locale(locale: &Locale) -> Result<Self> {
+        let layout = KeyboardLayout::from_locale(locale).ok_or_else(|| Error::InvalidLocale(locale.to_string()))?;
+        Ok(KeyboardModel::new(layout, locale.to_string()))
+    }
+
+    /// Create keyboard model using language tag
+    /// Panics if language tag is invalid, see `try_from_locale_str`
+    ///
+    /// # Arguments
+    /// * `locale_str` - Language tag, with or without script and region.
+    ///   See [Unicode Language Identifier](https://unicode.org/reports/tr35/#Unicode_language_identifier)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::models::text::{KeyboardLayout, KeyboardModel};
+    ///
+    /// let keyboard_model = KeyboardModel::from_locale_str(&"fr-FR");
+    ///
+    /// assert_eq!(keyboard_model.layout, KeyboardLayout::Azerty);
+    /// ```
+    pub fn from_locale_str(locale_str: &str) -> Self {
+        Self::try_from_locale_str(locale_str).expect("language tag should be valid")
+    }
+
+    /// Create keyboard model using language tag
+    /// Returns `Error::InvalidLocale` if language tag is invalid or keyboard layout is not known for it
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::models::text::KeyboardModel;
+    ///
+    /// assert!(KeyboardModel::try_from_locale_str("de-AT").is_ok());
+    /// assert!(KeyboardModel::try_from_locale_str("ja").is_err());
+    /// ```
+    pub fn try_from_locale_str(locale_str: &str) -> Result<Self> {
+        let locale =
+            Locale::try_from_bytes(locale_str.as_bytes()).map_err(|_| Error::InvalidLocale(locale_str.to_string()))?;
+        Self::try_from_locale(&locale)
+    }
+
+    /// Get keys adjacent to the given char, None if char is not on the keyboard
+    pub fn get_adjacent_chars(&self, c: char) -> Option<&Vec<char>> {
+        self.adjacency.get(&c)
+    }
+
+    /// Check if two chars are physically adjacent keys
+    pub fn is_adjacent(&self, a: char, b: char) -> bool {
+        self.adjacency.get(&a).is_some_and(|neighbours| neighbours.contains(&b))
+    }
+
+    /// Get random key adjacent to the given char
+    ///
+    /// # Arguments
+    /// * `c` - Char to find neighbour for
+    /// * `rng` - Random number generator
+    pub fn get_random_adjacent_char(&self, c: char, rng: &mut dyn RngCore) -> Option<char> {
+        self.adjacency
+            .get(&c)
+            .and_then(|neighbours| neighbours.choose(rng))
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("en", KeyboardLayout::Qwerty)]
+    #[test_case("en-US", KeyboardLayout::Qwerty)]
+    #[test_case("de", KeyboardLayout::Qwertz)]
+    #[test_case("de-CH", KeyboardLayout::Qwertz)]
+    #[test_case("fr", KeyboardLayout::Azerty)]
+    #[test_case("fr-BE", KeyboardLayout::Azerty)]
+    #[test_case("fr-CA", KeyboardLayout::Qwerty)]
+    #[test_case("fr-CH", KeyboardLayout::Qwertz)]
+    #[test_case("ru_RU", KeyboardLayout::Jcuken)]
+    fn test_from_locale_str(locale_str: &str, expected_layout: KeyboardLayout) {
+        let keyboard_model = KeyboardModel::from_locale_str(locale_str);
+
+        assert_eq!(keyboard_model.layout, expected_layout);
+        assert!(!keyboard_model.adjacency.is_empty());
+    }
+
+    #[test_case("invalid")]
+    #[test_case("sr-La!!!tn-RS")]
+    #[test_case("ja")]
+    fn test_from_locale_str_with_invalid_locale(locale_str: &str) {
+        let result = std::panic::catch_unwind(|| {
+            KeyboardModel::from_locale_str(locale_str);
+        });
+        assert!(result.is_err());
+    }
+
+    #[test_case("invalid")]
+    #[test_case("sr-La!!!tn-RS")]
+    #[test_case("ja")]
+    fn test_try_from_locale_str_with_invalid_locale(locale_str: &str) {
+        let result = KeyboardModel::try_from_locale_str(locale_str);
+        assert!(matches!(result, Err(Error::InvalidLocale(_))));
+    }
+
+    #[test_case(KeyboardLayout::Qwerty, 'a', vec!['q', 'w', 's', 'z'])]
+    #[test_case(KeyboardLayout::Qwerty, 's', vec!['a', 'd', 'w', 'e', 'z', 'x'])]
+    #[test_case(KeyboardLayout::Qwerty, 'p', vec!['o', 'l'])]
+    #[test_case(KeyboardLayout::Qwerty, 'S', vec!['A', 'D', 'W', 'E', 'Z', 'X'] ; "shift layer")]
+    #[test_case(KeyboardLayout::Qwertz, 'y', vec!['x', 'a', 's'])]
+    #[test_case(KeyboardLayout::Azerty, 'q', vec!['s', 'a', 'z', 'w'])]
+    #[test_case(KeyboardLayout::Jcuken, 'ф', vec!['ы', 'й', 'ц', 'я'])]
+    fn test_adjacent_chars(layout: KeyboardLayout, c: char, expected: Vec<char>) {
+        let keyboard_model = KeyboardModel::new(layout, "".to_string());
+
+        let mut adjacent = keyboard_model.get_adjacent_chars(c).unwrap().clone();
+        let mut expected = expected;
+        adjacent.sort();
+        expected.sort();
+        assert_eq!(adjacent, expected);
+    }
+
+    #[test]
+    fn test_random_adjacent_char_keeps_case() {
+        let keyboard_model = KeyboardModel::new(KeyboardLayout::Qwerty, "en".to_string());
+        let rng = &mut rand::thread_rng();
+
+        for _ in 0..100 {
+            assert!(keyboard_model
+                .get_random_adjacent_char('G', rng)
+                .unwrap()
+                .is_uppercase());
+            assert!(keyboard_model
+                .get_random_adjacent_char('g', rng)
+                .unwrap()
+                .is_lowercase());
+        }
+        assert_eq!(keyboard_model.get_random_adjacent_char('1', rng), None);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::error::Result;
+
+/// Masked language model filling masked words from the context, e.g. BERT-style model
+/// Implement it to plug any local model into `ContextualWordsAugmenter`
+/// (`CandleMaskedLmModel` with `candle` feature runs BERT checkpoints on CPU)
+pub trait MaskedLmModel: Send + Sync {
+    /// Predict candidate words for each masked token
+    /// Candidates are whole words (subword pieces merged into words), sorted by probability in descending order
+    ///
+    /// # Arguments
+    /// * `tokens` - Text split into tokens (words, spaces, punctuation), masked tokens are `None`
+    /// * `top_k` - Maximum number of candidates for each mask
+    ///
+    /// Returns candidates with probabilities for each mask, in order of the masks in the text
+    fn fill_masks(&self, tokens: &[Option<&str>], top_k: usize) -> Result<Vec<Vec<(String, f32)>>>;
+}
@@ -1,3 +1,29 @@
 mod alphabet_model;
+#[cfg(feature = "candle")]
+mod candle_masked_lm_model;
+#[cfg(feature = "candle")]
+mod candle_seq2seq_model;
+mod emoji_model;
+mod keyboard_model;
+mod masked_lm_model;
+mod pos_tagger_model;
+mod seq2seq_model;
+mod spelling_model;
+mod tf_idf_model;
+mod vocab_model;
+mod wordnet_model;
 
 pub use alphabet_model::AlphabetModel;
+#[cfg(feature = "candle")]
+pub use candle_masked_lm_model::CandleMaskedLmModel;
+#[cfg(feature = "candle")]
+pub use candle_seq2seq_model::{CandleSeq2SeqModel, GenerationParameters, Seq2SeqArchitecture};
+pub use emoji_model::EmojiModel;
+pub use keyboard_model::{KeyboardLayout, KeyboardModel};
+pub use masked_lm_model::MaskedLmModel;
+pub use pos_tagger_model::{AveragedPerceptronTagger, PosTagger};
+pub use seq2seq_model::{BackTranslationModel, Seq2SeqModel};
+pub use spelling_model::SpellingModel;
+pub use tf_idf_model::TfIdfModel;
+pub use vocab_model::VocabModel;
+pub use wordnet_model::WordNetModel;
@@ -0,0 +1,400 @@
+use crate::error::Result;
+use rand::seq::SliceRandom;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Part-of-speech tagger assigning a tag (e.g. "NN", "VBZ" or "ADJ") to each word of the sentence
+/// Implement it to plug any tagger into `RandomPosAugmenter` and `Doc::tag_pos`
+pub trait PosTagger: Send + Sync {
+    /// Tag words of the sentence, returns one tag for each word, in the same order
+    ///
+    /// # Arguments
+    /// * `words` - Words and punctuation of the sentence, without spaces
+    fn tag(&self, words: &[&str]) -> Vec<String>;
+}
+
+/// Context padding of the sentence, the same as in NLTK
+const START: [&str; 2] = ["-START-", "-START2-"];
+const END: [&str; 2] = ["-END-", "-END2-"];
+
+/// Averaged perceptron POS tagger (https://explosion.ai/blog/part-of-speech-pos-tagger-in-python)
+/// Features and json format follow NLTK `PerceptronTagger`, so NLTK weights can be converted:
+/// `{"weights": {feature: {tag: weight}}, "tagdict": {word: tag}, "classes": [tag]}`
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::models::text::{AveragedPerceptronTagger, PosTagger};
+///
+/// let sentences = vec![
+///     vec![("the", "DT"), ("cat", "NN"), ("sleeps", "VBZ")],
+///     vec![("a", "DT"), ("dog", "NN"), ("runs", "VBZ")],
+/// ];
+/// let tagger = AveragedPerceptronTagger::train(&sentences, 5, &mut rand::thread_rng());
+///
+/// assert_eq!(tagger.tag(&["the", "dog", "sleeps"]), vec!["DT", "NN", "VBZ"]);
+/// ```
+#[derive(Default, Serialize, Deserialize)]
+pub struct AveragedPerceptronTagger {
+    /// Weights of each feature for each tag
+    weights: HashMap<String, HashMap<String, f32>>,
+    /// Frequent unambiguous words with their tags, tagged without the model
+    #[serde(default)]
+    tagdict: HashMap<String, String>,
+    /// All known tags
+    classes: Vec<String>,
+}
+
+impl AveragedPerceptronTagger {
+    /// Create tagger from precomputed weights
+    ///
+    /// # Arguments
+    /// * `weights` - Weights of each feature for each tag
+    /// * `tagdict` - Frequent unambiguous words with their tags
+    /// * `classes` - All known tags
+    pub fn new(
+        weights: HashMap<String, HashMap<String, f32>>,
+        tagdict: HashMap<String, String>,
+        mut classes: Vec<String>,
+    ) -> Self {
+        // Sorted classes make ties between equal scores deterministic
+        classes.sort();
+        classes.dedup();
+        AveragedPerceptronTagger {
+            weights,
+            tagdict,
+            classes,
+        }
+    }
+
+    /// Train tagger on the tagged sentences
+    /// Sentences are shuffled after each iteration, weights are averaged over all updates
+    ///
+    /// # Arguments
+    /// * `sentences` - Sentences as lists of (word, tag) pairs
+    /// * `iterations` - Number of passes over the sentences
+    /// * `rng` - Random number generator to shuffle the sentences
+    pub fn train<S: AsRef<str>>(sentences: &[Vec<(S, S)>], iterations: usize, rng: &mut dyn RngCore) -> Self {
+        let classes = sentences
+            .iter()
+            .flatten()
+            .map(|(_, tag)| tag.as_ref().to_string())
+            .collect();
+        let mut tagger = AveragedPerceptronTagger::new(HashMap::new(), Self::make_tagdict(sentences), classes);
+
+        // Accumulated weights and the last update of each (feature, tag) pair, to average weights lazily
+        let mut totals: HashMap<(String, String), f32> = HashMap::new();
+        let mut timestamps: HashMap<(String, String), usize> = HashMap::new();
+        let mut num_updates = 0;
+
+        let mut order = (0..sentences.len()).collect::<Vec<usize>>();
+        for _ in 0..iterations {
+            for &sentence_index in &order {
+                let sentence = &sentences[sentence_index];
+                let words = sentence.iter().map(|(word, _)| word.as_ref()).collect::<Vec<&str>>();
+                let context = Self::make_context(&words);
+                let (mut prev, mut prev2) = (START[0].to_string(), START[1].to_string());
+                for (i, (word, tag)) in sentence.iter().enumerate() {
+                    let guess = match tagger.tagdict.get(word.as_ref()) {
+                        Some(guess) => guess.clone(),
+                        None => {
+                            let features = Self::get_features(i, word.as_ref(), &context, &prev, &prev2);
+                            let guess = tagger.predict(&features).to_string();
+                            num_updates += 1;
+                            if guess != tag.as_ref() {
+                                for feature in &features {
+                                    for (class, delta) in [(tag.as_ref(), 1.0), (guess.as_str(), -1.0)] {
+                                        let key = (feature.clone(), class.to_string());
+                                        let weight = tagger.weights.entry(feature.clone()).or_default();
+                                        let current = *weight.get(class).unwrap_or(&0.0);
+                                        let timestamp = timestamps.insert(key.clone(), num_updates).unwrap_or(0);
+                                        *totals.entry(key).or_insert(0.0) += (num_updates - timestamp) as f32 * current;
+                                        weight.insert(class.to_string(), current + delta);
+                                    }
+                                }
+                            }
+                            guess
+                        }
+                    };
+                    prev2 = std::mem::replace(&mut prev, guess);
+                }
+            }
+            order.shuffle(rng);
+        }
+
+        // Average weights over all updates, zero weights are dropped
+        for (feature, weights) in tagger.weights.iter_mut() {
+            weights.retain(|class, weight| {
+                let key = (feature.clone(), class.clone());
+                let timestamp = *timestamps.get(&key).unwrap_or(&0);
+                let total = totals.get(&key).unwrap_or(&0.0) + (num_updates - timestamp) as f32 * *weight;
+                *weight = (total / num_updates.max(1) as f32 * 1000.0).round() / 1000.0;
+                *weight != 0.0
+            });
+        }
+        tagger.weights.retain(|_, weights| !weights.is_empty());
+
+        tagger
+    }
+
+    /// Words seen at least 20 times with the same tag in at least 97% cases are tagged without the model
+    fn make_tagdict<S: AsRef<str>>(sentences: &[Vec<(S, S)>]) -> HashMap<String, String> {
+        let mut counts: HashMap<&str, HashMap<&str, usize>> = HashMap::new();
+        for (word, tag) in sentences.iter().flatten() {
+            *counts
+                .entry(word.as_ref())
+                .or_default()
+                .entry(tag.as_ref())
+                .or_insert(0) += 1;
+        }
+        counts
+            .into_iter()
+            .filter_map(|(word, tag_counts)| {
+                let total = tag_counts.values().sum::<usize>();
+                let (tag, mode) = tag_counts.into_iter().max_by_key(|(tag, count)| (*count, *tag))?;
+                (total >= 20 && mode as f32 / total as f32 >= 0.97).then(|| (word.to_string(), tag.to_string()))
+            })
+            .collect()
+    }
+
+    /// Normalize word for features: hyphenated words, years and numbers are replaced with placeholders
+    fn normalize(word: &str) -> String {
+        if word.contains('-') && !word.starts_with('-') {
+            "!HYPHEN".to_string()
+        } else if word.chars().all(|c| c.is_ascii_digit()) && word.len() == 4 {
+            "!YEAR".to_string()
+        } else if word.starts_with(|c: char| c.is_ascii_digit()) {
+            "!DIGITS".to_string()
+        } else {
+            word.to_lowercase()
+        }
+    }
+
+    /// Normalized words padded with START and END
+    fn make_context(words: &[&str]) -> Vec<String> {
+        START
+            .iter()
+            .map(|word| word.to_string())
+            .chain(words.iter().map(|word| Self::normalize(word)))
+            .chain(END.iter().map(|word| word.to_string()))
+            .collect()
+    }
+
+    /// Last (up to) 3 chars of the word
+    fn suffix(word: &str) -> String {
+        let num_chars = word.chars().count();
+        word.chars().skip(num_chars.saturating_sub(3)).collect()
+    }
+
+    /// Features of the i-th word: the word, its neighbours, their suffixes and previous predicted tags
+    fn get_features(i: usize, word: &str, context: &[String], prev: &str, prev2: &str) -> Vec<String> {
+        let i = i + START.len();
+        vec![
+            "bias".to_string(),
+            format!("i suffix {}", Self::suffix(word)),
+            format!("i pref1 {}", word.chars().next().unwrap_or_default()),
+            format!("i-1 tag {}", prev),
+            format!("i-2 tag {}", prev2),
+            format!("i tag+i-2 tag {} {}", prev, prev2),
+            format!("i word {}", context[i]),
+            format!("i-1 tag+i word {} {}", prev, context[i]),
+            format!("i-1 word {}", context[i - 1]),
+            format!("i-1 suffix {}", Self::suffix(&context[i - 1])),
+            format!("i-2 word {}", context[i - 2]),
+            format!("i+1 word {}", context[i + 1]),
+            format!("i+1 suffix {}", Self::suffix(&context[i + 1])),
+            format!("i+2 word {}", context[i + 2]),
+        ]
+    }
+
+    /// Predict the tag with the highest score, ties are broken by the tag name
+    fn predict(&self, features: &[String]) -> &str {
+        let mut scores: HashMap<&str, f32> = HashMap::new();
+        for weights in features.iter().filter_map(|feature| self.weights.get(feature)) {
+            for (class, weight) in weights {
+                *scores.entry(class.as_str()).or_insert(0.0) += weight;
+            }
+        }
+        self.classes
+            .iter()
+            .map(|class| (*scores.get(class.as_str()).unwrap_or(&0.0), class.as_str()))
+            .max_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(b.1)))
+            .map(|(_, class)| class)
+            .unwrap_or_default()
+    }
+
+    /// Serialize tagger to json string
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Load tagger from json string, created with `to_json_string`
+    pub fn from_json_str(content: &str) -> Result<Self> {
+        let tagger: AveragedPerceptronTagger = serde_json::from_str(content)?;
+        Ok(AveragedPerceptronTagger::new(
+            tagger.weights,
+            tagger.tagdict,
+            tagger.classes,
+        ))
+    }
+
+    /// Save tagger to json file
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        Ok(fs::write(path, self.to_json_string()?)?)
+    }
+
+    /// Load tagger from json file
+    pub fn load_json<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = fs::read_to_string(path)?;
+        AveragedPerceptronTagger::from_json_str(&content)
+    }
+
+    /// All known tags
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+}
+
+impl PosTagger for AveragedPerceptronTagger {
+    fn tag(&self, words: &[&str]) -> Vec<String> {
+        let context = Self::make_context(words);
+        let (mut prev, mut prev2) = (START[0].to_string(), START[1].to_string());
+        let mut tags = Vec::with_capacity(words.len());
+        for (i, word) in words.iter().enumerate() {
+            let tag = match self.tagdict.get(*word) {
+                Some(tag) => tag.clone(),
+                None => {
+                    let features = Self::get_features(i, word, &context, &prev, &prev2);
+                    self.predict(&features).to_string()
+                }
+            };
+            prev2 = std::mem::replace(&mut prev, tag.clone());
+            tags.push(tag);
+        }
+        tags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use rand::SeedableRng;
+    use test_case::test_case;
+
+    fn corpus() -> Vec<Vec<(&'static str, &'static str)>> {
+        vec![
+            vec![
+                ("The", "DT"),
+                ("quick", "JJ"),
+                ("fox", "NN"),
+                ("jumps", "VBZ"),
+                (".", "."),
+            ],
+            vec![
+                ("A", "DT"),
+                ("lazy", "JJ"),
+                ("dog", "NN"),
+                ("sleeps", "VBZ"),
+                (".", "."),
+            ],
+            vec![
+                ("The", "DT"),
+                ("dog", "NN"),
+                ("runs", "VBZ"),
+                ("quickly", "RB"),
+                (".", "."),
+            ],
+            vec![
+                ("A", "DT"),
+                ("cat", "NN"),
+                ("eats", "VBZ"),
+                ("slowly", "RB"),
+                (".", "."),
+            ],
+            vec![
+                ("The", "DT"),
+                ("big", "JJ"),
+                ("cat", "NN"),
+                ("sleeps", "VBZ"),
+                (".", "."),
+            ],
+            vec![
+                ("Cats", "NNS"),
+                ("sleep", "VBP"),
+                ("in", "IN"),
+                ("2024", "CD"),
+                (".", "."),
+            ],
+        ]
+    }
+
+    fn train() -> AveragedPerceptronTagger {
+        AveragedPerceptronTagger::train(&corpus(), 10, &mut rand::rngs::StdRng::seed_from_u64(42))
+    }
+
+    #[test]
+    fn test_train_tags_training_sentences() {
+        let tagger = train();
+
+        for sentence in corpus() {
+            let words = sentence.iter().map(|(word, _)| *word).collect::<Vec<&str>>();
+            let tags = sentence.iter().map(|(_, tag)| tag.to_string()).collect::<Vec<String>>();
+            assert_eq!(tagger.tag(&words), tags);
+        }
+        assert_eq!(
+            tagger.classes(),
+            [".", "CD", "DT", "IN", "JJ", "NN", "NNS", "RB", "VBP", "VBZ"]
+        );
+    }
+
+    #[test_case(vec!["A", "quick", "cat", "jumps", "."], vec!["DT", "JJ", "NN", "VBZ", "."] ; "new sentence")]
+    #[test_case(vec![], vec![] ; "empty sentence")]
+    fn test_tag(words: Vec<&str>, expected: Vec<&str>) {
+        let tagger = train();
+
+        assert_eq!(tagger.tag(&words), expected);
+    }
+
+    #[test]
+    fn test_tagdict() {
+        let sentences = vec![vec![("the", "DT"), ("end", "NN")]; 20];
+
+        let tagger = AveragedPerceptronTagger::train(&sentences, 1, &mut rand::thread_rng());
+
+        assert_eq!(tagger.tagdict.get("the"), Some(&"DT".to_string()));
+        assert_eq!(tagger.tag(&["the"]), vec!["DT"]);
+    }
+
+    #[test_case("Self-driving", "!HYPHEN" ; "hyphen")]
+    #[test_case("-", "-" ; "leading hyphen")]
+    #[test_case("1999", "!YEAR" ; "year")]
+    #[test_case("12.5", "!DIGITS" ; "digits")]
+    #[test_case("Cat", "cat" ; "lowercase")]
+    fn test_normalize(word: &str, expected: &str) {
+        assert_eq!(AveragedPerceptronTagger::normalize(word), expected);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let tagger = train();
+        let path = std::env::temp_dir().join("fast_aug_test_pos_tagger.json");
+
+        tagger.save_json(&path).unwrap();
+        let loaded_tagger = AveragedPerceptronTagger::load_json(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let words = ["The", "lazy", "fox", "sleeps", "."];
+        assert_eq!(loaded_tagger.tag(&words), tagger.tag(&words));
+    }
+
+    #[test]
+    fn test_load_json_invalid() {
+        assert!(matches!(
+            AveragedPerceptronTagger::from_json_str(r#"{"weights": []}"#),
+            Err(Error::Json(_))
+        ));
+    }
+}
@@ -0,0 +1,107 @@
+use crate::error::{Error, Result};
+use rand::RngCore;
+use std::sync::Arc;
+
+/// Sequence-to-sequence model generating text from text, e.g. paraphrasing or translation model
+/// Implement it to plug any local model into `ParaphraseAugmenter`
+/// (`CandleSeq2SeqModel` with `candle` feature runs MarianMT/T5 checkpoints on CPU)
+pub trait Seq2SeqModel: Send + Sync {
+    /// Generate one output text for each input text, in the same order
+    ///
+    /// # Arguments
+    /// * `inputs` - Batch of input texts
+    /// * `rng` - Random generator for sampling, models may ignore it for greedy decoding
+    fn generate(&self, inputs: &[String], rng: &mut dyn RngCore) -> Result<Vec<String>>;
+}
+
+/// Back-translation: translate texts to a pivot language and back, e.g. en -> de -> en
+///
+/// # Examples
+/// ```rust
+/// use std::sync::Arc;
+/// use fast_aug::models::text::{BackTranslationModel, Seq2SeqModel};
+///
+/// struct Upper;
+/// impl Seq2SeqModel for Upper {
+///     fn generate(&self, inputs: &[String], _rng: &mut dyn rand::RngCore) -> fast_aug::Result<Vec<String>> {
+///         Ok(inputs.iter().map(|text| text.to_uppercase()).collect())
+///     }
+/// }
+/// struct Reverse;
+/// impl Seq2SeqModel for Reverse {
+///     fn generate(&self, inputs: &[String], _rng: &mut dyn rand::RngCore) -> fast_aug::Result<Vec<String>> {
+///         Ok(inputs.iter().map(|text| text.chars().rev().collect()).collect())
+///     }
+/// }
+///
+/// let model = BackTranslationModel::new(Arc::new(Upper), Arc::new(Reverse));
+/// let outputs = model.generate(&["abc".to_string()], &mut rand::thread_rng()).unwrap();
+/// assert_eq!(outputs, vec!["CBA".to_string()]);
+/// ```
+pub struct BackTranslationModel {
+    /// Model translating from the source to the pivot language
+    forward: Arc<dyn Seq2SeqModel>,
+    /// Model translating from the pivot language back to the source
+    backward: Arc<dyn Seq2SeqModel>,
+}
+
+impl BackTranslationModel {
+    pub fn new(forward: Arc<dyn Seq2SeqModel>, backward: Arc<dyn Seq2SeqModel>) -> Self {
+        BackTranslationModel { forward, backward }
+    }
+}
+
+impl Seq2SeqModel for BackTranslationModel {
+    fn generate(&self, inputs: &[String], rng: &mut dyn RngCore) -> Result<Vec<String>> {
+        let translated = self.forward.generate(inputs, rng)?;
+        if translated.len() != inputs.len() {
+            return Err(Error::Model(format!(
+                "expected {} generated texts, got {}",
+                inputs.len(),
+                translated.len()
+            )));
+        }
+        self.backward.generate(&translated, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Suffix(&'static str);
+
+    impl Seq2SeqModel for Suffix {
+        fn generate(&self, inputs: &[String], _rng: &mut dyn RngCore) -> Result<Vec<String>> {
+            Ok(inputs.iter().map(|text| format!("{}{}", text, self.0)).collect())
+        }
+    }
+
+    struct Broken;
+
+    impl Seq2SeqModel for Broken {
+        fn generate(&self, _inputs: &[String], _rng: &mut dyn RngCore) -> Result<Vec<String>> {
+            Ok(vec![])
+        }
+    }
+
+    #[test]
+    fn test_back_translation() {
+        let model = BackTranslationModel::new(Arc::new(Suffix(" de")), Arc::new(Suffix(" en")));
+
+        let outputs = model
+            .generate(&["a".to_string(), "b".to_string()], &mut rand::thread_rng())
+            .unwrap();
+
+        assert_eq!(outputs, vec!["a de en".to_string(), "b de en".to_string()]);
+    }
+
+    #[test]
+    fn test_back_translation_mismatched_outputs() {
+        let model = BackTranslationModel::new(Arc::new(Broken), Arc::new(Suffix(" en")));
+
+        let result = model.generate(&["a".to_string()], &mut rand::thread_rng());
+
+        assert!(matches!(result, Err(Error::Model(_))));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::error::Result;
+use rand::seq::SliceRandom;
+use rand::RngCore;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Spelling model holds a dictionary of common misspellings
+/// One correct word is mapped to a list of its misspellings, all words are stored in lowercase
+pub struct SpellingModel {
+    pub misspellings: HashMap<String, Vec<String>>,
+}
+
+impl SpellingModel {
+    /// Create spelling model from the given dictionary
+    /// Words are converted to lowercase, words without misspellings are skipped
+    ///
+    /// # Arguments
+    /// * `misspellings` - Map of correct word to the list of its misspellings
+    pub fn new(misspellings: HashMap<String, Vec<String>>) -> Self {
+        let mut normalized: HashMap<String, Vec<String>> = HashMap::with_capacity(misspellings.len());
+        for (word, word_misspellings) in misspellings {
+            let word = word.to_lowercase();
+            let entry = normalized.entry(word.clone()).or_default();
+            for misspelling in word_misspellings {
+                let misspelling = misspelling.to_lowercase();
+                if misspelling != word && !entry.contains(&misspelling) {
+                    entry.push(misspelling);
+                }
+            }
+        }
+        normalized.retain(|_, word_misspellings| !word_misspellings.is_empty());
+
+        SpellingModel {
+            misspellings: normalized,
+        }
+    }
+
+    /// Load spelling model from plain text content
+    /// Each line contains correct word followed by its misspellings, separated by whitespace.
+    /// Empty lines and lines starting with `#` are skipped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::models::text::SpellingModel;
+    ///
+    /// let spelling_model = SpellingModel::from_txt_str("because becuase becasue\nwhich wich");
+    ///
+    /// assert_eq!(spelling_model.len(), 2);
+    /// assert_eq!(spelling_model.get_misspellings("because").unwrap().len(), 2);
+    /// ```
+    pub fn from_txt_str(content: &str) -> Self {
+        let mut misspellings: HashMap<String, Vec<String>> = HashMap::new();
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut words = line.split_whitespace();
+            if let Some(word) = words.next() {
+                misspellings
+                    .entry(word.to_string())
+                    .or_default()
+                    .extend(words.map(|w| w.to_string()));
+            }
+        }
+        SpellingModel::new(misspellings)
+    }
+
+    /// Load spelling model from json content
+    /// Json should be an object of correct word mapped to the list of its misspellings.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::models::text::SpellingModel;
+    ///
+    /// let spelling_model = SpellingModel::from_json_str(r#"{"because": ["becuase", "becasue"]}"#).unwrap();
+    ///
+    /// assert_eq!(spelling_model.get_misspellings("because").unwrap().len(), 2);
+    /// ```
+    pub fn from_json_str(content: &str) -> Result<Self> {
+        let misspellings: HashMap<String, Vec<String>> = serde_json::from_str(content)?;
+        Ok(SpellingModel::new(misspellings))
+    }
+
+    /// Load spelling model from file, format is selected by file extension
+    /// `.json` files are loaded as json, all others as plain text
+    ///
+    /// # Arguments
+    /// * `path` - Path to the misspellings dictionary file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => SpellingModel::from_json_str(&content),
+            _ => Ok(SpellingModel::from_txt_str(&content)),
+        }
+    }
+
+    /// Number of words with known misspellings
+    pub fn len(&self) -> usize {
+        self.misspellings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.misspellings.is_empty()
+    }
+
+    /// Get all misspellings of the word (case-insensitive)
+    pub fn get_misspellings(&self, word: &str) -> Option<&Vec<String>> {
+        self.misspellings.get(&word.to_lowercase())
+    }
+
+    /// Get random misspelling of the word (case-insensitive), returned in lowercase
+    ///
+    /// # Arguments
+    /// * `word` - Word to find misspelling for
+    /// * `rng` - Random number generator
+    pub fn get_random_misspelling(&self, word: &str, rng: &mut dyn RngCore) -> Option<&String> {
+        self.get_misspellings(word)
+            .and_then(|word_misspellings| word_misspellings.choose(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const SPELLING_TXT_PATH: &str = "../test_data/spelling_en.txt";
+    const SPELLING_JSON_PATH: &str = "../test_data/spelling_en.json";
+
+    #[test_case(SPELLING_TXT_PATH ; "plain text")]
+    #[test_case(SPELLING_JSON_PATH ; "json")]
+    fn test_from_file(path: &str) {
+        let spelling_model = SpellingModel::from_file(path).unwrap();
+
+        assert!(!spelling_model.is_empty());
+        assert!(spelling_model.get_misspellings("because").unwrap().len() > 1);
+        assert!(spelling_model.get_misspellings("not_existing_word").is_none());
+    }
+
+    #[test_case("../test_data/not_existing_file.txt")]
+    #[test_case("../test_data/not_existing_file.json")]
+    fn test_from_file_not_existing(path: &str) {
+        assert!(SpellingModel::from_file(path).is_err());
+    }
+
+    #[test_case(r#"["because"]"# ; "not an object")]
+    #[test_case(r#"{"because": "becuase"}"# ; "not a list")]
+    #[test_case("because becuase" ; "not a json")]
+    fn test_from_json_str_invalid(content: &str) {
+        assert!(SpellingModel::from_json_str(content).is_err());
+    }
+
+    #[test_case("# comment\n\nword wrod\n", 1 ; "skip comments and empty lines")]
+    #[test_case("word wrod\nword wodr", 1 ; "merge duplicated words")]
+    #[test_case("word\nother otehr", 1 ; "skip words without misspellings")]
+    #[test_case("Word WROD word", 1 ; "lowercase and skip same word")]
+    fn test_from_txt_str(content: &str, expected_len: usize) {
+        let spelling_model = SpellingModel::from_txt_str(content);
+        assert_eq!(spelling_model.len(), expected_len);
+    }
+
+    #[test_case("because" ; "lowercase")]
+    #[test_case("Because" ; "title case")]
+    #[test_case("BECAUSE" ; "uppercase")]
+    fn test_get_random_misspelling(word: &str) {
+        let spelling_model = SpellingModel::from_txt_str("because becuase becasue");
+
+        let misspelling = spelling_model.get_random_misspelling(word, &mut rand::thread_rng());
+
+        assert!(misspelling.is_some_and(|m| m == "becuase" || m == "becasue"));
+    }
+}
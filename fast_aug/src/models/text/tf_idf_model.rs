@@ -0,0 +1,305 @@
+use crate::error::{Error, Result};
+use crate::text::{Doc, TokenType};
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+/// Serializable part of the TfIdfModel
+#[derive(Serialize, Deserialize)]
+struct TfIdfModelData {
+    num_documents: usize,
+    idf: HashMap<String, f32>,
+}
+
+/// TF-IDF model holds inverse document frequencies of the words fitted on the corpus
+/// Words are stored in lowercase, only TokenType::Word tokens are considered
+///
+/// Sampling of the replacement words follows UDA paper (https://arxiv.org/abs/1904.12848):
+/// words are sampled from vocabulary with probability proportional to `max_idf - idf(word)`,
+/// so uninformative (frequent) words are more likely to be sampled.
+pub struct TfIdfModel {
+    /// Number of documents the model was fitted on
+    pub num_documents: usize,
+    /// Inverse document frequency of each word
+    pub idf: HashMap<String, f32>,
+    /// Vocabulary and distribution to sample replacement words from
+    vocabulary: Vec<String>,
+    vocabulary_distribution: Option<WeightedIndex<f32>>,
+    max_idf: f32,
+}
+
+impl TfIdfModel {
+    /// Create TF-IDF model from precomputed idf values, values are not validated, see `try_new`
+    /// If any idf is not finite, words can not be sampled from the vocabulary
+    ///
+    /// # Arguments
+    /// * `idf` - Map of word to its inverse document frequency
+    /// * `num_documents` - Number of documents idf was computed on
+    pub fn new(idf: HashMap<String, f32>, num_documents: usize) -> Self {
+        let idf: HashMap<String, f32> = idf
+            .into_iter()
+            .map(|(word, value)| (word.to_lowercase(), value))
+            .collect();
+
+        let max_idf = idf.values().copied().fold(0.0, f32::max);
+        let mut vocabulary: Vec<String> = idf.keys().cloned().collect();
+        vocabulary.sort();
+        // Small epsilon so the words with max idf still can be sampled (and all-equal weights are valid)
+        let vocabulary_distribution = WeightedIndex::new(vocabulary.iter().map(|word| max_idf - idf[word] + 1e-6)).ok();
+
+        TfIdfModel {
+            num_documents,
+            idf,
+            vocabulary,
+            vocabulary_distribution,
+            max_idf,
+        }
+    }
+
+    /// Create TF-IDF model from precomputed idf values
+    /// Returns `Error::InvalidWeights` if any idf is negative or not finite
+    pub fn try_new(idf: HashMap<String, f32>, num_documents: usize) -> Result<Self> {
+        if let Some((word, value)) = idf.iter().find(|(_, value)| !value.is_finite() || **value < 0.0) {
+            return Err(Error::InvalidWeights(format!(
+                "idf of \"{}\" should be finite and non-negative, got {}",
+                word, value
+            )));
+        }
+        Ok(TfIdfModel::new(idf, num_documents))
+    }
+
+    /// Fit TF-IDF model on the corpus of documents, using Doc tokenizer
+    /// Smoothed idf is used: `idf(word) = ln((1 + N) / (1 + df(word))) + 1`
+    ///
+    /// # Arguments
+    /// * `documents` - Iterator of documents (texts)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::models::text::TfIdfModel;
+    ///
+    /// let tf_idf_model = TfIdfModel::fit(vec!["The cat sat", "The dog ran"]);
+    ///
+    /// assert_eq!(tf_idf_model.num_documents, 2);
+    /// assert!(tf_idf_model.get_idf("the") < tf_idf_model.get_idf("cat"));
+    /// ```
+    pub fn fit<I, S>(documents: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut document_frequency: HashMap<String, usize> = HashMap::new();
+        let mut num_documents = 0;
+
+        for document in documents {
+            let doc = Doc::new(document.as_ref());
+            let unique_words = doc
+                .tokens
+                .iter()
+                .filter(|token| token.kind() == &TokenType::Word)
+                .map(|token| token.token().to_lowercase())
+                .collect::<HashSet<String>>();
+            for word in unique_words {
+                *document_frequency.entry(word).or_insert(0) += 1;
+            }
+            num_documents += 1;
+        }
+
+        let idf = document_frequency
+            .into_iter()
+            .map(|(word, df)| {
+                let idf = ((1 + num_documents) as f32 / (1 + df) as f32).ln() + 1.0;
+                (word, idf)
+            })
+            .collect();
+
+        TfIdfModel::new(idf, num_documents)
+    }
+
+    /// Serialize model to json string
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string(&TfIdfModelData {
+            num_documents: self.num_documents,
+            idf: self.idf.clone(),
+        })?)
+    }
+
+    /// Load model from json string, created with `to_json_string`
+    /// Returns `Error::InvalidWeights` if any idf is negative or not finite
+    pub fn from_json_str(content: &str) -> Result<Self> {
+        let data: TfIdfModelData = serde_json::from_str(content)?;
+        TfIdfModel::try_new(data.idf, data.num_documents)
+    }
+
+    /// Save model to json file
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        Ok(fs::write(path, self.to_json_string()?)?)
+    }
+
+    /// Load model from json file
+    pub fn load_json<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = fs::read_to_string(path)?;
+        TfIdfModel::from_json_str(&content)
+    }
+
+    /// Number of words in vocabulary
+    pub fn len(&self) -> usize {
+        self.vocabulary.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vocabulary.is_empty()
+    }
+
+    /// Get idf of the word (case-insensitive)
+    /// Unknown words are considered to be the most informative, so max idf is returned
+    pub fn get_idf(&self, word: &str) -> f32 {
+        match self.idf.get(&word.to_lowercase()) {
+            Some(idf) => *idf,
+            None => self.max_idf,
+        }
+    }
+
+    /// Calculate TF-IDF scores of the selected tokens of the document
+    /// Term frequency is calculated over all word tokens of the document
+    ///
+    /// # Arguments
+    /// * `doc` - Document
+    /// * `token_indexes` - Indexes of the tokens to calculate scores for
+    pub fn get_tf_idf_scores(&self, doc: &Doc, token_indexes: &[usize]) -> Vec<f32> {
+        let mut term_frequency: HashMap<String, usize> = HashMap::new();
+        let mut num_words = 0;
+        for token in doc.tokens.iter().filter(|token| token.kind() == &TokenType::Word) {
+            *term_frequency.entry(token.token().to_lowercase()).or_insert(0) += 1;
+            num_words += 1;
+        }
+
+        token_indexes
+            .iter()
+            .map(|&idx| {
+                let word = doc.tokens[idx].token().to_lowercase();
+                let tf = *term_frequency.get(&word).unwrap_or(&0) as f32 / num_words.max(1) as f32;
+                tf * self.get_idf(&word)
+            })
+            .collect()
+    }
+
+    /// Sample random word from vocabulary, uninformative (low idf) words are more likely
+    /// Returns None if vocabulary is empty
+    pub fn get_random_word(&self, rng: &mut dyn RngCore) -> Option<&String> {
+        self.vocabulary_distribution
+            .as_ref()
+            .map(|distribution| &self.vocabulary[distribution.sample(rng)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const CORPUS: [&str; 4] = [
+        "The cat sat on the mat.",
+        "The dog ate my homework.",
+        "A cat and the dog are friends!",
+        "The weather is nice today.",
+    ];
+
+    #[test]
+    fn test_fit() {
+        let tf_idf_model = TfIdfModel::fit(CORPUS);
+
+        assert_eq!(tf_idf_model.num_documents, 4);
+        assert!(!tf_idf_model.is_empty());
+        assert!(tf_idf_model.get_idf("the") < tf_idf_model.get_idf("cat"));
+        assert!(tf_idf_model.get_idf("cat") < tf_idf_model.get_idf("weather"));
+        assert_eq!(tf_idf_model.get_idf("The"), tf_idf_model.get_idf("the"));
+        assert_eq!(
+            tf_idf_model.get_idf("not_existing_word"),
+            tf_idf_model.get_idf("weather")
+        );
+        assert!(!tf_idf_model.idf.contains_key("."));
+    }
+
+    #[test]
+    fn test_fit_empty() {
+        let tf_idf_model = TfIdfModel::fit(Vec::<String>::new());
+
+        assert!(tf_idf_model.is_empty());
+        assert!(tf_idf_model.get_random_word(&mut rand::thread_rng()).is_none());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let tf_idf_model = TfIdfModel::fit(CORPUS);
+
+        let json = tf_idf_model.to_json_string().unwrap();
+        let loaded_model = TfIdfModel::from_json_str(&json).unwrap();
+
+        assert_eq!(loaded_model.num_documents, tf_idf_model.num_documents);
+        assert_eq!(loaded_model.idf, tf_idf_model.idf);
+        assert_eq!(loaded_model.len(), tf_idf_model.len());
+    }
+
+    #[test]
+    fn test_save_load_json() {
+        let tf_idf_model = TfIdfModel::fit(CORPUS);
+        let path = std::env::temp_dir().join("fast_aug_test_tf_idf_model.json");
+
+        tf_idf_model.save_json(&path).unwrap();
+        let loaded_model = TfIdfModel::load_json(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded_model.idf, tf_idf_model.idf);
+    }
+
+    #[test_case(f32::NAN ; "nan")]
+    #[test_case(f32::INFINITY ; "infinity")]
+    #[test_case(-1.0 ; "negative")]
+    fn test_try_new_invalid_idf(value: f32) {
+        let idf = HashMap::from([("cat".to_string(), 1.0), ("dog".to_string(), value)]);
+
+        assert!(matches!(TfIdfModel::try_new(idf, 2), Err(Error::InvalidWeights(_))));
+    }
+
+    #[test]
+    fn test_from_json_str_invalid_idf() {
+        let json = r#"{"num_documents": 2, "idf": {"cat": 1.0, "dog": -2.0}}"#;
+
+        assert!(matches!(TfIdfModel::from_json_str(json), Err(Error::InvalidWeights(_))));
+    }
+
+    #[test_case("../test_data/not_existing_file.json")]
+    fn test_load_json_not_existing(path: &str) {
+        assert!(TfIdfModel::load_json(path).is_err());
+    }
+
+    #[test]
+    fn test_tf_idf_scores() {
+        let tf_idf_model = TfIdfModel::fit(CORPUS);
+        let doc = Doc::new("the cat the weather");
+
+        let scores = tf_idf_model.get_tf_idf_scores(&doc, &[0, 4, 2, 6]);
+
+        assert_eq!(scores[0], scores[1]);
+        assert!(scores[2] < scores[3]);
+    }
+
+    #[test]
+    fn test_random_word_prefers_uninformative_words() {
+        let tf_idf_model = TfIdfModel::fit(CORPUS);
+        let rng = &mut rand::thread_rng();
+
+        let num_the = (0..1000)
+            .filter(|_| tf_idf_model.get_random_word(rng).unwrap() == "the")
+            .count();
+        let num_weather = (0..1000)
+            .filter(|_| tf_idf_model.get_random_word(rng).unwrap() == "weather")
+            .count();
+
+        assert!(num_the > num_weather);
+    }
+}
@@ -0,0 +1,470 @@
+use crate::error::{Error, Result};
+use flate2::read::GzDecoder;
+use icu_locid::Locale;
+use rand::{Rng, RngCore};
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+/// Word frequency lists embedded at build time, gzipped plain text (see build.rs)
+const EMBEDDED_VOCABULARIES: [(&str, &[u8]); 1] =
+    [("en", include_bytes!(concat!(env!("OUT_DIR"), "/vocab/vocab_en.txt.gz")))];
+
+/// Alias table to sample indexes proportionally to their weights in O(1) per draw, see Vose's alias method
+struct AliasTable {
+    /// Probability to keep the drawn index instead of its alias
+    probabilities: Vec<f64>,
+    aliases: Vec<usize>,
+}
+
+impl AliasTable {
+    /// Create alias table, returns None if there are no weights or all weights are zero
+    /// Weights should be finite and non-negative
+    fn new(weights: &[f64]) -> Option<Self> {
+        let total: f64 = weights.iter().sum();
+        if weights.is_empty() || total <= 0.0 || !total.is_finite() {
+            return None;
+        }
+
+        // Scale weights to average 1, then pair each "small" index with some "large" index to fill it up to 1
+        let num_weights = weights.len();
+        let mut scaled: Vec<f64> = weights
+            .iter()
+            .map(|weight| weight * num_weights as f64 / total)
+            .collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..num_weights).partition(|&idx| scaled[idx] < 1.0);
+        let mut probabilities = vec![1.0; num_weights];
+        let mut aliases: Vec<usize> = (0..num_weights).collect();
+        while let (Some(small_idx), Some(&large_idx)) = (small.pop(), large.last()) {
+            probabilities[small_idx] = scaled[small_idx];
+            aliases[small_idx] = large_idx;
+            scaled[large_idx] += scaled[small_idx] - 1.0;
+            if scaled[large_idx] < 1.0 {
+                large.pop();
+                small.push(large_idx);
+            }
+        }
+        // Indexes left in any list are 1 up to the floating point errors, so they are always kept
+
+        Some(AliasTable { probabilities, aliases })
+    }
+
+    fn sample(&self, rng: &mut dyn RngCore) -> usize {
+        let idx = rng.gen_range(0..self.probabilities.len());
+        if rng.gen::<f64>() < self.probabilities[idx] {
+            idx
+        } else {
+            self.aliases[idx]
+        }
+    }
+}
+
+/// Vocabulary model holds words of the language with their weights (e.g. frequencies in the corpus)
+/// Random words are sampled proportionally to their weights, so common words are selected more often.
+/// Sampling uses alias table, so each draw is O(1) regardless of the vocabulary size.
+/// Temperature reshapes the distribution: weights are raised to the power 1/temperature,
+/// so temperature > 1 flattens the distribution (to uniform in the limit) and temperature < 1 sharpens it.
+/// Plain list of words (e.g. `Vec<String>`) is converted to the vocabulary with equal weights, i.e. uniform sampling.
+/// Words, weights and temperature are read-only, as the alias table is built from them on creation.
+pub struct VocabModel {
+    words: Vec<String>,
+    weights: Vec<f64>,
+    temperature: f64,
+    pub locale_str: String,
+    /// None for empty vocabulary or vocabulary with all zero weights
+    alias_table: Option<AliasTable>,
+}
+
+impl VocabModel {
+    /// Create vocabulary from words with their weights
+    /// Panics if any weight is negative or not finite, see `try_new`
+    ///
+    /// # Arguments
+    /// * `words_weights` - Words with their weights (e.g. counts in the corpus)
+    /// * `locale_string` - Language tag the vocabulary is created for
+    pub fn new(words_weights: Vec<(String, f64)>, locale_string: String) -> Self {
+        Self::try_new(words_weights, locale_string).expect("weights should be finite and non-negative")
+    }
+
+    /// Create vocabulary from words with their weights
+    /// Weights of duplicated words are summed up, order of the first occurrence is kept
+    /// Returns `Error::InvalidWeights` if any weight is negative or not finite
+    pub fn try_new(words_weights: Vec<(String, f64)>, locale_string: String) -> Result<Self> {
+        let mut words: Vec<String> = Vec::with_capacity(words_weights.len());
+        let mut weights: Vec<f64> = Vec::with_capacity(words_weights.len());
+        let mut word_indexes: HashMap<String, usize> = HashMap::with_capacity(words_weights.len());
+        for (word, weight) in words_weights {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(Error::InvalidWeights(format!(
+                    "weight of \"{}\" should be finite and non-negative, got {}",
+                    word, weight
+                )));
+            }
+            match word_indexes.get(&word) {
+                Some(&idx) => weights[idx] += weight,
+                None => {
+                    word_indexes.insert(word.clone(), words.len());
+                    words.push(word);
+                    weights.push(weight);
+                }
+            }
+        }
+        let alias_table = AliasTable::new(&weights);
+
+        Ok(VocabModel {
+            words,
+            weights,
+            temperature: 1.0,
+            locale_str: locale_string,
+            alias_table,
+        })
+    }
+
+    /// Create vocabulary from plain list of words, all words have equal weight
+    pub fn from_words(words: Vec<String>, locale_string: String) -> Self {
+        VocabModel::new(words.into_iter().map(|word| (word, 1.0)).collect(), locale_string)
+    }
+
+    /// Set sampling temperature, see `try_with_temperature`
+    /// Panics if temperature is not positive and finite
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::models::text::VocabModel;
+    ///
+    /// // Rare words are sampled more often than by their frequencies
+    /// let vocab_model = VocabModel::from_locale_str("en").with_temperature(2.0);
+    ///
+    /// assert_eq!(vocab_model.temperature(), 2.0);
+    /// ```
+    pub fn with_temperature(self, temperature: f64) -> Self {
+        self.try_with_temperature(temperature)
+            .expect("temperature should be positive and finite")
+    }
+
+    /// Set sampling temperature, words are sampled proportionally to weight^(1/temperature)
+    /// Returns `Error::InvalidWeights` if temperature is not positive and finite
+    pub fn try_with_temperature(mut self, temperature: f64) -> Result<Self> {
+        if !temperature.is_finite() || temperature <= 0.0 {
+            return Err(Error::InvalidWeights(format!(
+                "temperature should be positive and finite, got {}",
+                temperature
+            )));
+        }
+        // Weights are normalized by the max weight before the power, so sharp temperatures do not overflow
+        let max_weight = self.weights.iter().cloned().fold(0.0, f64::max);
+        let tempered_weights: Vec<f64> = self
+            .weights
+            .iter()
+            .map(|weight| {
+                if max_weight > 0.0 {
+                    (weight / max_weight).powf(1.0 / temperature)
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        self.alias_table = AliasTable::new(&tempered_weights);
+        self.temperature = temperature;
+        Ok(self)
+    }
+
+    /// Load vocabulary from frequency list content
+    /// Each line contains word optionally followed by its weight, separated by whitespace (e.g. "the 23135851162").
+    /// Words without weight get weight 1. Empty lines, lines starting with `#` and invalid weights are skipped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::models::text::VocabModel;
+    ///
+    /// let vocab_model = VocabModel::from_txt_str("the 100\nof 60.5\nrare", "en".to_string());
+    ///
+    /// assert_eq!(vocab_model.len(), 3);
+    /// assert_eq!(vocab_model.get_weight("rare"), Some(1.0));
+    /// ```
+    pub fn from_txt_str(content: &str, locale_string: String) -> Self {
+        let words_weights = content
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let word = fields.next()?.to_string();
+                match fields.next() {
+                    Some(weight) => weight
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|weight| weight.is_finite() && *weight >= 0.0)
+                        .map(|weight| (word, weight)),
+                    None => Some((word, 1.0)),
+                }
+            })
+            .collect();
+        VocabModel::new(words_weights, locale_string)
+    }
+
+    /// Load vocabulary from frequency list file, see `from_txt_str`
+    /// Lists of [FrequencyWords](https://github.com/hermitdave/FrequencyWords) can be used as is
+    ///
+    /// # Arguments
+    /// * `path` - Path to the frequency list file
+    /// * `locale_string` - Language tag of the vocabulary
+    pub fn from_file<P: AsRef<Path>>(path: P, locale_string: String) -> Result<Self> {
+        let content = fs::read_to_string(path)?;
+        Ok(VocabModel::from_txt_str(&content, locale_string))
+    }
+
+    /// Load vocabulary embedded into the library for icu_locid::Locale
+    /// Panics if there is no embedded vocabulary for the language, see `try_from_locale`
+    pub fn from_locale(locale: &Locale) -> Self {
+        Self::try_from_locale(locale).expect("locale should have embedded vocabulary")
+    }
+
+    /// Load vocabulary embedded into the library for icu_locid::Locale (en)
+    /// Returns `Error::InvalidLocale` if there is no embedded vocabulary for the language
+    pub fn try_from_locale(locale: &Locale) -> Result<Self> {
+        let (_, compressed) = EMBEDDED_VOCABULARIES
+            .iter()
+            .find(|(language, _)| *language == locale.id.language.as_str())
+            .ok_or_else(|| Error::InvalidLocale(locale.to_string()))?;
+
+        let mut content = String::new();
+        GzDecoder::new(*compressed).read_to_string(&mut content)?;
+        Ok(VocabModel::from_txt_str(&content, locale.to_string()))
+    }
+
+    /// Load vocabulary embedded into the library using language tag
+    /// Panics if language tag is invalid or there is no embedded vocabulary for it, see `try_from_locale_str`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::models::text::VocabModel;
+    ///
+    /// let vocab_model = VocabModel::from_locale_str("en-US");
+    ///
+    /// assert!(vocab_model.get_weight("the") > vocab_model.get_weight("coffee"));
+    /// ```
+    pub fn from_locale_str(locale_str: &str) -> Self {
+        Self::try_from_locale_str(locale_str).expect("language tag should be valid")
+    }
+
+    /// Load vocabulary embedded into the library using language tag
+    /// Returns `Error::InvalidLocale` if language tag is invalid or there is no embedded vocabulary for it
+    pub fn try_from_locale_str(locale_str: &str) -> Result<Self> {
+        let locale =
+            Locale::try_from_bytes(locale_str.as_bytes()).map_err(|_| Error::InvalidLocale(locale_str.to_string()))?;
+        Self::try_from_locale(&locale)
+    }
+
+    /// Get words of the vocabulary, in order of the first occurrence
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Get weights of the words, in order of the words
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Get sampling temperature, 1.0 by default
+    pub fn temperature(&self) -> f64 {
+        self.temperature
+    }
+
+    /// Number of words in the vocabulary
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Vocabulary is empty if there are no words or all weights are zero, so no word can be sampled
+    pub fn is_empty(&self) -> bool {
+        self.alias_table.is_none()
+    }
+
+    /// Get weight of the word, None if the word is not in the vocabulary
+    pub fn get_weight(&self, word: &str) -> Option<f64> {
+        self.words
+            .iter()
+            .position(|vocab_word| vocab_word == word)
+            .map(|idx| self.weights[idx])
+    }
+
+    /// Get random word, sampled proportionally to the word weights (with temperature applied)
+    /// Returns None if the vocabulary is empty
+    pub fn get_random_word(&self, rng: &mut dyn RngCore) -> Option<&String> {
+        self.alias_table
+            .as_ref()
+            .map(|alias_table| &self.words[alias_table.sample(rng)])
+    }
+}
+
+impl From<Vec<String>> for VocabModel {
+    /// Vocabulary of the plain list of words with equal weights, language is not specified
+    fn from(words: Vec<String>) -> Self {
+        VocabModel::from_words(words, String::new())
+    }
+}
+
+impl From<Vec<(String, f64)>> for VocabModel {
+    /// Vocabulary of the words with their weights, language is not specified
+    /// Panics if any weight is negative or not finite, see `VocabModel::try_new`
+    fn from(words_weights: Vec<(String, f64)>) -> Self {
+        VocabModel::new(words_weights, String::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use test_case::test_case;
+
+    fn sample_counts(vocab_model: &VocabModel, num_samples: usize) -> HashMap<String, usize> {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let mut counts = HashMap::new();
+        for _ in 0..num_samples {
+            *counts
+                .entry(vocab_model.get_random_word(&mut rng).unwrap().clone())
+                .or_default() += 1;
+        }
+        counts
+    }
+
+    fn words_weights(words_weights: &[(&str, f64)]) -> Vec<(String, f64)> {
+        words_weights
+            .iter()
+            .map(|(word, weight)| (word.to_string(), *weight))
+            .collect()
+    }
+
+    #[test_case("# comment\n\nthe 10\nof 5\n", 2 ; "skip comments and empty lines")]
+    #[test_case("the 10\nthe 5", 1 ; "merge duplicated words")]
+    #[test_case("the 10\nof many\nrare", 2 ; "skip invalid weights")]
+    #[test_case("the 10\nof -5\na NaN", 1 ; "skip negative and not finite weights")]
+    #[test_case("the\tof", 0 ; "tab separated invalid weight")]
+    fn test_from_txt_str(content: &str, expected_len: usize) {
+        let vocab_model = VocabModel::from_txt_str(content, "en".to_string());
+        assert_eq!(vocab_model.len(), expected_len);
+    }
+
+    #[test]
+    fn test_merge_duplicated_weights() {
+        let vocab_model = VocabModel::from_txt_str("the 10\nof 3\nthe 5", "en".to_string());
+
+        assert_eq!(vocab_model.words(), ["the", "of"]);
+        assert_eq!(vocab_model.get_weight("the"), Some(15.0));
+        assert_eq!(vocab_model.get_weight("a"), None);
+    }
+
+    #[test_case(-1.0 ; "negative")]
+    #[test_case(f64::NAN ; "nan")]
+    #[test_case(f64::INFINITY ; "infinity")]
+    fn test_try_new_invalid_weights(weight: f64) {
+        assert!(matches!(
+            VocabModel::try_new(words_weights(&[("a", 1.0), ("b", weight)]), "en".to_string()),
+            Err(Error::InvalidWeights(_))
+        ));
+    }
+
+    #[test]
+    fn test_get_random_word_weighted() {
+        let vocab_model = VocabModel::new(
+            words_weights(&[("common", 70.0), ("medium", 20.0), ("rare", 10.0), ("never", 0.0)]),
+            "en".to_string(),
+        );
+
+        let counts = sample_counts(&vocab_model, 10000);
+
+        assert!((6700..7300).contains(&counts["common"]));
+        assert!((1700..2300).contains(&counts["medium"]));
+        assert!((800..1200).contains(&counts["rare"]));
+        assert!(!counts.contains_key("never"));
+    }
+
+    #[test_case(1.0, 0.9 ; "temperature 1 keeps weights")]
+    #[test_case(100.0, 0.505 ; "high temperature flattens")]
+    #[test_case(0.5, 0.988 ; "low temperature sharpens")]
+    fn test_with_temperature(temperature: f64, expected_common_share: f64) {
+        let vocab_model = VocabModel::new(words_weights(&[("common", 9.0), ("rare", 1.0)]), "en".to_string())
+            .with_temperature(temperature);
+
+        let counts = sample_counts(&vocab_model, 10000);
+
+        let common_share = counts["common"] as f64 / 10000.0;
+        assert!((common_share - expected_common_share).abs() < 0.02);
+        assert_eq!(vocab_model.weights(), [9.0, 1.0]);
+    }
+
+    #[test]
+    fn test_with_temperature_large_weights() {
+        let vocab_model =
+            VocabModel::new(words_weights(&[("a", 1e300), ("b", 1e299)]), "en".to_string()).with_temperature(0.01);
+
+        assert_eq!(sample_counts(&vocab_model, 100)["a"], 100);
+    }
+
+    #[test_case(0.0 ; "zero")]
+    #[test_case(-1.0 ; "negative")]
+    #[test_case(f64::INFINITY ; "infinity")]
+    fn test_try_with_temperature_invalid(temperature: f64) {
+        let vocab_model = VocabModel::from_words(vec!["a".to_string()], "en".to_string());
+        assert!(matches!(
+            vocab_model.try_with_temperature(temperature),
+            Err(Error::InvalidWeights(_))
+        ));
+    }
+
+    #[test_case(vec![] ; "no words")]
+    #[test_case(vec![("zero".to_string(), 0.0)] ; "zero weights")]
+    fn test_empty(words_weights: Vec<(String, f64)>) {
+        let vocab_model = VocabModel::new(words_weights, "en".to_string()).with_temperature(2.0);
+
+        assert!(vocab_model.is_empty());
+        assert!(vocab_model.get_random_word(&mut rand::thread_rng()).is_none());
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let vocab_model: VocabModel = vec!["a".to_string(), "b".to_string()].into();
+
+        assert_eq!(vocab_model.weights(), [1.0, 1.0]);
+        assert_eq!(vocab_model.locale_str, "");
+    }
+
+    #[test]
+    fn test_alias_table_uniform() {
+        let vocab_model = VocabModel::from_words((0..100).map(|idx| idx.to_string()).collect(), "en".to_string());
+
+        let counts = sample_counts(&vocab_model, 100000);
+
+        assert_eq!(counts.len(), 100);
+        assert!(counts.values().all(|count| (800..1200).contains(count)));
+    }
+
+    #[test_case("en" ; "language")]
+    #[test_case("en-GB" ; "language with region")]
+    fn test_from_locale_str(locale_str: &str) {
+        let vocab_model = VocabModel::from_locale_str(locale_str);
+
+        assert!(vocab_model.len() > 500);
+        assert_eq!(vocab_model.locale_str, locale_str);
+    }
+
+    #[test_case("fr" ; "no embedded vocabulary")]
+    #[test_case("sr-La!!!tn-RS" ; "invalid language tag")]
+    fn test_try_from_locale_str_invalid(locale_str: &str) {
+        assert!(matches!(
+            VocabModel::try_from_locale_str(locale_str),
+            Err(Error::InvalidLocale(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_file() {
+        let vocab_model = VocabModel::from_file("data/vocab/vocab_en.txt", "en".to_string()).unwrap();
+
+        assert_eq!(vocab_model.words()[0], "the");
+        assert!(VocabModel::from_file("data/vocab/missing.txt", "en".to_string()).is_err());
+    }
+}
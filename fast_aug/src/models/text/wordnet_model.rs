@@ -0,0 +1,407 @@
+use crate::error::{Error, Result};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, XmlVersion};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Data files of WordNet database (Princeton WNDB format), one for each part of speech
+const WNDB_DATA_FILES: [&str; 4] = ["data.noun", "data.verb", "data.adj", "data.adv"];
+
+/// WordNet model holds synsets (sets of synonyms) and antonym relations between lemmas
+/// All lemmas are stored in lowercase, words of multi-word lemmas are separated with spaces (e.g. "hot dog")
+///
+/// Can be loaded from WordNet database files (`data.noun`, `data.adj`, etc., e.g. Princeton WordNet 3.0)
+/// or from WN-LMF XML file (e.g. Open Multilingual WordNet, OdeNet for German).
+pub struct WordNetModel {
+    /// Synsets as lists of lemmas, synsets with single lemma are skipped
+    synsets: Vec<Vec<String>>,
+    /// Indexes of synsets of each lemma
+    lemma_synsets: HashMap<String, Vec<usize>>,
+    /// Antonyms of each lemma
+    antonyms: HashMap<String, Vec<String>>,
+}
+
+impl WordNetModel {
+    /// Create WordNet model from the given synsets and antonym pairs
+    /// Lemmas are converted to lowercase and underscores are replaced with spaces, antonyms are symmetric
+    ///
+    /// # Arguments
+    /// * `synsets` - Sets of lemmas with the same meaning
+    /// * `antonyms` - Pairs of lemmas with the opposite meaning
+    pub fn new(synsets: Vec<Vec<String>>, antonyms: Vec<(String, String)>) -> Self {
+        let mut normalized_synsets: Vec<Vec<String>> = Vec::with_capacity(synsets.len());
+        let mut lemma_synsets: HashMap<String, Vec<usize>> = HashMap::new();
+        for synset in synsets {
+            let mut lemmas: Vec<String> = Vec::with_capacity(synset.len());
+            for lemma in synset {
+                let lemma = Self::normalize_lemma(&lemma);
+                if !lemma.is_empty() && !lemmas.contains(&lemma) {
+                    lemmas.push(lemma);
+                }
+            }
+            if lemmas.len() < 2 {
+                continue;
+            }
+            for lemma in &lemmas {
+                lemma_synsets
+                    .entry(lemma.clone())
+                    .or_default()
+                    .push(normalized_synsets.len());
+            }
+            normalized_synsets.push(lemmas);
+        }
+
+        let mut normalized_antonyms: HashMap<String, Vec<String>> = HashMap::new();
+        for (lemma, antonym) in antonyms {
+            let (lemma, antonym) = (Self::normalize_lemma(&lemma), Self::normalize_lemma(&antonym));
+            if lemma.is_empty() || antonym.is_empty() || lemma == antonym {
+                continue;
+            }
+            for (word, opposite) in [(&lemma, &antonym), (&antonym, &lemma)] {
+                let entry = normalized_antonyms.entry(word.clone()).or_default();
+                if !entry.contains(opposite) {
+                    entry.push(opposite.clone());
+                }
+            }
+        }
+
+        WordNetModel {
+            synsets: normalized_synsets,
+            lemma_synsets,
+            antonyms: normalized_antonyms,
+        }
+    }
+
+    /// Lowercase lemma, replace underscores with spaces and drop adjective markers, e.g. "Good(p)" -> "good"
+    fn normalize_lemma(lemma: &str) -> String {
+        let lemma = match lemma.find('(') {
+            Some(marker_start) if lemma.ends_with(')') => &lemma[..marker_start],
+            _ => lemma,
+        };
+        lemma
+            .split(['_', ' '])
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<&str>>()
+            .join(" ")
+            .to_lowercase()
+    }
+
+    /// Load WordNet model from the content of WordNet database data file (e.g. `data.adj`)
+    /// Lines of the license header (starting with spaces) and malformed lines are skipped.
+    /// Antonyms are resolved within the file, as they always have the same part of speech.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::models::text::WordNetModel;
+    ///
+    /// let wordnet_model = WordNetModel::from_wndb_str(
+    ///     "00978754 00 a 02 fast 0 quick 0 001 ! 00980527 a 0101 | moving quickly\n\
+    ///      00980527 00 a 02 slow 0 dull 0 001 ! 00978754 a 0101 | not moving quickly",
+    /// );
+    ///
+    /// assert_eq!(wordnet_model.get_synonyms("Fast"), vec!["quick"]);
+    /// assert_eq!(wordnet_model.get_antonyms("fast"), vec!["slow"]);
+    /// ```
+    pub fn from_wndb_str(content: &str) -> Self {
+        let (synsets, antonyms) = Self::parse_wndb(content);
+        WordNetModel::new(synsets, antonyms)
+    }
+
+    /// Parse synsets and antonym pairs from WordNet database data file
+    /// Line format: `offset lex_filenum ss_type w_cnt [word lex_id]... p_cnt [ptr offset pos source/target]... | gloss`
+    fn parse_wndb(content: &str) -> (Vec<Vec<String>>, Vec<(String, String)>) {
+        let mut synsets: Vec<Vec<String>> = Vec::new();
+        let mut offsets: HashMap<&str, usize> = HashMap::new();
+        // Antonym pointers as (synset index, source word number, target offset, target word number)
+        let mut antonym_pointers: Vec<(usize, usize, &str, usize)> = Vec::new();
+
+        for line in content.lines() {
+            if line.starts_with(' ') {
+                continue;
+            }
+            let fields = line.split(" | ").next().unwrap_or("").split(' ').collect::<Vec<&str>>();
+            let Some(num_words) = fields.get(3).and_then(|field| usize::from_str_radix(field, 16).ok()) else {
+                continue;
+            };
+            let pointers_start = 4 + 2 * num_words;
+            let Some(num_pointers) = fields.get(pointers_start).and_then(|field| field.parse::<usize>().ok()) else {
+                continue;
+            };
+            if fields.len() < pointers_start + 1 + 4 * num_pointers {
+                continue;
+            }
+
+            let synset_index = synsets.len();
+            synsets.push((0..num_words).map(|i| fields[4 + 2 * i].to_string()).collect());
+            offsets.insert(fields[0], synset_index);
+            for pointer in fields[pointers_start + 1..pointers_start + 1 + 4 * num_pointers].chunks(4) {
+                // source/target is 4 hex digits, malformed (e.g. non-ASCII) fields are skipped
+                if pointer[0] != "!" || pointer[3].len() != 4 || !pointer[3].is_ascii() {
+                    continue;
+                }
+                if let (Ok(source), Ok(target)) = (
+                    usize::from_str_radix(&pointer[3][..2], 16),
+                    usize::from_str_radix(&pointer[3][2..], 16),
+                ) {
+                    antonym_pointers.push((synset_index, source, pointer[1], target));
+                }
+            }
+        }
+
+        // Word numbers are 1-based, 0 means the whole synset (not used by antonyms)
+        let antonyms = antonym_pointers
+            .into_iter()
+            .filter_map(|(synset_index, source, target_offset, target)| {
+                let target_synset = &synsets[*offsets.get(target_offset)?];
+                let lemma = synsets[synset_index].get(source.checked_sub(1)?)?;
+                let antonym = target_synset.get(target.checked_sub(1)?)?;
+                Some((lemma.clone(), antonym.clone()))
+            })
+            .collect();
+
+        (synsets, antonyms)
+    }
+
+    /// Load WordNet model from WN-LMF XML content (Global WordNet Association format)
+    /// Lemmas are grouped by synsets of their senses, antonyms are taken from `antonym` sense relations.
+    /// All lexicons of the file are loaded, so use a file with lexicon of a single language.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::models::text::WordNetModel;
+    ///
+    /// let wordnet_model = WordNetModel::from_lmf_str(r#"<LexicalResource><Lexicon id="de" language="de">
+    ///     <LexicalEntry id="e1"><Lemma writtenForm="Hund" partOfSpeech="n"/><Sense id="s1" synset="n1"/></LexicalEntry>
+    ///     <LexicalEntry id="e2"><Lemma writtenForm="Köter" partOfSpeech="n"/><Sense id="s2" synset="n1"/></LexicalEntry>
+    /// </Lexicon></LexicalResource>"#).unwrap();
+    ///
+    /// assert_eq!(wordnet_model.get_synonyms("Hund"), vec!["köter"]);
+    /// ```
+    pub fn from_lmf_str(content: &str) -> Result<Self> {
+        let mut synsets: Vec<Vec<String>> = Vec::new();
+        let mut synset_indexes: HashMap<String, usize> = HashMap::new();
+        let mut sense_lemmas: HashMap<String, String> = HashMap::new();
+        // Antonym relations as (source sense id, target sense id)
+        let mut antonym_relations: Vec<(String, String)> = Vec::new();
+
+        let mut reader = Reader::from_str(content);
+        let mut lemma: Option<String> = None;
+        let mut sense: Option<String> = None;
+        loop {
+            let event = reader.read_event().map_err(|error| {
+                Error::Model(format!("invalid WordNet LMF at {}: {}", reader.error_position(), error))
+            })?;
+            match event {
+                Event::Start(element) | Event::Empty(element) => match element.local_name().as_ref() {
+                    b"Lemma" => lemma = Self::lmf_attribute(&element, "writtenForm")?,
+                    b"Sense" => {
+                        let (Some(lemma), Some(sense_id), Some(synset_id)) = (
+                            lemma.as_ref(),
+                            Self::lmf_attribute(&element, "id")?,
+                            Self::lmf_attribute(&element, "synset")?,
+                        ) else {
+                            continue;
+                        };
+                        let synset_index = *synset_indexes.entry(synset_id).or_insert_with(|| {
+                            synsets.push(Vec::new());
+                            synsets.len() - 1
+                        });
+                        synsets[synset_index].push(lemma.clone());
+                        sense_lemmas.insert(sense_id.clone(), lemma.clone());
+                        sense = Some(sense_id);
+                    }
+                    b"SenseRelation" => {
+                        if Self::lmf_attribute(&element, "relType")?.as_deref() != Some("antonym") {
+                            continue;
+                        }
+                        if let (Some(sense_id), Some(target)) =
+                            (sense.as_ref(), Self::lmf_attribute(&element, "target")?)
+                        {
+                            antonym_relations.push((sense_id.clone(), target));
+                        }
+                    }
+                    _ => {}
+                },
+                Event::End(element) => match element.local_name().as_ref() {
+                    b"Sense" => sense = None,
+                    b"LexicalEntry" => lemma = None,
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        let antonyms = antonym_relations
+            .into_iter()
+            .filter_map(|(source, target)| {
+                Some((sense_lemmas.get(&source)?.clone(), sense_lemmas.get(&target)?.clone()))
+            })
+            .collect();
+
+        Ok(WordNetModel::new(synsets, antonyms))
+    }
+
+    /// Get unescaped attribute value of LMF element
+    fn lmf_attribute(element: &BytesStart, name: &str) -> Result<Option<String>> {
+        let attribute = element
+            .try_get_attribute(name)
+            .map_err(|error| Error::Model(format!("invalid WordNet LMF attribute {}: {}", name, error)))?;
+        attribute
+            .map(|attribute| {
+                attribute
+                    .normalized_value(XmlVersion::Implicit1_0)
+                    .map(|value| value.into_owned())
+                    .map_err(|error| Error::Model(format!("invalid WordNet LMF attribute {}: {}", name, error)))
+            })
+            .transpose()
+    }
+
+    /// Load WordNet model from the path
+    /// * directory - WordNet database, all present `data.noun`, `data.verb`, `data.adj` and `data.adv` files are loaded
+    /// * `.xml` file - WN-LMF XML, see `from_lmf_str`
+    /// * other files - single WordNet database data file, see `from_wndb_str`
+    ///
+    /// # Arguments
+    /// * `path` - Path to the WordNet database directory or file
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if path.is_dir() {
+            let data_paths = WNDB_DATA_FILES
+                .iter()
+                .map(|file_name| path.join(file_name))
+                .filter(|data_path| data_path.exists())
+                .collect::<Vec<_>>();
+            if data_paths.is_empty() {
+                return Err(Error::Io(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!("no WordNet data files in {}", path.display()),
+                )));
+            }
+            let mut synsets = Vec::new();
+            let mut antonyms = Vec::new();
+            for data_path in data_paths {
+                let (file_synsets, file_antonyms) = Self::parse_wndb(&fs::read_to_string(data_path)?);
+                synsets.extend(file_synsets);
+                antonyms.extend(file_antonyms);
+            }
+            return Ok(WordNetModel::new(synsets, antonyms));
+        }
+
+        let content = fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("xml") => WordNetModel::from_lmf_str(&content),
+            _ => Ok(WordNetModel::from_wndb_str(&content)),
+        }
+    }
+
+    /// Number of synsets with at least 2 lemmas
+    pub fn len(&self) -> usize {
+        self.synsets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.synsets.is_empty()
+    }
+
+    /// Get all synonyms of the word (case-insensitive) from all its synsets, without the word itself
+    pub fn get_synonyms(&self, word: &str) -> Vec<&String> {
+        let word = Self::normalize_lemma(word);
+        let mut synonyms: Vec<&String> = Vec::new();
+        for &synset_index in self.lemma_synsets.get(&word).into_iter().flatten() {
+            for lemma in &self.synsets[synset_index] {
+                if *lemma != word && !synonyms.contains(&lemma) {
+                    synonyms.push(lemma);
+                }
+            }
+        }
+        synonyms
+    }
+
+    /// Get all antonyms of the word (case-insensitive)
+    pub fn get_antonyms(&self, word: &str) -> Vec<&String> {
+        self.antonyms
+            .get(&Self::normalize_lemma(word))
+            .map(|antonyms| antonyms.iter().collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const WORDNET_EN_PATH: &str = "../test_data/wordnet_en";
+    const WORDNET_EN_ADJ_PATH: &str = "../test_data/wordnet_en/data.adj";
+    const WORDNET_DE_PATH: &str = "../test_data/wordnet_de.xml";
+
+    #[test_case("dog", vec!["domestic dog", "canis familiaris"] ; "multi-word lemmas")]
+    #[test_case("Hot_Dog", vec!["hotdog"] ; "underscore and casing")]
+    #[test_case("good", vec!["beneficial"] ; "adjective marker")]
+    #[test_case("quick", vec!["fast"] ; "adjective")]
+    #[test_case("table", vec![] ; "unknown word")]
+    fn test_wndb_dir_synonyms(word: &str, expected: Vec<&str>) {
+        let wordnet_model = WordNetModel::from_path(WORDNET_EN_PATH).unwrap();
+
+        assert_eq!(wordnet_model.len(), 6);
+        assert_eq!(wordnet_model.get_synonyms(word), expected);
+    }
+
+    #[test_case("good", vec!["bad"] ; "antonym")]
+    #[test_case("bad", vec!["good"] ; "reverse antonym")]
+    #[test_case("quick", vec![] ; "antonym of other word in synset")]
+    #[test_case("fast", vec!["slow"] ; "antonym of first word in synset")]
+    fn test_wndb_file_antonyms(word: &str, expected: Vec<&str>) {
+        let wordnet_model = WordNetModel::from_path(WORDNET_EN_ADJ_PATH).unwrap();
+
+        assert_eq!(wordnet_model.get_antonyms(word), expected);
+    }
+
+    #[test_case("Hund", vec!["köter", "haushund"], vec![] ; "noun")]
+    #[test_case("schnell", vec!["rasch"], vec!["langsam"] ; "adjective with antonym")]
+    #[test_case("keineswegs", vec!["gar nicht"], vec![] ; "multi-word lemma")]
+    fn test_lmf_file(word: &str, expected_synonyms: Vec<&str>, expected_antonyms: Vec<&str>) {
+        let wordnet_model = WordNetModel::from_path(WORDNET_DE_PATH).unwrap();
+
+        assert_eq!(wordnet_model.len(), 3);
+        assert_eq!(wordnet_model.get_synonyms(word), expected_synonyms);
+        assert_eq!(wordnet_model.get_antonyms(word), expected_antonyms);
+    }
+
+    #[test]
+    fn test_malformed_lines_skipped() {
+        let wordnet_model =
+            WordNetModel::from_wndb_str("  1 license\nnot a synset\n00000001 00 n 02 a 0 b 0 000 | gloss");
+
+        assert_eq!(wordnet_model.len(), 1);
+    }
+
+    #[test_case("0101", vec!["slow"] ; "valid source target")]
+    #[test_case("0\u{e9}1", vec![] ; "non-ascii source target")]
+    #[test_case("0x01", vec![] ; "invalid hex source")]
+    fn test_malformed_pointer_skipped(source_target: &str, expected_antonyms: Vec<&str>) {
+        let content = format!(
+            "00000001 00 a 02 fast 0 quick 0 001 ! 00000002 a {} | gloss\n00000002 00 a 02 slow 0 dull 0 000 | gloss",
+            source_target
+        );
+        let wordnet_model = WordNetModel::from_wndb_str(&content);
+
+        assert_eq!(wordnet_model.len(), 2);
+        assert_eq!(wordnet_model.get_antonyms("fast"), expected_antonyms);
+    }
+
+    #[test]
+    fn test_invalid_lmf() {
+        let result = WordNetModel::from_lmf_str("<LexicalResource><Lexicon></LexicalResource>");
+
+        assert!(matches!(result, Err(Error::Model(_))));
+    }
+
+    #[test_case("../test_data/not_existing_file.xml" ; "not existing file")]
+    #[test_case("../test_data" ; "directory without data files")]
+    fn test_from_path_error(path: &str) {
+        assert!(matches!(WordNetModel::from_path(path), Err(Error::Io(_))));
+    }
+}
@@ -1,9 +1,21 @@
-use super::utils::{Doc, Token};
+use super::utils::{Change, Doc, Token};
 use crate::base::BaseAugmenter;
 use rand::prelude::IteratorRandom;
 use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
+/// Action to perform on the selected elements (words, chars, etc.)
+/// Serialized in lowercase, e.g. "substitute"
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TextAction {
+    Insert,
+    Substitute,
+    Swap,
+    Delete,
+}
+
 pub trait BaseTextAugmenter: BaseAugmenter<String, Doc> {
     /// Select random word tokens to augment given a number of elements
     /// Returns a vector of tuples (index, &mut token)
@@ -64,8 +76,146 @@ pub trait BaseTextAugmenter: BaseAugmenter<String, Doc> {
     }
 }
 
+/// Augment text and get the log of changes made by augmenters, e.g. to debug pipelines
+/// Implemented for all text augmenters, including flow augmenters (Sequential, Selector, Chance) over text
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::text::{AugmentWithChanges, TextAction, TextAugmentParameters, WordsRandomDeleteAugmenter};
+///
+/// let augmenter = WordsRandomDeleteAugmenter::new(TextAugmentParameters::new(1.0, None, None), None);
+/// let (output, changes) = augmenter.augment_with_changes("Some text".to_string(), &mut rand::thread_rng());
+///
+/// assert_eq!(output, " ");
+/// assert_eq!(changes.len(), 2);
+/// assert_eq!(changes[0].operation, TextAction::Delete);
+/// ```
+pub trait AugmentWithChanges {
+    /// Augment an input, returns augmented text and the changes in the order they were made
+    fn augment_with_changes(&self, input: String, rng: &mut dyn rand::RngCore) -> (String, Vec<Change>);
+}
+
+impl<A: BaseAugmenter<String, Doc> + ?Sized> AugmentWithChanges for A {
+    fn augment_with_changes(&self, input: String, rng: &mut dyn rand::RngCore) -> (String, Vec<Change>) {
+        let mut doc = self.convert_to_inner(input);
+        doc.track_changes();
+        let mut doc = self.augment_inner(doc, rng);
+        let changes = doc.take_changes();
+        (self.convert_to_outer(doc), changes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::flow::{ChanceAugmenter, SelectorAugmenter, SequentialAugmenter};
+    use crate::models::text::{AlphabetModel, TfIdfModel};
+    use crate::text::{
+        CharsRandomInsertAugmenter, TextAugmentParameters, WordsRandomDeleteAugmenter, WordsRandomInsertAugmenter,
+        WordsRandomSwapAugmenter, WordsTfIdfAugmenter,
+    };
+    use std::sync::Arc;
+    use test_case::test_case;
+
+    type TextAugmenter = Arc<dyn BaseAugmenter<String, Doc> + Send + Sync>;
+
+    const TEXT: &str = "The quick brown fox jumps over the lazy dog";
+
+    fn params() -> TextAugmentParameters {
+        TextAugmentParameters::new(0.5, None, None)
+    }
+
+    #[test_case(Arc::new(WordsRandomSwapAugmenter::new(params(), None)), TextAction::Swap ; "words swap")]
+    #[test_case(Arc::new(WordsRandomDeleteAugmenter::new(params(), None)), TextAction::Delete ; "words delete")]
+    #[test_case(Arc::new(WordsRandomInsertAugmenter::new(params(), vec!["new".to_string()], None)), TextAction::Insert ; "words insert")]
+    #[test_case(Arc::new(WordsTfIdfAugmenter::new(TextAction::Insert, params(), TfIdfModel::fit([TEXT]), None)), TextAction::Insert ; "words tf-idf insert")]
+    #[test_case(Arc::new(CharsRandomInsertAugmenter::new(params(), params(), AlphabetModel::from_locale_str("en"), None)), TextAction::Insert ; "chars insert")]
+    fn test_changes_point_to_changed_tokens(augmenter: TextAugmenter, expected_operation: TextAction) {
+        let mut doc = Doc::new(TEXT);
+        doc.track_changes();
+
+        let doc = augmenter.augment_inner(doc, &mut rand::thread_rng());
+
+        let changes = doc.changes().unwrap();
+        assert!(!changes.is_empty());
+        assert_eq!(changes.len(), doc.num_changes);
+        for change in changes {
+            assert_eq!(change.operation, expected_operation);
+            // Swapped tokens can be moved again by the following swaps
+            if let (Some(new_index), false) = (change.new_index, expected_operation == TextAction::Swap) {
+                assert_eq!(&doc.tokens[new_index].token().as_str(), &change.new_text);
+            }
+        }
+    }
+
+    #[test]
+    fn test_augment_with_changes_sequential() {
+        let augmenter = SequentialAugmenter::new(vec![
+            Arc::new(WordsRandomSwapAugmenter::new(params(), None)) as TextAugmenter,
+            Arc::new(WordsRandomDeleteAugmenter::new(params(), None)),
+        ]);
+
+        let (output, changes) = augmenter.augment_with_changes(TEXT.to_string(), &mut rand::thread_rng());
+
+        assert_ne!(output, TEXT);
+        let augmenters = changes
+            .iter()
+            .map(|change| change.augmenter.as_str())
+            .collect::<Vec<&str>>();
+        let num_swaps = augmenters
+            .iter()
+            .take_while(|&&name| name == "WordsRandomSwapAugmenter")
+            .count();
+        assert!(num_swaps > 0);
+        assert!(augmenters[num_swaps..]
+            .iter()
+            .all(|&name| name == "WordsRandomDeleteAugmenter"));
+        assert!(augmenters.len() > num_swaps);
+    }
+
+    #[test_case(0.0, 0 ; "never applied")]
+    #[test_case(1.0, 2 ; "always applied")]
+    fn test_augment_with_changes_chance(probability: f64, expected_num_changes: usize) {
+        let swap_all = Arc::new(WordsRandomSwapAugmenter::new(
+            TextAugmentParameters::new(1.0, None, None),
+            None,
+        ));
+        let augmenter = ChanceAugmenter::new(swap_all as TextAugmenter, probability);
+
+        let (_, changes) = augmenter.augment_with_changes("A B C D".to_string(), &mut rand::thread_rng());
+
+        assert_eq!(changes.len(), expected_num_changes);
+    }
+
+    #[test]
+    fn test_augment_with_changes_selector() {
+        let augmenter = SelectorAugmenter::new(
+            vec![
+                Arc::new(WordsRandomSwapAugmenter::new(params(), None)) as TextAugmenter,
+                Arc::new(WordsRandomDeleteAugmenter::new(params(), None)),
+            ],
+            None,
+        );
+
+        let (_, changes) = augmenter.augment_with_changes(TEXT.to_string(), &mut rand::thread_rng());
+
+        assert!(!changes.is_empty());
+        assert!(changes.iter().all(|change| change.augmenter == changes[0].augmenter));
+    }
+
+    #[test]
+    fn test_augment_with_changes_output_matches_augment() {
+        use rand::SeedableRng;
+
+        let augmenter = WordsRandomSwapAugmenter::new(params(), None);
+
+        let output = augmenter.augment(TEXT.to_string(), &mut rand::rngs::StdRng::seed_from_u64(42));
+        let (output_with_changes, _) =
+            augmenter.augment_with_changes(TEXT.to_string(), &mut rand::rngs::StdRng::seed_from_u64(42));
+
+        assert_eq!(output, output_with_changes);
+    }
+
     // #[test_case(0.5, 10, 5)]
     // #[test_case(0.7, 10, 7)]
     // #[test_case(0.3, 10, 3)]
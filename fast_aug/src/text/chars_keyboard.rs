@@ -1,53 +1,217 @@
-#![allow(dead_code, unused_variables, unreachable_code, unused_imports)]
-
 use super::base::{BaseTextAugmenter, TextAction};
-use super::utils::{Doc, TextAugmentParameters};
+use super::utils::{Casing, Change, CharUnit, Doc, TextAugmentParameters};
 use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
+use crate::models::text::KeyboardModel;
 use std::collections::HashSet;
 
+/// Augmenter that imitates typos using physically adjacent keys of the keyboard
+/// * `TextAction::Substitute` - replace chars with adjacent keys (e.g. "hello" -> "hrllo")
+/// * `TextAction::Swap` - swap neighbour chars typed with adjacent keys (e.g. "hello" -> "helol")
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::models::text::KeyboardModel;
+/// use fast_aug::text::{CharsKeyboardAugmenter, TextAction, TextAugmentParameters};
+///
+/// let rng = &mut rand::thread_rng();
+/// let keyboard_model = KeyboardModel::from_locale_str("en");
+/// let augmenter = CharsKeyboardAugmenter::new(
+///     TextAction::Substitute,
+///     TextAugmentParameters::new(0.5, None, None),
+///     TextAugmentParameters::new(0.5, None, None),
+///     keyboard_model,
+///     None,
+/// );
+/// augmenter.augment("Some text!".to_string(), rng);
+/// augmenter.augment_batch(vec!["Some text!".to_string()], rng);
+/// ```
 pub struct CharsKeyboardAugmenter {
-    /// Action to augmentation, set of values {'substitute', 'swap', 'delete'}
+    /// Action to augmentation, set of values {'substitute', 'swap'}
     action: TextAction,
     /// Parameters to calculate number of words that will be augmented
     word_params: TextAugmentParameters,
     /// Parameters to calculate number of chars that will be augmented in each word
     char_params: TextAugmentParameters,
+    /// Units of chars to augment, see `CharUnit`
+    char_unit: CharUnit,
+    /// Casing of the new chars, see `Casing`
+    casing: Casing,
+    /// Keyboard Model to find adjacent keys
+    keyboard_model: KeyboardModel,
     /// Filter, Set of words that cannot be augmented
     stopwords: Option<HashSet<String>>,
 }
 
 impl CharsKeyboardAugmenter {
+    /// Create augmenter, action and parameters are not validated, see `try_new`
     pub fn new(
         action: TextAction,
         word_params: TextAugmentParameters,
         char_params: TextAugmentParameters,
+        keyboard_model: KeyboardModel,
         stopwords: Option<HashSet<String>>,
     ) -> Self {
         CharsKeyboardAugmenter {
             action,
             word_params,
             char_params,
+            char_unit: CharUnit::Grapheme,
+            casing: Casing::Preserve,
+            keyboard_model,
             stopwords,
         }
     }
 
-    fn substitute(&self, mut _doc: Doc, _rng: &mut dyn rand::RngCore) -> Doc {
-        todo!();
+    /// Create augmenter, returns error if action is not Substitute or Swap, or parameters are invalid
+    pub fn try_new(
+        action: TextAction,
+        word_params: TextAugmentParameters,
+        char_params: TextAugmentParameters,
+        keyboard_model: KeyboardModel,
+        stopwords: Option<HashSet<String>>,
+    ) -> Result<Self> {
+        if action != TextAction::Substitute && action != TextAction::Swap {
+            return Err(Error::UnsupportedAction(format!(
+                "CharsKeyboardAugmenter supports only Substitute and Swap, got {:?}",
+                action
+            )));
+        }
+        word_params.validate()?;
+        char_params.validate()?;
+        Ok(Self::new(action, word_params, char_params, keyboard_model, stopwords))
+    }
+
+    /// Set units of chars to augment, extended grapheme clusters by default
+    /// With graphemes only single code point graphemes are typed with keys, e.g. "e" in "café" but not "é" in "cafe\u{301}"
+    pub fn with_char_unit(mut self, char_unit: CharUnit) -> Self {
+        self.char_unit = char_unit;
+        self
+    }
+
+    /// Set casing of the new chars, matches casing of the original chars by default
+    pub fn with_casing(mut self, casing: Casing) -> Self {
+        self.casing = casing;
+        self
+    }
+
+    /// Get the char of a single code point unit, None for graphemes of several code points
+    fn single_char(unit: &str) -> Option<char> {
+        let mut chars = unit.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    }
+
+    fn substitute(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        // Select random word tokens
+        let word_tokens_indexes = doc.get_word_indexes(false, self.stopwords.as_ref());
+        let num_tokens_to_change = self.word_params.num_elements(word_tokens_indexes.len());
+        let selected_tokens_indexes =
+            self.select_random_element_indexes(rng, word_tokens_indexes, num_tokens_to_change);
+
+        // For all selected tokens select random chars (present on the keyboard) and substitute with adjacent keys
+        for token_index in selected_tokens_indexes {
+            let token = &mut doc.tokens[token_index];
+            let chars = self.char_unit.split(token.token());
+            let num_chars_to_change = self.char_params.num_elements(chars.len());
+
+            let candidate_chars_indexes = (0..chars.len())
+                .filter(|&idx| {
+                    Self::single_char(chars[idx]).is_some_and(|c| self.keyboard_model.get_adjacent_chars(c).is_some())
+                })
+                .collect::<Vec<usize>>();
+            if candidate_chars_indexes.is_empty() || num_chars_to_change == 0 {
+                continue;
+            }
+
+            let selected_chars_indexes =
+                self.select_random_element_indexes(rng, candidate_chars_indexes, num_chars_to_change);
+            let mut new_chars: Vec<Option<String>> = vec![None; chars.len()];
+            for idx in selected_chars_indexes {
+                let char = Self::single_char(chars[idx]).unwrap();
+                new_chars[idx] = self
+                    .keyboard_model
+                    .get_random_adjacent_char(char, rng)
+                    .map(|new_char| self.casing.apply_char(chars[idx], &new_char.to_string(), rng));
+            }
+            let mut new_token = String::with_capacity(token.byte_len());
+            for (char, new_char) in chars.into_iter().zip(new_chars) {
+                match new_char {
+                    Some(new_char) => new_token.push_str(&new_char),
+                    None => new_token.push_str(char),
+                }
+            }
+            let old_token = token.change(&new_token, *token.kind());
+
+            doc.record_change(|tokens| {
+                Change::new(
+                    "CharsKeyboardAugmenter",
+                    TextAction::Substitute,
+                    Some(token_index),
+                    Some(token_index),
+                    &old_token,
+                    tokens[token_index].token(),
+                )
+            });
+        }
+
+        doc
     }
 
     fn swap(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
-        // TODO: adjacent, middle, random swaps (now only random)
         // Select random word tokens
         let word_tokens_indexes = doc.get_word_indexes(false, self.stopwords.as_ref());
         let num_tokens_to_change = self.word_params.num_elements(word_tokens_indexes.len());
         let selected_tokens_indexes =
             self.select_random_element_indexes(rng, word_tokens_indexes, num_tokens_to_change);
 
-        // For all selected tokens select random chars and swap them
+        // For all selected tokens select random neighbour chars typed with adjacent keys and swap them
         for token_index in selected_tokens_indexes {
-            todo!();
+            let token = &mut doc.tokens[token_index];
+            let mut chars = self.char_unit.split(token.token());
+            let num_chars_to_change = self.char_params.num_elements(chars.len());
+
+            // Candidate is a position i, such that chars i and i+1 are adjacent keys
+            let candidate_pairs_indexes = (0..chars.len().saturating_sub(1))
+                .filter(
+                    |&idx| match (Self::single_char(chars[idx]), Self::single_char(chars[idx + 1])) {
+                        (Some(left), Some(right)) => self.keyboard_model.is_adjacent(left, right),
+                        _ => false,
+                    },
+                )
+                .collect::<Vec<usize>>();
+            if candidate_pairs_indexes.is_empty() || num_chars_to_change == 0 {
+                continue;
+            }
 
-            doc.num_changes += 1;
+            // Skip overlapping pairs, so each char is swapped at most once
+            let mut selected_pairs_indexes =
+                self.select_random_element_indexes(rng, candidate_pairs_indexes, num_chars_to_change);
+            selected_pairs_indexes.sort();
+            let mut last_swapped_idx: Option<usize> = None;
+            for idx in selected_pairs_indexes {
+                if last_swapped_idx.is_some_and(|last_idx| idx <= last_idx + 1) {
+                    continue;
+                }
+                chars.swap(idx, idx + 1);
+                last_swapped_idx = Some(idx);
+            }
+            let new_token = chars.concat();
+            let old_token = token.change(&new_token, *token.kind());
+
+            doc.record_change(|tokens| {
+                Change::new(
+                    "CharsKeyboardAugmenter",
+                    TextAction::Swap,
+                    Some(token_index),
+                    Some(token_index),
+                    &old_token,
+                    tokens[token_index].token(),
+                )
+            });
         }
 
         doc
@@ -78,4 +242,122 @@ impl BaseAugmenter<String, Doc> for CharsKeyboardAugmenter {
 mod tests {
     use super::*;
     use test_case::test_case;
+
+    #[test_case(vec!["asdf", "qwer", "zxcv", "tyui", "ghjk"], 0.5, 0.5, 3 ; "round 2.5 as 3 words, round 2.5 as 3 chars each")]
+    #[test_case(vec!["asdf", "qwer", "zxcv", "tyui", "ghjk"], 0.0, 0.5, 0 ; "substitute chars in 0 words - no changes")]
+    #[test_case(vec!["asdf", "qwer", "zxcv", "tyui", "ghjk"], 0.5, 0.0, 0 ; "substitute 0 chars - no changes")]
+    #[test_case(vec!["1234", "5678", "!", "...", "-"], 1.0, 1.0, 0 ; "no chars on keyboard - no changes")]
+    fn test_substitute(input_tokens: Vec<&str>, words_p: f32, chars_p: f32, expected_doc_changes: usize) {
+        let mut doc = Doc::from_tokens(input_tokens);
+        let words_params = TextAugmentParameters::new(words_p, None, None);
+        let chars_params = TextAugmentParameters::new(chars_p, None, None);
+        let keyboard_model = KeyboardModel::from_locale_str("en");
+        let aug = CharsKeyboardAugmenter::new(TextAction::Substitute, words_params, chars_params, keyboard_model, None);
+
+        let doc_tokens_before = doc.tokens.clone();
+
+        doc = aug.augment_inner(doc, &mut rand::thread_rng());
+
+        let doc_tokens_after = doc.tokens.clone();
+
+        assert_eq!(doc.num_changes, expected_doc_changes);
+        let mut num_changed_words = 0;
+        for (token_before, token_after) in doc_tokens_before.iter().zip(doc_tokens_after.iter()) {
+            if token_before.token() != token_after.token() {
+                assert_eq!(token_before.utf8_len(), token_after.utf8_len());
+                num_changed_words += 1;
+            }
+        }
+        assert_eq!(num_changed_words, expected_doc_changes);
+    }
+
+    #[test_case("Hello WORLD", "en" ; "latin script")]
+    #[test_case("Привет МИР", "ru" ; "cyrillic script")]
+    fn test_substitute_keeps_case(text: &str, locale_str: &str) {
+        let keyboard_model = KeyboardModel::from_locale_str(locale_str);
+        let params = TextAugmentParameters::new(1.0, None, None);
+        let aug = CharsKeyboardAugmenter::new(TextAction::Substitute, params.clone(), params, keyboard_model, None);
+
+        let output = aug.augment(text.to_string(), &mut rand::thread_rng());
+
+        assert_ne!(output, text);
+        for (char_before, char_after) in text.chars().zip(output.chars()) {
+            assert_eq!(char_before.is_uppercase(), char_after.is_uppercase());
+        }
+    }
+
+    #[test_case(vec!["asdf", "qwer", "zxcv", "tyui", "ghjk"], 0.5, 0.5, 3 ; "round 2.5 as 3 words")]
+    #[test_case(vec!["asdf", "qwer", "zxcv", "tyui", "ghjk"], 0.0, 0.5, 0 ; "swap chars in 0 words - no changes")]
+    #[test_case(vec!["aqaq", "plpl", "zaza", "mjmj", "xsxs"], 0.5, 0.0, 0 ; "swap 0 chars - no changes")]
+    #[test_case(vec!["apap", "zpzp", "qmqm", "pzpz", "mqmq"], 1.0, 1.0, 0 ; "no adjacent keys - no changes")]
+    fn test_swap(input_tokens: Vec<&str>, words_p: f32, chars_p: f32, expected_doc_changes: usize) {
+        let mut doc = Doc::from_tokens(input_tokens);
+        let words_params = TextAugmentParameters::new(words_p, None, None);
+        let chars_params = TextAugmentParameters::new(chars_p, None, None);
+        let keyboard_model = KeyboardModel::from_locale_str("en");
+        let aug = CharsKeyboardAugmenter::new(TextAction::Swap, words_params, chars_params, keyboard_model, None);
+
+        let doc_tokens_before = doc.tokens.clone();
+
+        doc = aug.augment_inner(doc, &mut rand::thread_rng());
+
+        let doc_tokens_after = doc.tokens.clone();
+
+        assert_eq!(doc.num_changes, expected_doc_changes);
+        let mut num_changed_words = 0;
+        for (token_before, token_after) in doc_tokens_before.iter().zip(doc_tokens_after.iter()) {
+            if token_before.token() != token_after.token() {
+                let mut chars_before = token_before.token().chars().collect::<Vec<char>>();
+                let mut chars_after = token_after.token().chars().collect::<Vec<char>>();
+                chars_before.sort();
+                chars_after.sort();
+                assert_eq!(chars_before, chars_after);
+                num_changed_words += 1;
+            }
+        }
+        assert_eq!(num_changed_words, expected_doc_changes);
+    }
+
+    #[test]
+    fn test_unsupported_action() {
+        let aug = CharsKeyboardAugmenter::new(
+            TextAction::Delete,
+            TextAugmentParameters::default(),
+            TextAugmentParameters::default(),
+            KeyboardModel::from_locale_str("en"),
+            None,
+        );
+        let result = std::panic::catch_unwind(|| aug.augment("hello".to_string(), &mut rand::thread_rng()));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_try_new_unsupported_action() {
+        let result = CharsKeyboardAugmenter::try_new(
+            TextAction::Insert,
+            TextAugmentParameters::default(),
+            TextAugmentParameters::default(),
+            KeyboardModel::from_locale_str("en"),
+            None,
+        );
+        assert!(matches!(result, Err(Error::UnsupportedAction(_))));
+    }
+
+    #[test_case(CharUnit::Grapheme, true ; "grapheme with combining diacritic is kept")]
+    #[test_case(CharUnit::CodePoint, false ; "base char of combining diacritic is typed")]
+    fn test_substitute_char_units(char_unit: CharUnit, expected_kept: bool) {
+        let aug = CharsKeyboardAugmenter::new(
+            TextAction::Substitute,
+            TextAugmentParameters::new(1.0, None, None),
+            TextAugmentParameters::new(1.0, None, None),
+            KeyboardModel::from_locale_str("en"),
+            None,
+        )
+        .with_char_unit(char_unit);
+
+        let output = aug.augment("cafe\u{301}".to_string(), &mut rand::thread_rng());
+
+        assert!(output.ends_with('\u{301}'));
+        assert_eq!(output.ends_with("e\u{301}"), expected_kept);
+    }
 }
@@ -1,6 +1,7 @@
-use super::base::BaseTextAugmenter;
-use super::utils::{Doc, TextAugmentParameters};
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Change, CharUnit, Doc, TextAugmentParameters};
 use crate::base::BaseAugmenter;
+use crate::error::Result;
 use std::collections::HashSet;
 
 /// Augmenter that deletes random chars in random words in text
@@ -24,6 +25,8 @@ pub struct CharsRandomDeleteAugmenter {
     word_params: TextAugmentParameters,
     /// Parameters to calculate number of chars that will be augmented in each word
     char_params: TextAugmentParameters,
+    /// Units of chars to augment, see `CharUnit`
+    char_unit: CharUnit,
     /// Filter, Set of words that cannot be augmented
     stopwords: Option<HashSet<String>>,
 }
@@ -37,9 +40,27 @@ impl CharsRandomDeleteAugmenter {
         CharsRandomDeleteAugmenter {
             word_params,
             char_params,
+            char_unit: CharUnit::Grapheme,
             stopwords,
         }
     }
+
+    /// Create augmenter, returns error if word or char parameters are invalid
+    pub fn try_new(
+        word_params: TextAugmentParameters,
+        char_params: TextAugmentParameters,
+        stopwords: Option<HashSet<String>>,
+    ) -> Result<Self> {
+        word_params.validate()?;
+        char_params.validate()?;
+        Ok(CharsRandomDeleteAugmenter::new(word_params, char_params, stopwords))
+    }
+
+    /// Set units of chars to augment, extended grapheme clusters by default
+    pub fn with_char_unit(mut self, char_unit: CharUnit) -> Self {
+        self.char_unit = char_unit;
+        self
+    }
 }
 
 impl BaseTextAugmenter for CharsRandomDeleteAugmenter {}
@@ -55,19 +76,29 @@ impl BaseAugmenter<String, Doc> for CharsRandomDeleteAugmenter {
         // For all selected tokens select random chars and remove them
         for token_index in selected_tokens_indexes {
             let token = &mut input.tokens[token_index];
-            let num_chars_to_change = self.char_params.num_elements(token.utf8_len());
+            let chars = self.char_unit.split(token.token());
+            let num_chars_to_change = self.char_params.num_elements(chars.len());
 
             let selected_chars_indexes =
-                self.select_random_element_indexes(rng, (0..token.utf8_len()).collect(), num_chars_to_change);
-            let mut new_token = String::with_capacity(token.utf8_len());
-            for (idx, char) in token.token().char_indices() {
+                self.select_random_element_indexes(rng, (0..chars.len()).collect(), num_chars_to_change);
+            let mut new_token = String::with_capacity(token.byte_len());
+            for (idx, char) in chars.into_iter().enumerate() {
                 if !selected_chars_indexes.contains(&idx) {
-                    new_token.push(char);
+                    new_token.push_str(char);
                 }
             }
-            token.change(&new_token, *token.kind());
-
-            input.num_changes += 1;
+            let old_token = token.change(&new_token, *token.kind());
+
+            input.record_change(|tokens| {
+                Change::new(
+                    "CharsRandomDeleteAugmenter",
+                    TextAction::Delete,
+                    Some(token_index),
+                    Some(token_index),
+                    &old_token,
+                    tokens[token_index].token(),
+                )
+            });
         }
 
         input
@@ -85,6 +116,7 @@ impl BaseAugmenter<String, Doc> for CharsRandomDeleteAugmenter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::text::TokenType;
     use test_case::test_case;
 
     #[test_case(vec!["AAAA", "BBBB", "CCCC", "DDDD", "EEEE"], 0.5, 0.5, 3, 3 ; "round 2.5 as 3 words round 2.5 as 3 chars each")]
@@ -125,4 +157,39 @@ mod tests {
         }
         assert_eq!(num_changed_words, expected_changed_words);
     }
+
+    #[test_case(1.5, 0.5 ; "invalid word probability")]
+    #[test_case(0.5, -0.5 ; "invalid char probability")]
+    fn test_try_new_invalid(words_p: f32, chars_p: f32) {
+        let words_params = TextAugmentParameters::new(words_p, None, None);
+        let chars_params = TextAugmentParameters::new(chars_p, None, None);
+        assert!(CharsRandomDeleteAugmenter::try_new(words_params, chars_params, None).is_err());
+    }
+
+    #[test_case("नमस्ते दुनिया", CharUnit::Grapheme ; "devanagari graphemes")]
+    #[test_case("สวัสดี ชาวโลก", CharUnit::Grapheme ; "thai graphemes")]
+    #[test_case("nai\u{308}ve cafe\u{301}", CharUnit::Grapheme ; "combining diacritics graphemes")]
+    #[test_case("nai\u{308}ve cafe\u{301}", CharUnit::CodePoint ; "combining diacritics code points")]
+    fn test_delete_char_units(text: &str, char_unit: CharUnit) {
+        let chars_params = TextAugmentParameters::new(0.5, None, None);
+        let aug =
+            CharsRandomDeleteAugmenter::new(TextAugmentParameters::new(1.0, None, None), chars_params.clone(), None)
+                .with_char_unit(char_unit);
+
+        let doc_tokens_before = Doc::new(text).tokens;
+        let doc = aug.augment_inner(Doc::new(text), &mut rand::thread_rng());
+
+        for (token_before, token_after) in doc_tokens_before.iter().zip(doc.tokens.iter()) {
+            if token_before.kind() != &TokenType::Word {
+                continue;
+            }
+            let (chars_before, chars_after) = (
+                char_unit.split(token_before.token()),
+                char_unit.split(token_after.token()),
+            );
+            let num_deleted_chars = chars_params.num_elements(chars_before.len());
+            assert_eq!(chars_after.len(), chars_before.len() - num_deleted_chars);
+            assert!(chars_after.iter().all(|char| chars_before.contains(char)));
+        }
+    }
 }
@@ -1,6 +1,7 @@
-use super::base::BaseTextAugmenter;
-use super::utils::{Doc, TextAugmentParameters};
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Casing, Change, CharUnit, Doc, TextAugmentParameters};
 use crate::base::BaseAugmenter;
+use crate::error::Result;
 use crate::models::text::AlphabetModel;
 use std::collections::HashSet;
 
@@ -28,6 +29,10 @@ pub struct CharsRandomSubstituteAugmenter {
     word_params: TextAugmentParameters,
     /// Parameters to calculate number of chars that will be augmented in each word
     char_params: TextAugmentParameters,
+    /// Units of chars to augment, see `CharUnit`
+    char_unit: CharUnit,
+    /// Casing of the new chars, see `Casing`
+    casing: Casing,
     /// Optional Alphabet Model to use for insert and substitute actions
     /// TODO: Make Lazy Loading for models
     alphabet_model: AlphabetModel,
@@ -45,10 +50,41 @@ impl CharsRandomSubstituteAugmenter {
         CharsRandomSubstituteAugmenter {
             word_params,
             char_params,
+            char_unit: CharUnit::Grapheme,
+            casing: Casing::Preserve,
             alphabet_model,
             stopwords,
         }
     }
+
+    /// Create augmenter, returns error if word or char parameters are invalid
+    pub fn try_new(
+        word_params: TextAugmentParameters,
+        char_params: TextAugmentParameters,
+        alphabet_model: AlphabetModel,
+        stopwords: Option<HashSet<String>>,
+    ) -> Result<Self> {
+        word_params.validate()?;
+        char_params.validate()?;
+        Ok(CharsRandomSubstituteAugmenter::new(
+            word_params,
+            char_params,
+            alphabet_model,
+            stopwords,
+        ))
+    }
+
+    /// Set units of chars to augment, extended grapheme clusters by default
+    pub fn with_char_unit(mut self, char_unit: CharUnit) -> Self {
+        self.char_unit = char_unit;
+        self
+    }
+
+    /// Set casing of the new chars, matches casing of the original chars by default
+    pub fn with_casing(mut self, casing: Casing) -> Self {
+        self.casing = casing;
+        self
+    }
 }
 
 impl BaseTextAugmenter for CharsRandomSubstituteAugmenter {}
@@ -64,19 +100,35 @@ impl BaseAugmenter<String, Doc> for CharsRandomSubstituteAugmenter {
         // For all selected tokens select random chars and substitute them
         for token_index in selected_tokens_indexes {
             let token = &mut input.tokens[token_index];
-            let num_chars_to_change = self.char_params.num_elements(token.utf8_len());
+            let chars = self.char_unit.split(token.token());
+            let num_chars_to_change = self.char_params.num_elements(chars.len());
 
             let selected_chars_indexes =
-                self.select_random_element_indexes(rng, (0..token.utf8_len()).collect(), num_chars_to_change);
-            let mut chars = token.token().chars().collect::<Vec<char>>();
+                self.select_random_element_indexes(rng, (0..chars.len()).collect(), num_chars_to_change);
+            let mut new_chars: Vec<Option<String>> = vec![None; chars.len()];
             for idx in selected_chars_indexes {
-                let new_char = self.alphabet_model.get_random_char(true, false, rng);
-                chars[idx] = new_char;
+                let new_char = self.alphabet_model.get_random_char(true, false, rng).to_string();
+                new_chars[idx] = Some(self.casing.apply_char(chars[idx], &new_char, rng));
             }
-            let new_token = chars.iter().collect::<String>();
-            token.change(&new_token, *token.kind());
-
-            input.num_changes += 1;
+            let mut new_token = String::with_capacity(token.byte_len());
+            for (char, new_char) in chars.into_iter().zip(new_chars) {
+                match new_char {
+                    Some(new_char) => new_token.push_str(&new_char),
+                    None => new_token.push_str(char),
+                }
+            }
+            let old_token = token.change(&new_token, *token.kind());
+
+            input.record_change(|tokens| {
+                Change::new(
+                    "CharsRandomSubstituteAugmenter",
+                    TextAction::Substitute,
+                    Some(token_index),
+                    Some(token_index),
+                    &old_token,
+                    tokens[token_index].token(),
+                )
+            });
         }
 
         input
@@ -130,4 +182,52 @@ mod tests {
         }
         assert_eq!(num_changed_words, expected_doc_changes);
     }
+
+    #[test_case("नमस्ते दुनिया", CharUnit::Grapheme ; "devanagari graphemes")]
+    #[test_case("สวัสดี ชาวโลก", CharUnit::Grapheme ; "thai graphemes")]
+    #[test_case("nai\u{308}ve cafe\u{301}", CharUnit::Grapheme ; "combining diacritics graphemes")]
+    #[test_case("nai\u{308}ve cafe\u{301}", CharUnit::CodePoint ; "combining diacritics code points")]
+    fn test_substitute_char_units(text: &str, char_unit: CharUnit) {
+        let aug = CharsRandomSubstituteAugmenter::new(
+            TextAugmentParameters::new(1.0, None, None),
+            TextAugmentParameters::new(0.5, None, None),
+            AlphabetModel::from_locale_str("en"),
+            None,
+        )
+        .with_char_unit(char_unit);
+
+        let doc_tokens_before = Doc::new(text).tokens;
+        let doc = aug.augment_inner(Doc::new(text), &mut rand::thread_rng());
+
+        for (token_before, token_after) in doc_tokens_before.iter().zip(doc.tokens.iter()) {
+            let (chars_before, chars_after) = (
+                char_unit.split(token_before.token()),
+                char_unit.split(token_after.token()),
+            );
+            assert_eq!(chars_after.len(), chars_before.len());
+            for (char_before, char_after) in chars_before.into_iter().zip(chars_after) {
+                assert!(char_after == char_before || char_after.is_ascii());
+            }
+        }
+    }
+
+    #[test_case("HELLO", Casing::Preserve, "HELLO" ; "preserve upper")]
+    #[test_case("hello", Casing::Preserve, "hello" ; "preserve lower")]
+    #[test_case("HELLO", Casing::AsIs, "hello" ; "as is lower case alphabet")]
+    fn test_substitute_casing(text: &str, casing: Casing, expected_case: &str) {
+        let aug = CharsRandomSubstituteAugmenter::new(
+            TextAugmentParameters::new(1.0, None, None),
+            TextAugmentParameters::new(1.0, None, None),
+            AlphabetModel::from_locale_str("en"),
+            None,
+        )
+        .with_casing(casing);
+
+        let output = aug.augment(text.to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output.len(), text.len());
+        for (char, expected_char) in output.chars().zip(expected_case.chars()) {
+            assert_eq!(char.is_uppercase(), expected_char.is_uppercase());
+        }
+    }
 }
@@ -1,21 +1,42 @@
-use super::base::BaseTextAugmenter;
-use super::utils::{Doc, TextAugmentParameters};
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Change, CharUnit, Doc, TextAugmentParameters};
 use crate::base::BaseAugmenter;
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::ops::Range;
+
+/// Modes to select chars to swap in the word
+///
+/// SwapMode::Random   -> Swap pairs of random chars
+/// SwapMode::Adjacent -> Swap random chars with their right neighbours, like typing errors
+/// SwapMode::Middle   -> Swap pairs of random chars, but keep the first and the last char in place ("Cmabrigde")
+///
+/// Serialized in lowercase, e.g. "adjacent"
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SwapMode {
+    #[default]
+    Random,
+    Adjacent,
+    Middle,
+}
 
 /// Augmenter that swaps random chars in random words in text
+/// Number of chars to swap is calculated with char parameters, each swap changes 2 chars.
 ///
 /// # Examples
 /// ```rust
 /// use fast_aug::base::BaseAugmenter;
-/// use fast_aug::text::{CharsRandomSwapAugmenter, TextAugmentParameters};
+/// use fast_aug::text::{CharsRandomSwapAugmenter, SwapMode, TextAugmentParameters};
 ///
 /// let rng = &mut rand::thread_rng();
 /// let augmenter = CharsRandomSwapAugmenter::new(
 ///     TextAugmentParameters::new(0.5, None, None),
 ///     TextAugmentParameters::new(0.5, None, None),
 ///     None,
-/// );
+/// )
+/// .with_swap_mode(SwapMode::Adjacent);
 /// augmenter.augment("Some text!".to_string(), rng);
 /// augmenter.augment_batch(vec!["Some text!".to_string()], rng);
 /// ```
@@ -24,6 +45,10 @@ pub struct CharsRandomSwapAugmenter {
     word_params: TextAugmentParameters,
     /// Parameters to calculate number of chars that will be augmented in each word
     char_params: TextAugmentParameters,
+    /// Units of chars to augment, see `CharUnit`
+    char_unit: CharUnit,
+    /// Mode to select chars to swap
+    swap_mode: SwapMode,
     /// Filter, Set of words that cannot be augmented
     stopwords: Option<HashSet<String>>,
 }
@@ -37,16 +62,73 @@ impl CharsRandomSwapAugmenter {
         CharsRandomSwapAugmenter {
             word_params,
             char_params,
+            char_unit: CharUnit::Grapheme,
+            swap_mode: SwapMode::Random,
             stopwords,
         }
     }
+
+    /// Create augmenter, returns error if word or char parameters are invalid
+    pub fn try_new(
+        word_params: TextAugmentParameters,
+        char_params: TextAugmentParameters,
+        stopwords: Option<HashSet<String>>,
+    ) -> Result<Self> {
+        word_params.validate()?;
+        char_params.validate()?;
+        Ok(CharsRandomSwapAugmenter::new(word_params, char_params, stopwords))
+    }
+
+    /// Set mode to select chars to swap, random pairs by default
+    pub fn with_swap_mode(mut self, swap_mode: SwapMode) -> Self {
+        self.swap_mode = swap_mode;
+        self
+    }
+
+    /// Set units of chars to augment, extended grapheme clusters by default
+    pub fn with_char_unit(mut self, char_unit: CharUnit) -> Self {
+        self.char_unit = char_unit;
+        self
+    }
+
+    /// Swap chars of the word in place according to the swap mode
+    fn swap_chars<T>(&self, chars: &mut [T], rng: &mut dyn rand::RngCore) {
+        match self.swap_mode {
+            SwapMode::Random => self.swap_random_pairs(chars, 0..chars.len(), rng),
+            SwapMode::Middle => self.swap_random_pairs(chars, 1..chars.len().saturating_sub(1), rng),
+            SwapMode::Adjacent => {
+                // Swapped pairs must not overlap, otherwise a char moves further than its neighbour position.
+                // k non-overlapping pairs among n chars are k positions among n - k slots,
+                // each pair takes its slot and one extra char: the j-th sorted slot s starts the pair at s + j
+                let num_swaps = self.char_params.num_elements(chars.len()) / 2;
+                let mut selected_slots = self.select_random_element_indexes(
+                    rng,
+                    (0..chars.len().saturating_sub(num_swaps)).collect(),
+                    num_swaps,
+                );
+                selected_slots.sort_unstable();
+                for (num_pairs_before, slot) in selected_slots.into_iter().enumerate() {
+                    let index = slot + num_pairs_before;
+                    chars.swap(index, index + 1);
+                }
+            }
+        }
+    }
+
+    /// Select random chars in the range and swap them in pairs
+    fn swap_random_pairs<T>(&self, chars: &mut [T], range: Range<usize>, rng: &mut dyn rand::RngCore) {
+        let num_chars_to_change = self.char_params.num_elements(range.len());
+        let selected_chars_indexes = self.select_random_element_indexes(rng, range.collect(), num_chars_to_change);
+        selected_chars_indexes.chunks_exact(2).for_each(|chunk| {
+            chars.swap(chunk[0], chunk[1]);
+        });
+    }
 }
 
 impl BaseTextAugmenter for CharsRandomSwapAugmenter {}
 
 impl BaseAugmenter<String, Doc> for CharsRandomSwapAugmenter {
     fn augment_inner(&self, mut input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
-        // TODO: adjacent, middle, random swaps (now only random)
         // Select random word tokens
         let word_tokens_indexes = input.get_word_indexes(false, self.stopwords.as_ref());
         let num_tokens_to_change = self.word_params.num_elements(word_tokens_indexes.len());
@@ -56,20 +138,21 @@ impl BaseAugmenter<String, Doc> for CharsRandomSwapAugmenter {
         // For all selected tokens select random chars and swap them
         for token_index in selected_tokens_indexes {
             let token = &mut input.tokens[token_index];
-            let num_chars_to_change = self.char_params.num_elements(token.utf8_len());
-
-            let selected_chars_indexes =
-                self.select_random_element_indexes(rng, (0..token.utf8_len()).collect(), num_chars_to_change);
-            let mut chars = token.token().chars().collect::<Vec<char>>();
-            selected_chars_indexes.chunks(2).for_each(|chunk| {
-                if chunk.len() == 2 {
-                    chars.swap(chunk[0], chunk[1]);
-                }
-            });
-            let new_token = chars.iter().collect::<String>();
-            token.change(&new_token, *token.kind());
+            let mut chars = self.char_unit.split(token.token());
+            self.swap_chars(&mut chars, rng);
+            let new_token = chars.concat();
+            let old_token = token.change(&new_token, *token.kind());
 
-            input.num_changes += 1;
+            input.record_change(|tokens| {
+                Change::new(
+                    "CharsRandomSwapAugmenter",
+                    TextAction::Swap,
+                    Some(token_index),
+                    Some(token_index),
+                    &old_token,
+                    tokens[token_index].token(),
+                )
+            });
         }
 
         input
@@ -139,4 +222,104 @@ mod tests {
         assert_eq!(doc_tokens_before.len(), doc_tokens_after.len());
         assert_ne!(doc_tokens_before, doc_tokens_after);
     }
+
+    #[test_case("ABCD", 0.5, &["BACD", "ACBD", "ABDC"] ; "one adjacent swap")]
+    #[test_case("AB", 1.0, &["BA"] ; "two chars word")]
+    #[test_case("A", 1.0, &["A"] ; "single char word")]
+    fn test_swap_adjacent(word: &str, chars_p: f32, expected: &[&str]) {
+        let aug = CharsRandomSwapAugmenter::new(
+            TextAugmentParameters::new(1.0, None, None),
+            TextAugmentParameters::new(chars_p, None, None),
+            None,
+        )
+        .with_swap_mode(SwapMode::Adjacent);
+
+        let output = aug.augment(word.to_string(), &mut rand::thread_rng());
+
+        assert!(expected.contains(&output.as_str()), "unexpected output {}", output);
+    }
+
+    #[test_case("abcdefghijklmnopqrstuvwxyz", 0.5 ; "half of chars")]
+    #[test_case("abcdefghijklmnopqrstuvwxyz", 1.0 ; "all chars")]
+    #[test_case("abc", 1.0 ; "odd number of chars")]
+    fn test_swap_adjacent_moves_chars_by_one(word: &str, chars_p: f32) {
+        let aug = CharsRandomSwapAugmenter::new(
+            TextAugmentParameters::new(1.0, None, None),
+            TextAugmentParameters::new(chars_p, None, None),
+            None,
+        )
+        .with_swap_mode(SwapMode::Adjacent);
+
+        for _ in 0..100 {
+            let output = aug.augment(word.to_string(), &mut rand::thread_rng());
+
+            assert_ne!(output, word);
+            for (index, c) in output.chars().enumerate() {
+                let original_index = word.chars().position(|original| original == c).unwrap();
+                assert!(index.abs_diff(original_index) <= 1, "{} moved too far in {}", c, output);
+            }
+        }
+    }
+
+    #[test_case("Cambridge" ; "long word")]
+    #[test_case("word" ; "two middle chars")]
+    fn test_swap_middle(word: &str) {
+        let aug = CharsRandomSwapAugmenter::new(
+            TextAugmentParameters::new(1.0, None, None),
+            TextAugmentParameters::new(1.0, None, None),
+            None,
+        )
+        .with_swap_mode(SwapMode::Middle);
+
+        let output = aug.augment(word.to_string(), &mut rand::thread_rng());
+
+        let (chars, output_chars): (Vec<char>, Vec<char>) = (word.chars().collect(), output.chars().collect());
+        assert_ne!(output, word);
+        assert_eq!(output_chars.first(), chars.first());
+        assert_eq!(output_chars.last(), chars.last());
+        let (mut sorted_chars, mut sorted_output_chars) = (chars.clone(), output_chars.clone());
+        sorted_chars.sort_unstable();
+        sorted_output_chars.sort_unstable();
+        assert_eq!(sorted_output_chars, sorted_chars);
+    }
+
+    #[test_case("abc" ; "one middle char")]
+    #[test_case("ab" ; "no middle chars")]
+    fn test_swap_middle_short_words(word: &str) {
+        let aug = CharsRandomSwapAugmenter::new(
+            TextAugmentParameters::new(1.0, None, None),
+            TextAugmentParameters::new(1.0, None, None),
+            None,
+        )
+        .with_swap_mode(SwapMode::Middle);
+
+        assert_eq!(aug.augment(word.to_string(), &mut rand::thread_rng()), word);
+    }
+
+    #[test_case("नमस्ते दुनिया", CharUnit::Grapheme ; "devanagari graphemes")]
+    #[test_case("สวัสดี ชาวโลก", CharUnit::Grapheme ; "thai graphemes")]
+    #[test_case("👨‍👩‍👧x🇩🇪y", CharUnit::Grapheme ; "emoji graphemes")]
+    #[test_case("nai\u{308}ve cafe\u{301}", CharUnit::Grapheme ; "combining diacritics graphemes")]
+    #[test_case("nai\u{308}ve cafe\u{301}", CharUnit::CodePoint ; "combining diacritics code points")]
+    fn test_swap_char_units(text: &str, char_unit: CharUnit) {
+        let aug = CharsRandomSwapAugmenter::new(
+            TextAugmentParameters::new(1.0, None, None),
+            TextAugmentParameters::new(1.0, None, None),
+            None,
+        )
+        .with_char_unit(char_unit);
+
+        let doc_tokens_before = Doc::new(text).tokens;
+        let doc = aug.augment_inner(Doc::new(text), &mut rand::thread_rng());
+
+        for (token_before, token_after) in doc_tokens_before.iter().zip(doc.tokens.iter()) {
+            let (mut chars_before, mut chars_after) = (
+                char_unit.split(token_before.token()),
+                char_unit.split(token_after.token()),
+            );
+            chars_before.sort_unstable();
+            chars_after.sort_unstable();
+            assert_eq!(chars_after, chars_before);
+        }
+    }
 }
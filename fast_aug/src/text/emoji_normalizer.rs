@@ -0,0 +1,207 @@
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Change, Doc, TextAugmentParameters, Token, TokenType};
+use crate::base::BaseAugmenter;
+use crate::error::Result;
+use crate::models::text::EmojiModel;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Augmenter that replaces emoji with their text names from CLDR annotations, e.g. "😂" -> "face with tears of joy"
+/// Only emoji annotated with a name in the emoji model can be replaced.
+/// Names are separated from the neighbouring tokens with spaces, so "lol😂" becomes "lol face with tears of joy".
+/// With `TextAugmentParameters::new(1.0, None, None)` all emoji are replaced, so text is normalized.
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::models::text::EmojiModel;
+/// use fast_aug::text::{EmojiNormalizer, TextAugmentParameters};
+///
+/// let rng = &mut rand::thread_rng();
+/// let augmenter = EmojiNormalizer::new(
+///     TextAugmentParameters::new(1.0, None, None),
+///     EmojiModel::from_locale_str("en"),
+/// );
+/// assert_eq!(augmenter.augment("So hot🔥!".to_string(), rng), "So hot fire !");
+/// ```
+pub struct EmojiNormalizer {
+    /// Action to augmentation, set of values {'substitute'}
+    action: TextAction,
+    /// Parameters to calculate number of emoji that will be augmented
+    emoji_params: TextAugmentParameters,
+    /// Emoji Model with CLDR annotations
+    emoji_model: EmojiModel,
+}
+
+impl EmojiNormalizer {
+    pub fn new(emoji_params: TextAugmentParameters, emoji_model: EmojiModel) -> Self {
+        EmojiNormalizer {
+            action: TextAction::Substitute,
+            emoji_params,
+            emoji_model,
+        }
+    }
+
+    /// Create augmenter, returns error if emoji parameters are invalid
+    pub fn try_new(emoji_params: TextAugmentParameters, emoji_model: EmojiModel) -> Result<Self> {
+        emoji_params.validate()?;
+        Ok(EmojiNormalizer::new(emoji_params, emoji_model))
+    }
+
+    fn substitute(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        // Select random emoji tokens, only emoji with names can be selected
+        let emoji_tokens_indexes = doc
+            .tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| {
+                token.kind() == &TokenType::Special
+                    && !token.is_protected()
+                    && self.emoji_model.get_name(token.token()).is_some()
+            })
+            .map(|(idx, _)| idx)
+            .collect::<Vec<usize>>();
+        let num_tokens_to_change = self.emoji_params.num_elements(emoji_tokens_indexes.len());
+        let mut selected_tokens_indexes =
+            self.select_random_element_indexes(rng, emoji_tokens_indexes, num_tokens_to_change);
+        selected_tokens_indexes.sort_unstable();
+
+        // Names add tokens, so following selected tokens are shifted by the number of added tokens
+        let mut num_added_tokens = 0;
+        for selected_index in selected_tokens_indexes {
+            let index = selected_index + num_added_tokens;
+            let old_token = doc.tokens[index].token().clone();
+            let name = self.emoji_model.get_name(&old_token).unwrap();
+
+            // Name is separated with spaces from the neighbouring tokens (including names of the previous emoji)
+            let space_before = index > 0 && doc.tokens[index - 1].kind() != &TokenType::Space;
+            let space_after = index + 1 < doc.tokens.len() && doc.tokens[index + 1].kind() != &TokenType::Space;
+            let new_text = format!(
+                "{}{}{}",
+                if space_before { " " } else { "" },
+                name,
+                if space_after { " " } else { "" }
+            );
+
+            // New tokens are aligned with the original span of the replaced emoji
+            let original_span = doc.tokens[index].original_span();
+            let new_tokens = new_text
+                .split_word_bounds()
+                .map(|token| {
+                    let mut token = Token::from_str(token);
+                    if let Some((start, end)) = original_span {
+                        token.set_original_span(start, end);
+                    }
+                    token
+                })
+                .collect::<Vec<Token>>();
+            num_added_tokens += new_tokens.len() - 1;
+            doc.tokens.splice(index..=index, new_tokens);
+
+            doc.record_change(|_| {
+                Change::new(
+                    "EmojiNormalizer",
+                    TextAction::Substitute,
+                    Some(index),
+                    Some(index),
+                    &old_token,
+                    &new_text,
+                )
+            });
+        }
+
+        doc
+    }
+}
+
+impl BaseTextAugmenter for EmojiNormalizer {}
+
+impl BaseAugmenter<String, Doc> for EmojiNormalizer {
+    fn augment_inner(&self, input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        match self.action {
+            TextAction::Substitute => self.substitute(input, rng),
+            _ => panic!("Action not implemented"),
+        }
+    }
+
+    fn convert_to_inner(&self, input: String) -> Doc {
+        Doc::new(&input)
+    }
+
+    fn convert_to_outer(&self, input: Doc) -> String {
+        input.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::AugmentWithChanges;
+    use test_case::test_case;
+
+    fn augmenter(p: f32) -> EmojiNormalizer {
+        EmojiNormalizer::new(
+            TextAugmentParameters::new(p, None, None),
+            EmojiModel::from_locale_str("en"),
+        )
+    }
+
+    #[test_case("I love it 😍", "I love it smiling face with heart-eyes" ; "emoji after space")]
+    #[test_case("lol😂", "lol face with tears of joy" ; "emoji glued to word")]
+    #[test_case("😂😂 ok", "face with tears of joy face with tears of joy ok" ; "consecutive emoji")]
+    #[test_case("Great 👏🏼👏🏼", "Great clapping hands clapping hands" ; "skin tone")]
+    #[test_case("Love ❤️!", "Love red heart !" ; "presentation selector")]
+    #[test_case("No emoji :)", "No emoji :)" ; "no emoji")]
+    fn test_substitute(input: &str, expected: &str) {
+        let aug = augmenter(1.0);
+
+        let output = aug.augment(input.to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_substitute_localized() {
+        let aug = EmojiNormalizer::new(
+            TextAugmentParameters::new(1.0, None, None),
+            EmojiModel::from_locale_str("de"),
+        );
+
+        let output = aug.augment("Super 👍".to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, "Super Daumen hoch");
+    }
+
+    #[test_case(0.5, 2 ; "round 2 of 4 emoji")]
+    #[test_case(0.0, 0 ; "substitute probability=0")]
+    fn test_substitute_num_changes(p: f32, expected_doc_changes: usize) {
+        let aug = augmenter(p);
+
+        let doc = aug.augment_inner(Doc::new("a 🔥 b 😂 c 🎉 d 🍕"), &mut rand::thread_rng());
+
+        assert_eq!(doc.num_changes, expected_doc_changes);
+    }
+
+    #[test]
+    fn test_augment_with_changes() {
+        let aug = augmenter(1.0);
+
+        let (output, changes) = aug.augment_with_changes("hot🔥 🍕".to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, "hot fire pizza");
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            (changes[0].original_index, changes[0].new_text.as_str()),
+            (Some(1), " fire")
+        );
+        assert_eq!(
+            (changes[1].original_index, changes[1].new_text.as_str()),
+            (Some(4), "pizza")
+        );
+    }
+
+    #[test]
+    fn test_try_new_invalid() {
+        let params = TextAugmentParameters::new(1.5, None, None);
+        assert!(EmojiNormalizer::try_new(params, EmojiModel::from_locale_str("en")).is_err());
+    }
+}
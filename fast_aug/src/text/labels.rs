@@ -0,0 +1,404 @@
+use super::utils::{Doc, Token, TokenType};
+use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Labelled char span [start, end) of the text, e.g. entity mention for NER
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabeledSpan {
+    /// First char of the span (in chars, not bytes)
+    pub start: usize,
+    /// Char after the last char of the span
+    pub end: usize,
+    pub label: String,
+}
+
+impl LabeledSpan {
+    pub fn new(start: usize, end: usize, label: &str) -> Self {
+        LabeledSpan {
+            start,
+            end,
+            label: label.to_string(),
+        }
+    }
+
+    /// Check if the span overlaps with char span [start, end)
+    fn overlaps(&self, start: usize, end: usize) -> bool {
+        self.start < end && start < self.end
+    }
+}
+
+/// Augmenter wrapper for labelled data (NER, token classification), keeps labels aligned with augmented text
+/// Any text augmenter (or pipeline) can be wrapped, labels are remapped using original offsets of the Doc tokens,
+/// so they are kept through inserts, deletions, substitutions and swaps.
+/// Labelled tokens can be protected - excluded from augmentation, and not split by inserted words.
+///
+/// # Examples
+/// ```rust
+/// use std::sync::Arc;
+/// use fast_aug::text::{LabelPreservingAugmenter, LabeledSpan, TextAugmentParameters, WordsRandomDeleteAugmenter};
+///
+/// let rng = &mut rand::thread_rng();
+/// let augmenter = LabelPreservingAugmenter::new(
+///     Arc::new(WordsRandomDeleteAugmenter::new(TextAugmentParameters::new(1.0, None, None), None)),
+///     true,
+/// );
+///
+/// let (text, spans) = augmenter
+///     .augment_spans("I live in New York".to_string(), vec![LabeledSpan::new(10, 18, "LOC")], rng)
+///     .unwrap();
+/// assert_eq!(text, "   New York");
+/// assert_eq!(spans, vec![LabeledSpan::new(3, 11, "LOC")]);
+///
+/// let (tokens, tags) = augmenter
+///     .augment_tags(vec!["Paris".into(), "is".into(), "nice".into()], vec!["B-LOC".into(), "O".into(), "O".into()], "O", rng)
+///     .unwrap();
+/// assert_eq!(tokens, vec!["Paris"]);
+/// assert_eq!(tags, vec!["B-LOC"]);
+/// ```
+pub struct LabelPreservingAugmenter {
+    /// Text augmenter (or pipeline) to apply
+    augmenter: Arc<dyn BaseAugmenter<String, Doc> + Send + Sync>,
+    /// Exclude labelled tokens from augmentation
+    protect_labeled: bool,
+}
+
+impl LabelPreservingAugmenter {
+    pub fn new(augmenter: Arc<dyn BaseAugmenter<String, Doc> + Send + Sync>, protect_labeled: bool) -> Self {
+        LabelPreservingAugmenter {
+            augmenter,
+            protect_labeled,
+        }
+    }
+
+    /// Augment text and remap labelled char spans to the augmented text
+    /// New span covers all non-space tokens originating from the span (and words inserted between them),
+    /// unchanged tokens on the span borders are cut to the original span boundaries.
+    /// Spans with all tokens deleted are dropped. Returns error if any span is out of the text bounds.
+    ///
+    /// # Arguments
+    /// * `text` - Text to augment
+    /// * `spans` - Labelled char spans of the text
+    /// * `rng` - Random number generator
+    pub fn augment_spans(
+        &self,
+        text: String,
+        spans: Vec<LabeledSpan>,
+        rng: &mut dyn rand::RngCore,
+    ) -> Result<(String, Vec<LabeledSpan>)> {
+        let text_len = text.chars().count();
+        if let Some(span) = spans.iter().find(|span| span.start > span.end || span.end > text_len) {
+            return Err(Error::InvalidSpan {
+                start: span.start,
+                end: span.end,
+                text_len,
+            });
+        }
+
+        let (doc, original_tokens) = self.augment_doc(text, &spans, rng);
+
+        let output_spans = Self::output_spans(&doc);
+        let new_spans = spans
+            .into_iter()
+            .filter_map(|span| {
+                let mut new_span: Option<(usize, usize)> = None;
+                for (token, &(output_start, output_end)) in doc.tokens.iter().zip(output_spans.iter()) {
+                    // Spaces are skipped, so spans do not start or end with spaces and are dropped if only they remain
+                    let (start, end) = match (token.original_span(), token.kind()) {
+                        (_, TokenType::Space | TokenType::Deleted) => continue,
+                        (Some((start, end)), _) if span.overlaps(start, end) => (start, end),
+                        _ => continue,
+                    };
+                    // Unchanged tokens are mapped char-to-char, changed ones are taken as a whole
+                    let (new_start, new_end) = if Self::is_unchanged(token, &original_tokens) {
+                        (
+                            output_start + span.start.saturating_sub(start),
+                            output_end - end.saturating_sub(span.end),
+                        )
+                    } else {
+                        (output_start, output_end)
+                    };
+                    new_span = Some(match new_span {
+                        Some((min_start, max_end)) => (min_start.min(new_start), max_end.max(new_end)),
+                        None => (new_start, new_end),
+                    });
+                }
+                new_span.map(|(start, end)| LabeledSpan::new(start, end, &span.label))
+            })
+            .collect();
+
+        Ok((self.augmenter.convert_to_outer(doc), new_spans))
+    }
+
+    /// Augment pre-tokenized text with per-token tags (e.g. BIO tags for NER), returns augmented tokens and tags
+    /// Tokens are joined with spaces for augmentation and augmented text is split back on spaces.
+    /// Each output token gets the tag of the input token it originates from, inserted words get `outside_tag`.
+    /// Returns error if number of tags does not match number of tokens.
+    ///
+    /// # Arguments
+    /// * `tokens` - Input tokens (words)
+    /// * `tags` - Tag of each token
+    /// * `outside_tag` - Tag of not labelled tokens, e.g. "O"; only other tags are protected
+    /// * `rng` - Random number generator
+    pub fn augment_tags(
+        &self,
+        tokens: Vec<String>,
+        tags: Vec<String>,
+        outside_tag: &str,
+        rng: &mut dyn rand::RngCore,
+    ) -> Result<(Vec<String>, Vec<String>)> {
+        if tokens.len() != tags.len() {
+            return Err(Error::MismatchedLabels {
+                expected: tokens.len(),
+                actual: tags.len(),
+            });
+        }
+
+        // Char offset of each input token in the joined text
+        let mut token_starts = Vec::with_capacity(tokens.len());
+        let mut spans = Vec::with_capacity(tokens.len());
+        let mut offset = 0;
+        for (token, tag) in tokens.iter().zip(tags.iter()) {
+            let token_len = token.chars().count();
+            if tag != outside_tag {
+                spans.push(LabeledSpan::new(offset, offset + token_len, tag));
+            }
+            token_starts.push(offset);
+            offset += token_len + 1;
+        }
+
+        let (doc, _) = self.augment_doc(tokens.join(" "), &spans, rng);
+
+        // Split augmented doc back on spaces and on borders of tokens from different sources
+        // Source is the index of the input token, None for inserted words
+        let mut new_tokens = Vec::new();
+        let mut new_tags = Vec::new();
+        let mut current_token = String::new();
+        let mut current_source: Option<usize> = None;
+        for token in doc.tokens.iter().chain([Token::new(" ", TokenType::Space)].iter()) {
+            if token.kind() == &TokenType::Deleted {
+                continue;
+            }
+            let source = token
+                .original_span()
+                .map(|(start, _)| token_starts.partition_point(|&token_start| token_start <= start) - 1);
+            let is_border = token.kind() == &TokenType::Space || source != current_source;
+            if is_border && !current_token.is_empty() {
+                new_tokens.push(std::mem::take(&mut current_token));
+                new_tags.push(match current_source {
+                    Some(index) => tags[index].clone(),
+                    None => outside_tag.to_string(),
+                });
+            }
+            if token.kind() != &TokenType::Space {
+                current_token.push_str(token.token());
+                current_source = source;
+            }
+        }
+
+        Ok((new_tokens, new_tags))
+    }
+
+    /// Tokenize and augment the text, protecting tokens overlapping the spans if required
+    /// Returns augmented doc and original tokens (ordered by original offsets)
+    fn augment_doc(&self, text: String, spans: &[LabeledSpan], rng: &mut dyn rand::RngCore) -> (Doc, Vec<Token>) {
+        let mut doc = self.augmenter.convert_to_inner(text);
+        if self.protect_labeled {
+            for token in doc.tokens.iter_mut() {
+                if let Some((start, end)) = token.original_span() {
+                    if spans.iter().any(|span| span.overlaps(start, end)) {
+                        token.set_protected(true);
+                    }
+                }
+            }
+        }
+        let original_tokens = doc.tokens.clone();
+        (self.augmenter.augment_inner(doc, rng), original_tokens)
+    }
+
+    /// Char spans of the doc tokens in the augmented text
+    fn output_spans(doc: &Doc) -> Vec<(usize, usize)> {
+        let mut offset = 0;
+        doc.tokens
+            .iter()
+            .map(|token| {
+                offset += token.utf8_len();
+                (offset - token.utf8_len(), offset)
+            })
+            .collect()
+    }
+
+    /// Check if the token content is the same as the original token with the same offset
+    fn is_unchanged(token: &Token, original_tokens: &[Token]) -> bool {
+        let start = token.original_span().map(|(start, _)| start);
+        original_tokens
+            .binary_search_by_key(&start, |original_token| {
+                original_token.original_span().map(|(start, _)| start)
+            })
+            .is_ok_and(|index| original_tokens[index].token() == token.token())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flow::SequentialAugmenter;
+    use crate::text::{
+        TextAugmentParameters, WordsRandomDeleteAugmenter, WordsRandomInsertAugmenter, WordsRandomSubstituteAugmenter,
+        WordsRandomSwapAugmenter,
+    };
+    use test_case::test_case;
+
+    type TextAugmenter = Arc<dyn BaseAugmenter<String, Doc> + Send + Sync>;
+
+    const TEXT: &str = "Yesterday John Smith flew from New York to Berlin with his friend";
+
+    fn params(p: f32) -> TextAugmentParameters {
+        TextAugmentParameters::new(p, None, None)
+    }
+
+    fn entity_spans() -> Vec<LabeledSpan> {
+        vec![
+            LabeledSpan::new(10, 20, "PER"),
+            LabeledSpan::new(31, 39, "LOC"),
+            LabeledSpan::new(43, 49, "LOC"),
+        ]
+    }
+
+    fn span_text(text: &str, span: &LabeledSpan) -> String {
+        text.chars().skip(span.start).take(span.end - span.start).collect()
+    }
+
+    #[test_case(Arc::new(WordsRandomDeleteAugmenter::new(params(0.5), None)) ; "delete")]
+    #[test_case(Arc::new(WordsRandomInsertAugmenter::new(params(0.5), vec!["new".to_string()], None)) ; "insert")]
+    #[test_case(Arc::new(WordsRandomSwapAugmenter::new(params(0.5), None)) ; "swap")]
+    #[test_case(Arc::new(WordsRandomSubstituteAugmenter::new(params(0.5), vec!["new".to_string()], None)) ; "substitute")]
+    #[test_case(Arc::new(SequentialAugmenter::new(vec![
+        Arc::new(WordsRandomInsertAugmenter::new(params(0.3), vec!["new".to_string()], None)) as TextAugmenter,
+        Arc::new(WordsRandomDeleteAugmenter::new(params(0.3), None)),
+        Arc::new(WordsRandomSwapAugmenter::new(params(0.3), None)),
+    ])) ; "pipeline")]
+    fn test_augment_spans_protected(augmenter: TextAugmenter) {
+        let augmenter = LabelPreservingAugmenter::new(augmenter, true);
+
+        for _ in 0..20 {
+            let (text, spans) = augmenter
+                .augment_spans(TEXT.to_string(), entity_spans(), &mut rand::thread_rng())
+                .unwrap();
+
+            let span_texts = spans.iter().map(|span| span_text(&text, span)).collect::<Vec<String>>();
+            assert_eq!(span_texts, vec!["John Smith", "New York", "Berlin"]);
+        }
+    }
+
+    #[test]
+    fn test_augment_spans_not_protected_deleted() {
+        let augmenter =
+            LabelPreservingAugmenter::new(Arc::new(WordsRandomDeleteAugmenter::new(params(1.0), None)), false);
+
+        let (text, spans) = augmenter
+            .augment_spans(TEXT.to_string(), entity_spans(), &mut rand::thread_rng())
+            .unwrap();
+
+        assert!(text.trim().is_empty());
+        assert!(spans.is_empty());
+    }
+
+    #[test]
+    fn test_augment_spans_not_protected_substituted() {
+        let augmenter = LabelPreservingAugmenter::new(
+            Arc::new(WordsRandomSubstituteAugmenter::new(
+                params(1.0),
+                vec!["xyz".to_string()],
+                None,
+            )),
+            false,
+        );
+
+        let (text, spans) = augmenter
+            .augment_spans(
+                "Hi John".to_string(),
+                vec![LabeledSpan::new(3, 7, "PER")],
+                &mut rand::thread_rng(),
+            )
+            .unwrap();
+
+        assert_eq!(text, "Xyz Xyz");
+        assert_eq!(spans, vec![LabeledSpan::new(4, 7, "PER")]);
+    }
+
+    #[test]
+    fn test_augment_spans_inside_token() {
+        let augmenter =
+            LabelPreservingAugmenter::new(Arc::new(WordsRandomDeleteAugmenter::new(params(0.0), None)), false);
+
+        let (text, spans) = augmenter
+            .augment_spans(
+                "Hi Johnny".to_string(),
+                vec![LabeledSpan::new(3, 7, "PER")],
+                &mut rand::thread_rng(),
+            )
+            .unwrap();
+
+        assert_eq!(span_text(&text, &spans[0]), "John");
+    }
+
+    #[test_case(vec![LabeledSpan::new(3, 100, "PER")] ; "out of bounds")]
+    #[test_case(vec![LabeledSpan::new(5, 3, "PER")] ; "start after end")]
+    fn test_augment_spans_invalid(spans: Vec<LabeledSpan>) {
+        let augmenter =
+            LabelPreservingAugmenter::new(Arc::new(WordsRandomDeleteAugmenter::new(params(0.5), None)), false);
+
+        let result = augmenter.augment_spans("Hi John".to_string(), spans, &mut rand::thread_rng());
+
+        assert!(matches!(result, Err(Error::InvalidSpan { .. })));
+    }
+
+    #[test_case(Arc::new(WordsRandomDeleteAugmenter::new(params(0.5), None)) ; "delete")]
+    #[test_case(Arc::new(WordsRandomInsertAugmenter::new(params(0.5), vec!["new".to_string()], None)) ; "insert")]
+    #[test_case(Arc::new(WordsRandomSwapAugmenter::new(params(0.5), None)) ; "swap")]
+    fn test_augment_tags_protected(augmenter: TextAugmenter) {
+        let augmenter = LabelPreservingAugmenter::new(augmenter, true);
+        let tokens = ["John", "Smith", "lives", "in", "Berlin", "now", "."];
+        let tags = ["B-PER", "I-PER", "O", "O", "B-LOC", "O", "O"];
+
+        for _ in 0..20 {
+            let (new_tokens, new_tags) = augmenter
+                .augment_tags(
+                    tokens.iter().map(|token| token.to_string()).collect(),
+                    tags.iter().map(|tag| tag.to_string()).collect(),
+                    "O",
+                    &mut rand::thread_rng(),
+                )
+                .unwrap();
+
+            assert_eq!(new_tokens.len(), new_tags.len());
+            let entities = new_tokens
+                .iter()
+                .zip(new_tags.iter())
+                .filter(|(_, tag)| tag.as_str() != "O")
+                .map(|(token, tag)| format!("{}/{}", token, tag))
+                .collect::<Vec<String>>();
+            assert_eq!(entities, vec!["John/B-PER", "Smith/I-PER", "Berlin/B-LOC"]);
+        }
+    }
+
+    #[test]
+    fn test_augment_tags_mismatched() {
+        let augmenter =
+            LabelPreservingAugmenter::new(Arc::new(WordsRandomDeleteAugmenter::new(params(0.5), None)), true);
+
+        let result = augmenter.augment_tags(
+            vec!["John".to_string(), "lives".to_string()],
+            vec!["B-PER".to_string()],
+            "O",
+            &mut rand::thread_rng(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::MismatchedLabels { expected: 2, actual: 1 })
+        ));
+    }
+}
@@ -1,21 +1,53 @@
 mod base;
+mod chars_keyboard;
 mod chars_random_delete;
 mod chars_random_insert;
 mod chars_random_substitute;
 mod chars_random_swap;
+mod emoji_normalizer;
+mod labels;
+mod paraphrase;
+mod pos;
+mod punctuation;
+mod sentences_random_crop;
+mod sentences_random_delete;
+mod sentences_random_swap;
 mod utils;
+mod whitespace;
+mod words_contextual;
+mod words_embeddings;
+mod words_emoji;
 mod words_random_delete;
 mod words_random_insert;
 mod words_random_substitute;
 mod words_random_swap;
+mod words_spelling;
+mod words_synonym;
+mod words_tf_idf;
 
-pub use base::BaseTextAugmenter;
+pub use base::{AugmentWithChanges, BaseTextAugmenter, TextAction};
+pub use chars_keyboard::CharsKeyboardAugmenter;
 pub use chars_random_delete::CharsRandomDeleteAugmenter;
 pub use chars_random_insert::CharsRandomInsertAugmenter;
 pub use chars_random_substitute::CharsRandomSubstituteAugmenter;
-pub use chars_random_swap::CharsRandomSwapAugmenter;
-pub use utils::{Doc, TextAugmentParameters, Token, TokenType};
+pub use chars_random_swap::{CharsRandomSwapAugmenter, SwapMode};
+pub use emoji_normalizer::EmojiNormalizer;
+pub use labels::{LabelPreservingAugmenter, LabeledSpan};
+pub use paraphrase::ParaphraseAugmenter;
+pub use pos::RandomPosAugmenter;
+pub use punctuation::PunctuationAugmenter;
+pub use sentences_random_crop::SentencesRandomCropAugmenter;
+pub use sentences_random_delete::SentencesRandomDeleteAugmenter;
+pub use sentences_random_swap::SentencesRandomSwapAugmenter;
+pub use utils::{Casing, Change, CharUnit, Doc, PosFilter, TextAugmentParameters, Token, TokenType};
+pub use whitespace::{WhitespaceAugmenter, WhitespaceMode};
+pub use words_contextual::ContextualWordsAugmenter;
+pub use words_embeddings::{EmbeddingsFormat, EmbeddingsWordsAugmenter};
+pub use words_emoji::WordsEmojiAugmenter;
 pub use words_random_delete::WordsRandomDeleteAugmenter;
 pub use words_random_insert::WordsRandomInsertAugmenter;
 pub use words_random_substitute::WordsRandomSubstituteAugmenter;
 pub use words_random_swap::WordsRandomSwapAugmenter;
+pub use words_spelling::WordsSpellingAugmenter;
+pub use words_synonym::WordsSynonymAugmenter;
+pub use words_tf_idf::WordsTfIdfAugmenter;
@@ -0,0 +1,231 @@
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Change, Doc, Token};
+use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
+use crate::models::text::Seq2SeqModel;
+use std::sync::Arc;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Augmenter that paraphrases the whole text with seq2seq model (paraphrasing model or back-translation)
+/// `augment_batch` passes texts in chunks of `batch_size` to the model, texts of a failed chunk are returned unchanged,
+/// use `try_augment_batch` to get the error.
+///
+/// Batching is a part of the `Seq2SeqModel` trait, models may generate a chunk at once.
+/// `CandleSeq2SeqModel` generates texts of a chunk one by one while holding a lock on the network,
+/// so neither batches nor `augment_batch_par` speed it up.
+///
+/// # Examples
+/// ```rust
+/// use std::sync::Arc;
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::models::text::Seq2SeqModel;
+/// use fast_aug::text::ParaphraseAugmenter;
+///
+/// struct Shout;
+/// impl Seq2SeqModel for Shout {
+///     fn generate(&self, inputs: &[String], _rng: &mut dyn rand::RngCore) -> fast_aug::Result<Vec<String>> {
+///         Ok(inputs.iter().map(|text| text.to_uppercase()).collect())
+///     }
+/// }
+///
+/// let rng = &mut rand::thread_rng();
+/// let augmenter = ParaphraseAugmenter::new(Arc::new(Shout), 16);
+/// assert_eq!(augmenter.augment("Some text!".to_string(), rng), "SOME TEXT!");
+/// assert_eq!(augmenter.augment_batch(vec!["Some text!".to_string()], rng), vec!["SOME TEXT!".to_string()]);
+/// ```
+pub struct ParaphraseAugmenter {
+    /// Model to generate paraphrases with
+    model: Arc<dyn Seq2SeqModel>,
+    /// Maximum number of texts passed to the model at once, at least 1
+    batch_size: usize,
+}
+
+impl ParaphraseAugmenter {
+    pub fn new(model: Arc<dyn Seq2SeqModel>, batch_size: usize) -> Self {
+        ParaphraseAugmenter {
+            model,
+            batch_size: batch_size.max(1),
+        }
+    }
+
+    /// Paraphrase inputs in batches, returns error if the model fails or returns wrong number of texts
+    pub fn try_augment_batch(&self, inputs: Vec<String>, rng: &mut dyn rand::RngCore) -> Result<Vec<String>> {
+        let mut outputs = Vec::with_capacity(inputs.len());
+        for batch in inputs.chunks(self.batch_size) {
+            let generated = self.model.generate(batch, rng)?;
+            if generated.len() != batch.len() {
+                return Err(Error::Model(format!(
+                    "expected {} generated texts, got {}",
+                    batch.len(),
+                    generated.len()
+                )));
+            }
+            outputs.extend(generated);
+        }
+        Ok(outputs)
+    }
+}
+
+impl BaseTextAugmenter for ParaphraseAugmenter {}
+
+impl BaseAugmenter<String, Doc> for ParaphraseAugmenter {
+    fn augment_batch(&self, inputs: Vec<String>, rng: &mut dyn rand::RngCore) -> Vec<String> {
+        let mut outputs = Vec::with_capacity(inputs.len());
+        for batch in inputs.chunks(self.batch_size) {
+            match self.try_augment_batch(batch.to_vec(), rng) {
+                Ok(generated) => outputs.extend(generated),
+                Err(_) => outputs.extend_from_slice(batch),
+            }
+        }
+        outputs
+    }
+
+    fn augment_inner(&self, mut input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        let old_text = input.to_string();
+        let new_text = match self.try_augment_batch(vec![old_text.clone()], rng) {
+            Ok(mut generated) => generated.pop().unwrap(),
+            Err(_) => return input,
+        };
+        if new_text == old_text {
+            return input;
+        }
+
+        // Paraphrase is not aligned with the original text, so new tokens have no original spans
+        input.tokens = new_text.split_word_bounds().map(Token::from_str).collect();
+        input.record_change(|_| {
+            Change::new(
+                "ParaphraseAugmenter",
+                TextAction::Substitute,
+                None,
+                None,
+                &old_text,
+                &new_text,
+            )
+        });
+
+        input
+    }
+
+    fn convert_to_inner(&self, input: String) -> Doc {
+        Doc::new(&input)
+    }
+
+    fn convert_to_outer(&self, input: Doc) -> String {
+        input.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::AugmentWithChanges;
+    use std::sync::Mutex;
+    use test_case::test_case;
+
+    /// Mock model reversing words and remembering sizes of the batches, fails on texts with "!"
+    struct MockModel {
+        batch_sizes: Mutex<Vec<usize>>,
+        fail: bool,
+    }
+
+    impl MockModel {
+        fn new(fail: bool) -> Self {
+            MockModel {
+                batch_sizes: Mutex::new(Vec::new()),
+                fail,
+            }
+        }
+    }
+
+    impl Seq2SeqModel for MockModel {
+        fn generate(&self, inputs: &[String], _rng: &mut dyn rand::RngCore) -> Result<Vec<String>> {
+            self.batch_sizes.lock().unwrap().push(inputs.len());
+            if self.fail || inputs.iter().any(|text| text.contains('!')) {
+                return Err(Error::Model("mock failure".to_string()));
+            }
+            Ok(inputs
+                .iter()
+                .map(|text| text.split(' ').rev().collect::<Vec<&str>>().join(" "))
+                .collect())
+        }
+    }
+
+    #[test_case(5, 2, vec![2, 2, 1] ; "last batch smaller")]
+    #[test_case(4, 4, vec![4] ; "single batch")]
+    #[test_case(3, 0, vec![1, 1, 1] ; "zero batch size as 1")]
+    fn test_try_augment_batch(num_inputs: usize, batch_size: usize, expected_batch_sizes: Vec<usize>) {
+        let model = Arc::new(MockModel::new(false));
+        let augmenter = ParaphraseAugmenter::new(model.clone(), batch_size);
+        let inputs = (0..num_inputs).map(|i| format!("text {}", i)).collect::<Vec<String>>();
+
+        let outputs = augmenter.try_augment_batch(inputs, &mut rand::thread_rng()).unwrap();
+
+        assert_eq!(
+            outputs,
+            (0..num_inputs).map(|i| format!("{} text", i)).collect::<Vec<String>>()
+        );
+        assert_eq!(*model.batch_sizes.lock().unwrap(), expected_batch_sizes);
+    }
+
+    #[test_case(5, 2, vec![2, 2, 1] ; "last batch smaller")]
+    #[test_case(3, 0, vec![1, 1, 1] ; "zero batch size as 1")]
+    fn test_augment_batch(num_inputs: usize, batch_size: usize, expected_batch_sizes: Vec<usize>) {
+        let model = Arc::new(MockModel::new(false));
+        let augmenter = ParaphraseAugmenter::new(model.clone(), batch_size);
+        let inputs = (0..num_inputs).map(|i| format!("text {}", i)).collect::<Vec<String>>();
+
+        let outputs = augmenter.augment_batch(inputs, &mut rand::thread_rng());
+
+        assert_eq!(
+            outputs,
+            (0..num_inputs).map(|i| format!("{} text", i)).collect::<Vec<String>>()
+        );
+        assert_eq!(*model.batch_sizes.lock().unwrap(), expected_batch_sizes);
+    }
+
+    #[test]
+    fn test_augment_batch_failed_chunk() {
+        let augmenter = ParaphraseAugmenter::new(Arc::new(MockModel::new(false)), 2);
+        let inputs = ["a b", "c d!", "e f"].map(String::from).to_vec();
+
+        let outputs = augmenter.augment_batch(inputs, &mut rand::thread_rng());
+
+        assert_eq!(outputs, ["a b", "c d!", "f e"].map(String::from).to_vec());
+    }
+
+    #[test]
+    fn test_augment_model_failure() {
+        let augmenter = ParaphraseAugmenter::new(Arc::new(MockModel::new(true)), 2);
+        let inputs = vec!["a b".to_string(), "c d".to_string(), "e f".to_string()];
+
+        assert_eq!(augmenter.augment("a b".to_string(), &mut rand::thread_rng()), "a b");
+        assert_eq!(augmenter.augment_batch(inputs.clone(), &mut rand::thread_rng()), inputs);
+        assert!(matches!(
+            augmenter.try_augment_batch(inputs, &mut rand::thread_rng()),
+            Err(Error::Model(_))
+        ));
+    }
+
+    #[test]
+    fn test_augment_with_changes() {
+        let augmenter = ParaphraseAugmenter::new(Arc::new(MockModel::new(false)), 1);
+
+        let (output, changes) = augmenter.augment_with_changes("quick fox".to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, "fox quick");
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].operation, TextAction::Substitute);
+        assert_eq!(changes[0].old_text, "quick fox");
+        assert_eq!(changes[0].new_text, "fox quick");
+    }
+
+    #[test]
+    fn test_augment_unchanged() {
+        let augmenter = ParaphraseAugmenter::new(Arc::new(MockModel::new(false)), 1);
+
+        let doc = augmenter.augment_inner(Doc::new("single"), &mut rand::thread_rng());
+
+        assert_eq!(doc.num_changes, 0);
+        assert_eq!(doc.tokens[0].original_span(), Some((0, 6)));
+    }
+}
@@ -0,0 +1,139 @@
+use super::utils::{Doc, PosFilter};
+use crate::base::BaseAugmenter;
+use crate::models::text::PosTagger;
+use std::sync::Arc;
+
+/// Augmenter wrapper restricting word augmenters (or pipeline) to words of certain parts of speech
+/// Doc is tagged with the POS tagger before augmentation, so tags are also available for the wrapped augmenter
+/// (e.g. `WordsRandomSubstituteAugmenter` picks vocabulary words of the same POS).
+/// Only words matching the POS filter are selected, e.g. only adjectives are substituted or verbs are never deleted.
+///
+/// # Examples
+/// ```rust
+/// use std::collections::HashSet;
+/// use std::sync::Arc;
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::models::text::PosTagger;
+/// use fast_aug::text::{PosFilter, RandomPosAugmenter, TextAugmentParameters, WordsRandomDeleteAugmenter};
+///
+/// struct SuffixTagger;
+/// impl PosTagger for SuffixTagger {
+///     fn tag(&self, words: &[&str]) -> Vec<String> {
+///         words.iter().map(|word| if word.ends_with("ly") { "RB" } else { "NN" }.to_string()).collect()
+///     }
+/// }
+///
+/// let rng = &mut rand::thread_rng();
+/// let augmenter = RandomPosAugmenter::new(
+///     Arc::new(WordsRandomDeleteAugmenter::new(TextAugmentParameters::new(1.0, None, None), None)),
+///     Arc::new(SuffixTagger),
+///     PosFilter::new(Some(HashSet::from(["RB".to_string()])), None),
+/// );
+/// assert_eq!(augmenter.augment("Dogs run quickly".to_string(), rng), "Dogs run ");
+/// ```
+pub struct RandomPosAugmenter {
+    /// Text augmenter (or pipeline) to apply
+    augmenter: Arc<dyn BaseAugmenter<String, Doc> + Send + Sync>,
+    /// POS tagger to tag the Doc before augmentation
+    tagger: Arc<dyn PosTagger>,
+    /// Filter of words that can be augmented
+    pos_filter: PosFilter,
+}
+
+impl RandomPosAugmenter {
+    pub fn new(
+        augmenter: Arc<dyn BaseAugmenter<String, Doc> + Send + Sync>,
+        tagger: Arc<dyn PosTagger>,
+        pos_filter: PosFilter,
+    ) -> Self {
+        RandomPosAugmenter {
+            augmenter,
+            tagger,
+            pos_filter,
+        }
+    }
+}
+
+impl BaseAugmenter<String, Doc> for RandomPosAugmenter {
+    fn augment_inner(&self, mut input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        // Doc is re-tagged, as previous augmenters of the pipeline could change the words
+        input.tag_pos(self.tagger.as_ref());
+        let previous_pos_filter = input.set_pos_filter(Some(self.pos_filter.clone()));
+        let mut output = self.augmenter.augment_inner(input, rng);
+        output.set_pos_filter(previous_pos_filter);
+        output
+    }
+
+    fn convert_to_inner(&self, input: String) -> Doc {
+        self.augmenter.convert_to_inner(input)
+    }
+
+    fn convert_to_outer(&self, input: Doc) -> String {
+        self.augmenter.convert_to_outer(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::{TextAugmentParameters, WordsRandomDeleteAugmenter, WordsRandomSwapAugmenter};
+    use std::collections::HashSet;
+    use test_case::test_case;
+
+    /// Tags words ending with "ly" as adverbs, words ending with "s" as verbs, other words as nouns
+    struct SuffixTagger;
+
+    impl PosTagger for SuffixTagger {
+        fn tag(&self, words: &[&str]) -> Vec<String> {
+            words
+                .iter()
+                .map(|word| match word {
+                    word if word.ends_with("ly") => "RB",
+                    word if word.ends_with('s') => "VBZ",
+                    _ => "NN",
+                })
+                .map(String::from)
+                .collect()
+        }
+    }
+
+    fn classes(classes: &[&str]) -> Option<HashSet<String>> {
+        Some(classes.iter().map(|class| class.to_string()).collect())
+    }
+
+    fn delete_all() -> Arc<dyn BaseAugmenter<String, Doc> + Send + Sync> {
+        Arc::new(WordsRandomDeleteAugmenter::new(
+            TextAugmentParameters::new(1.0, None, None),
+            None,
+        ))
+    }
+
+    #[test_case(classes(&["NN"]), None, " runs quickly" ; "delete only nouns")]
+    #[test_case(None, classes(&["VB"]), " runs " ; "never delete verbs")]
+    #[test_case(classes(&["RB", "VB"]), classes(&["VBZ"]), "dog runs " ; "exclude wins over include")]
+    #[test_case(None, None, "  " ; "no filter")]
+    fn test_augment(include: Option<HashSet<String>>, exclude: Option<HashSet<String>>, expected: &str) {
+        let augmenter = RandomPosAugmenter::new(delete_all(), Arc::new(SuffixTagger), PosFilter::new(include, exclude));
+
+        let output = augmenter.augment("dog runs quickly".to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_augment_restores_pos_filter() {
+        let augmenter = RandomPosAugmenter::new(
+            Arc::new(WordsRandomSwapAugmenter::new(
+                TextAugmentParameters::new(1.0, None, None),
+                None,
+            )),
+            Arc::new(SuffixTagger),
+            PosFilter::new(classes(&["RB"]), None),
+        );
+
+        let mut doc = augmenter.augment_inner(Doc::new("dog runs quickly"), &mut rand::thread_rng());
+
+        assert_eq!(doc.get_word_indexes(false, None), vec![0, 2, 4]);
+        assert!(doc.tokens.iter().any(|token| token.pos() == Some("RB")));
+    }
+}
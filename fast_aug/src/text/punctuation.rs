@@ -0,0 +1,315 @@
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Change, Doc, TextAugmentParameters, Token, TokenType};
+use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
+use crate::models::text::AlphabetModel;
+use icu_properties::sets;
+use rand::seq::SliceRandom;
+
+/// Punctuation marks inserted by AEDA, used if the locale has no terminal punctuation exemplars
+const AEDA_PUNCTUATION_MARKS: [char; 6] = ['.', ';', '?', ':', '!', ','];
+
+/// Augmenter that inserts, deletes or substitutes punctuation marks
+/// * `TextAction::Insert` - insert random marks with a space before random words,
+///   as in [AEDA](https://arxiv.org/abs/2108.13230) (e.g. "I like it" -> "I ; like it")
+/// * `TextAction::Delete` - delete punctuation tokens (e.g. "Yes, I do." -> "Yes I do")
+/// * `TextAction::Substitute` - replace punctuation tokens with other marks (e.g. "Yes, I do." -> "Yes; I do!")
+///
+/// Marks are the locale punctuation exemplars separating clauses and sentences (Unicode Terminal_Punctuation),
+/// e.g. ". , ; : ! ?" for "en" and "。 、 ！ ？" for "ja". Quotes, brackets and dashes are never changed.
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::models::text::AlphabetModel;
+/// use fast_aug::text::{PunctuationAugmenter, TextAction, TextAugmentParameters};
+///
+/// let rng = &mut rand::thread_rng();
+/// let augmenter = PunctuationAugmenter::new(
+///     TextAction::Delete,
+///     TextAugmentParameters::new(1.0, None, None),
+///     AlphabetModel::from_locale_str("en"),
+/// );
+/// assert_eq!(augmenter.augment("Yes, I do.".to_string(), rng), "Yes I do");
+/// ```
+pub struct PunctuationAugmenter {
+    /// Action to augmentation, set of values {'insert', 'delete', 'substitute'}
+    action: TextAction,
+    /// Parameters to calculate number of punctuation marks that will be augmented
+    /// For insertion number of marks is calculated from the number of words
+    punctuation_params: TextAugmentParameters,
+    /// Punctuation marks of the locale, sorted to sample reproducibly with a seeded rng
+    marks: Vec<char>,
+}
+
+impl PunctuationAugmenter {
+    /// Create augmenter, action and parameters are not validated, see `try_new`
+    pub fn new(action: TextAction, punctuation_params: TextAugmentParameters, alphabet_model: AlphabetModel) -> Self {
+        let terminal_punctuation = sets::terminal_punctuation();
+        let mut marks = alphabet_model
+            .punctuation
+            .iter()
+            .copied()
+            .filter(|&c| terminal_punctuation.contains(c))
+            .collect::<Vec<char>>();
+        if marks.is_empty() {
+            marks = AEDA_PUNCTUATION_MARKS.to_vec();
+        }
+        marks.sort_unstable();
+
+        PunctuationAugmenter {
+            action,
+            punctuation_params,
+            marks,
+        }
+    }
+
+    /// Create augmenter, returns error if action is not Insert, Delete or Substitute, or parameters are invalid
+    pub fn try_new(
+        action: TextAction,
+        punctuation_params: TextAugmentParameters,
+        alphabet_model: AlphabetModel,
+    ) -> Result<Self> {
+        if action == TextAction::Swap {
+            return Err(Error::UnsupportedAction(format!(
+                "PunctuationAugmenter supports only Insert, Delete and Substitute, got {:?}",
+                action
+            )));
+        }
+        punctuation_params.validate()?;
+        Ok(Self::new(action, punctuation_params, alphabet_model))
+    }
+
+    /// Get punctuation marks used by the augmenter
+    pub fn marks(&self) -> &[char] {
+        &self.marks
+    }
+
+    /// Check if the token is a single punctuation mark of the locale
+    fn is_mark(&self, token: &Token) -> bool {
+        let mut chars = token.token().chars();
+        token.kind() == &TokenType::Special
+            && !token.is_protected()
+            && matches!((chars.next(), chars.next()), (Some(c), None) if self.marks.binary_search(&c).is_ok())
+    }
+
+    /// Select random punctuation tokens, returns indexes in ascending order
+    fn select_mark_indexes(&self, doc: &Doc, rng: &mut dyn rand::RngCore) -> Vec<usize> {
+        let mark_tokens_indexes = doc
+            .tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| self.is_mark(token))
+            .map(|(idx, _)| idx)
+            .collect::<Vec<usize>>();
+        let num_tokens_to_change = self.punctuation_params.num_elements(mark_tokens_indexes.len());
+        let mut selected_tokens_indexes =
+            self.select_random_element_indexes(rng, mark_tokens_indexes, num_tokens_to_change);
+        selected_tokens_indexes.sort_unstable();
+        selected_tokens_indexes
+    }
+
+    fn insert(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        // Marks are inserted before random words, protected words keep their original neighbours
+        let word_tokens_indexes = doc
+            .tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| token.kind() == &TokenType::Word && !token.is_protected())
+            .map(|(idx, _)| idx)
+            .collect::<Vec<usize>>();
+        let num_marks_to_insert = self.punctuation_params.num_elements(word_tokens_indexes.len());
+        let mut selected_tokens_indexes =
+            self.select_random_element_indexes(rng, word_tokens_indexes, num_marks_to_insert);
+        selected_tokens_indexes.sort_unstable();
+
+        // Mark and space are placed before each selected word, each insertion shifts the following words by 2 tokens
+        for (num_inserted_before, selected_index) in selected_tokens_indexes.into_iter().enumerate() {
+            let index = selected_index + 2 * num_inserted_before;
+            let mark = self.marks.choose(rng).unwrap().to_string();
+            doc.tokens.splice(
+                index..index,
+                [Token::new(&mark, TokenType::Special), Token::new(" ", TokenType::Space)],
+            );
+
+            doc.record_change(|tokens| {
+                Change::new(
+                    "PunctuationAugmenter",
+                    TextAction::Insert,
+                    None,
+                    Some(index),
+                    "",
+                    tokens[index].token(),
+                )
+            });
+        }
+
+        doc
+    }
+
+    fn delete(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        for index in self.select_mark_indexes(&doc, rng) {
+            let old_token = doc.tokens[index].change("", TokenType::Deleted);
+            doc.record_change(|_| {
+                Change::new(
+                    "PunctuationAugmenter",
+                    TextAction::Delete,
+                    Some(index),
+                    None,
+                    &old_token,
+                    "",
+                )
+            });
+        }
+
+        doc
+    }
+
+    fn substitute(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        // Each mark is substituted with another mark, unless it is the only mark of the locale
+        for index in self.select_mark_indexes(&doc, rng) {
+            let other_marks = self
+                .marks
+                .iter()
+                .filter(|&&mark| !doc.tokens[index].token().starts_with(mark))
+                .collect::<Vec<&char>>();
+            let Some(mark) = other_marks.choose(rng) else {
+                continue;
+            };
+            let old_token = doc.tokens[index].change(&mark.to_string(), TokenType::Special);
+
+            doc.record_change(|tokens| {
+                Change::new(
+                    "PunctuationAugmenter",
+                    TextAction::Substitute,
+                    Some(index),
+                    Some(index),
+                    &old_token,
+                    tokens[index].token(),
+                )
+            });
+        }
+
+        doc
+    }
+}
+
+impl BaseTextAugmenter for PunctuationAugmenter {}
+
+impl BaseAugmenter<String, Doc> for PunctuationAugmenter {
+    fn augment_inner(&self, input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        match self.action {
+            TextAction::Insert => self.insert(input, rng),
+            TextAction::Delete => self.delete(input, rng),
+            TextAction::Substitute => self.substitute(input, rng),
+            _ => panic!("Action not implemented"),
+        }
+    }
+
+    fn convert_to_inner(&self, input: String) -> Doc {
+        Doc::new(&input)
+    }
+
+    fn convert_to_outer(&self, input: Doc) -> String {
+        input.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::AugmentWithChanges;
+    use test_case::test_case;
+
+    fn augmenter(action: TextAction, p: f32) -> PunctuationAugmenter {
+        PunctuationAugmenter::new(
+            action,
+            TextAugmentParameters::new(p, None, None),
+            AlphabetModel::from_locale_str("en"),
+        )
+    }
+
+    #[test_case("en", &['!', ',', '.', ':', ';', '?'] ; "en")]
+    #[test_case("ja", &['!', '?', '。', '、'] ; "ja")]
+    fn test_marks(locale_str: &str, expected_marks: &[char]) {
+        let aug = PunctuationAugmenter::new(
+            TextAction::Insert,
+            TextAugmentParameters::default(),
+            AlphabetModel::from_locale_str(locale_str),
+        );
+
+        for mark in expected_marks {
+            assert!(aug.marks().contains(mark), "{} is not a mark of {}", mark, locale_str);
+        }
+        assert!(!aug.marks().contains(&'"'));
+        assert!(!aug.marks().contains(&'('));
+    }
+
+    #[test_case("I like it", 1.0, 3 ; "before every word")]
+    #[test_case("I like it", 0.5, 2 ; "round 1.5 as 2 words")]
+    #[test_case("I like it", 0.0, 0 ; "probability=0")]
+    #[test_case("", 1.0, 0 ; "empty text")]
+    fn test_insert(text: &str, p: f32, expected_doc_changes: usize) {
+        let aug = augmenter(TextAction::Insert, p);
+
+        let doc = aug.augment_inner(Doc::new(text), &mut rand::thread_rng());
+
+        assert_eq!(doc.num_changes, expected_doc_changes);
+        assert_eq!(doc.tokens.len(), Doc::new(text).tokens.len() + 2 * expected_doc_changes);
+        let output = doc.to_string();
+        let words = output
+            .split(' ')
+            .filter(|word| !word.chars().next().is_some_and(|c| aug.marks().contains(&c)));
+        assert_eq!(words.collect::<Vec<&str>>().join(" "), text);
+    }
+
+    #[test_case("Yes, I do. Do you?", "Yes I do Do you" ; "all marks")]
+    #[test_case("\"Hi\" (me) - you", "\"Hi\" (me) - you" ; "quotes, brackets and dashes are kept")]
+    #[test_case("Wait...", "Wait" ; "repeated marks")]
+    fn test_delete(text: &str, expected: &str) {
+        let aug = augmenter(TextAction::Delete, 1.0);
+
+        let output = aug.augment(text.to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_substitute() {
+        let aug = augmenter(TextAction::Substitute, 1.0);
+        let text = "Yes, I do. Do you?";
+
+        let doc = aug.augment_inner(Doc::new(text), &mut rand::thread_rng());
+
+        assert_eq!(doc.num_changes, 3);
+        for (token_before, token_after) in Doc::new(text).tokens.iter().zip(doc.tokens.iter()) {
+            if aug.is_mark(token_before) {
+                assert!(aug.is_mark(token_after));
+                assert_ne!(token_before.token(), token_after.token());
+            } else {
+                assert_eq!(token_before, token_after);
+            }
+        }
+    }
+
+    #[test]
+    fn test_augment_with_changes() {
+        let aug = augmenter(TextAction::Insert, 1.0);
+
+        let (output, changes) = aug.augment_with_changes("Hi you".to_string(), &mut rand::thread_rng());
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].new_index, Some(0));
+        assert_eq!(changes[1].new_index, Some(4));
+        assert_eq!(output.chars().nth(2), Some('H'));
+    }
+
+    #[test]
+    fn test_try_new_unsupported_action() {
+        let params = TextAugmentParameters::new(0.5, None, None);
+        assert!(matches!(
+            PunctuationAugmenter::try_new(TextAction::Swap, params, AlphabetModel::from_locale_str("en")),
+            Err(Error::UnsupportedAction(_))
+        ));
+    }
+}
@@ -0,0 +1,114 @@
+use super::base::BaseTextAugmenter;
+use super::utils::{Doc, TextAugmentParameters};
+use crate::base::BaseAugmenter;
+use crate::error::Result;
+use rand::Rng;
+
+/// Augmenter that crops text to a random window of consecutive sentences
+/// Sentences outside the window are deleted, except sentences with protected tokens (e.g. labeled spans)
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::text::{SentencesRandomCropAugmenter, TextAugmentParameters};
+///
+/// let rng = &mut rand::thread_rng();
+/// // keep 2 consecutive sentences
+/// let augmenter = SentencesRandomCropAugmenter::new(TextAugmentParameters::new(0.5, None, Some(2)));
+/// let output = augmenter.augment("First sentence. Second one! And the third? Fourth.".to_string(), rng);
+/// let windows = ["First sentence. Second one!", "Second one! And the third?", "And the third? Fourth."];
+/// assert!(windows.contains(&output.as_str()));
+/// ```
+pub struct SentencesRandomCropAugmenter {
+    /// Parameters to calculate number of sentences in the window, i.e. number of sentences to keep
+    /// At least one sentence is always kept
+    sentence_params: TextAugmentParameters,
+}
+
+impl SentencesRandomCropAugmenter {
+    pub fn new(sentence_params: TextAugmentParameters) -> Self {
+        SentencesRandomCropAugmenter { sentence_params }
+    }
+
+    /// Create augmenter, returns error if sentence parameters are invalid
+    pub fn try_new(sentence_params: TextAugmentParameters) -> Result<Self> {
+        sentence_params.validate()?;
+        Ok(SentencesRandomCropAugmenter::new(sentence_params))
+    }
+}
+
+impl BaseTextAugmenter for SentencesRandomCropAugmenter {}
+
+impl BaseAugmenter<String, Doc> for SentencesRandomCropAugmenter {
+    fn augment_inner(&self, mut input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        let sentences = input.get_sentence_ranges();
+        if sentences.is_empty() {
+            return input;
+        }
+
+        // Select random window of consecutive sentences
+        let window_size = self
+            .sentence_params
+            .num_elements(sentences.len())
+            .clamp(1, sentences.len());
+        let window_start = rng.gen_range(0..=sentences.len() - window_size);
+        let window = window_start..window_start + window_size;
+
+        let is_deleted = sentences
+            .iter()
+            .enumerate()
+            .map(|(idx, sentence)| {
+                !window.contains(&idx) && !input.tokens[sentence.clone()].iter().any(|token| token.is_protected())
+            })
+            .collect::<Vec<bool>>();
+        input.delete_sentences(&sentences, &is_deleted, "SentencesRandomCropAugmenter");
+
+        input
+    }
+
+    fn convert_to_inner(&self, input: String) -> Doc {
+        Doc::new(&input)
+    }
+
+    fn convert_to_outer(&self, input: Doc) -> String {
+        input.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("A. B. C. D. E.", 0.5, None, 3 ; "round 2.5 as 3 of 5")]
+    #[test_case("A. B. C. D. E.", 0.5, Some(2), 2 ; "max 2 of 5")]
+    #[test_case("A. B. C. D. E.", 0.0, None, 1 ; "at least 1 sentence")]
+    #[test_case("A. B. C. D. E.", 1.0, None, 5 ; "all sentences")]
+    #[test_case("Just one sentence", 0.5, None, 1 ; "single sentence")]
+    fn test_crop(input_text: &str, p: f32, max_elements: Option<usize>, expected_sentences: usize) {
+        let sentences = ["A.", "B.", "C.", "D.", "E."];
+        let aug = SentencesRandomCropAugmenter::new(TextAugmentParameters::new(p, None, max_elements));
+
+        for _ in 0..10 {
+            let doc = aug.augment_inner(Doc::new(input_text), &mut rand::thread_rng());
+            let output = doc.to_string();
+
+            assert_eq!(doc.get_sentence_ranges().len(), expected_sentences);
+            if input_text.starts_with("A.") {
+                assert!(sentences
+                    .windows(expected_sentences)
+                    .any(|window| window.join(" ") == output));
+            }
+        }
+    }
+
+    #[test]
+    fn test_crop_empty() {
+        let aug = SentencesRandomCropAugmenter::new(TextAugmentParameters::new(0.5, None, None));
+
+        let doc = aug.augment_inner(Doc::new("  "), &mut rand::thread_rng());
+
+        assert_eq!(doc.to_string(), "  ");
+        assert_eq!(doc.num_changes, 0);
+    }
+}
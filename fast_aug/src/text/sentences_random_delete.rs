@@ -0,0 +1,108 @@
+use super::base::BaseTextAugmenter;
+use super::utils::{Doc, TextAugmentParameters};
+use crate::base::BaseAugmenter;
+use crate::error::Result;
+
+/// Augmenter that deletes random sentences in text
+/// Sentences with protected tokens (e.g. labeled spans) are never deleted
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::text::{SentencesRandomDeleteAugmenter, TextAugmentParameters};
+///
+/// let rng = &mut rand::thread_rng();
+/// let augmenter = SentencesRandomDeleteAugmenter::new(TextAugmentParameters::new(0.5, None, None));
+/// augmenter.augment("First sentence. Second one! And the third?".to_string(), rng);
+/// augmenter.augment_batch(vec!["First sentence. Second one!".to_string()], rng);
+/// ```
+pub struct SentencesRandomDeleteAugmenter {
+    /// Parameters to calculate number of sentences that will be augmented
+    sentence_params: TextAugmentParameters,
+}
+
+impl SentencesRandomDeleteAugmenter {
+    pub fn new(sentence_params: TextAugmentParameters) -> Self {
+        SentencesRandomDeleteAugmenter { sentence_params }
+    }
+
+    /// Create augmenter, returns error if sentence parameters are invalid
+    pub fn try_new(sentence_params: TextAugmentParameters) -> Result<Self> {
+        sentence_params.validate()?;
+        Ok(SentencesRandomDeleteAugmenter::new(sentence_params))
+    }
+}
+
+impl BaseTextAugmenter for SentencesRandomDeleteAugmenter {}
+
+impl BaseAugmenter<String, Doc> for SentencesRandomDeleteAugmenter {
+    fn augment_inner(&self, mut input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        // Select random sentences without protected tokens
+        let sentences = input.get_sentence_ranges();
+        let sentence_indexes = (0..sentences.len())
+            .filter(|&idx| {
+                !input.tokens[sentences[idx].clone()]
+                    .iter()
+                    .any(|token| token.is_protected())
+            })
+            .collect::<Vec<usize>>();
+        let num_sentences_to_change = self.sentence_params.num_elements(sentence_indexes.len());
+        let selected_sentences_indexes =
+            self.select_random_element_indexes(rng, sentence_indexes, num_sentences_to_change);
+
+        let mut is_deleted = vec![false; sentences.len()];
+        for idx in selected_sentences_indexes {
+            is_deleted[idx] = true;
+        }
+        input.delete_sentences(&sentences, &is_deleted, "SentencesRandomDeleteAugmenter");
+
+        input
+    }
+
+    fn convert_to_inner(&self, input: String) -> Doc {
+        Doc::new(&input)
+    }
+
+    fn convert_to_outer(&self, input: Doc) -> String {
+        input.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("A. B. C. D. E.", 0.5, 3 ; "round 2.5 as 3 of 5")]
+    #[test_case("A. B. C. D.", 0.5, 2 ; "2 of 4")]
+    #[test_case("Just one sentence", 0.5, 1 ; "single sentence")]
+    #[test_case("A. B. C.", 0.0, 0 ; "delete probability=0")]
+    #[test_case("  \t ", 0.5, 0 ; "no sentences in input")]
+    fn test_delete(input_text: &str, p: f32, expected_doc_changes: usize) {
+        let doc = Doc::new(input_text);
+        let num_sentences_before = doc.get_sentence_ranges().len();
+        let aug = SentencesRandomDeleteAugmenter::new(TextAugmentParameters::new(p, None, None));
+
+        let doc = aug.augment_inner(doc, &mut rand::thread_rng());
+
+        assert_eq!(doc.num_changes, expected_doc_changes);
+        assert_eq!(
+            doc.get_sentence_ranges().len(),
+            num_sentences_before - expected_doc_changes
+        );
+        if expected_doc_changes == 0 {
+            assert_eq!(doc.to_string(), input_text);
+        }
+    }
+
+    #[test]
+    fn test_delete_keeps_protected() {
+        let mut doc = Doc::new("Keep me. Delete me.");
+        doc.tokens[0].set_protected(true);
+        let aug = SentencesRandomDeleteAugmenter::new(TextAugmentParameters::new(1.0, None, None));
+
+        let doc = aug.augment_inner(doc, &mut rand::thread_rng());
+
+        assert_eq!(doc.to_string(), "Keep me.");
+    }
+}
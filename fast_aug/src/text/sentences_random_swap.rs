@@ -0,0 +1,148 @@
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Change, Doc, TextAugmentParameters, Token};
+use crate::base::BaseAugmenter;
+use crate::error::Result;
+
+/// Augmenter that swaps random sentences in text
+/// Whitespace between sentences is kept in place, so only the order of sentences is changed
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::text::{SentencesRandomSwapAugmenter, TextAugmentParameters};
+///
+/// let rng = &mut rand::thread_rng();
+/// let augmenter = SentencesRandomSwapAugmenter::new(TextAugmentParameters::new(1.0, None, None));
+/// let output = augmenter.augment("First sentence.\nSecond one!".to_string(), rng);
+/// assert_eq!(output, "Second one!\nFirst sentence.");
+/// ```
+pub struct SentencesRandomSwapAugmenter {
+    /// Parameters to calculate number of sentences that will be augmented
+    sentence_params: TextAugmentParameters,
+}
+
+impl SentencesRandomSwapAugmenter {
+    pub fn new(sentence_params: TextAugmentParameters) -> Self {
+        SentencesRandomSwapAugmenter { sentence_params }
+    }
+
+    /// Create augmenter, returns error if sentence parameters are invalid
+    pub fn try_new(sentence_params: TextAugmentParameters) -> Result<Self> {
+        sentence_params.validate()?;
+        Ok(SentencesRandomSwapAugmenter::new(sentence_params))
+    }
+}
+
+impl BaseTextAugmenter for SentencesRandomSwapAugmenter {}
+
+impl BaseAugmenter<String, Doc> for SentencesRandomSwapAugmenter {
+    fn augment_inner(&self, mut input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        // Select random sentences (shuffle selected sentences to make swaps)
+        let sentences = input.get_sentence_ranges();
+        let num_sentences_to_change = self.sentence_params.num_elements(sentences.len());
+        let selected_sentences_indexes =
+            self.select_random_element_indexes(rng, (0..sentences.len()).collect(), num_sentences_to_change);
+
+        // Order of sentences after swaps: position -> original sentence
+        // As shuffled we can swap adjacent pairs (using chunks), if odd number of sentences, swap last with first
+        let mut order = (0..sentences.len()).collect::<Vec<usize>>();
+        for indexes in selected_sentences_indexes.chunks(2) {
+            order.swap(*indexes.first().unwrap(), *indexes.last().unwrap());
+        }
+        if !selected_sentences_indexes.len().is_multiple_of(2) {
+            order.swap(
+                *selected_sentences_indexes.last().unwrap(),
+                *selected_sentences_indexes.first().unwrap(),
+            );
+        }
+        if order.iter().enumerate().all(|(position, &idx)| position == idx) {
+            return input;
+        }
+
+        // Rebuild tokens: whitespace between sentences stays, sentences are placed in the new order
+        let tokens = std::mem::take(&mut input.tokens);
+        let mut new_tokens: Vec<Token> = Vec::with_capacity(tokens.len());
+        let mut new_starts = vec![0; sentences.len()];
+        let mut offset = 0;
+        for (position, &idx) in order.iter().enumerate() {
+            new_tokens.extend_from_slice(&tokens[offset..sentences[position].start]);
+            new_starts[idx] = new_tokens.len();
+            new_tokens.extend_from_slice(&tokens[sentences[idx].clone()]);
+            offset = sentences[position].end;
+        }
+        new_tokens.extend_from_slice(&tokens[offset..]);
+        input.tokens = new_tokens;
+
+        // One change for each moved sentence, new text is the text of the sentence taking its place
+        let sentence_text = |idx: usize| {
+            tokens[sentences[idx].clone()]
+                .iter()
+                .map(|token| token.token().as_str())
+                .collect::<String>()
+        };
+        for (position, &idx) in order.iter().enumerate().filter(|(position, &idx)| *position != idx) {
+            input.record_change(|_| {
+                Change::new(
+                    "SentencesRandomSwapAugmenter",
+                    TextAction::Swap,
+                    Some(sentences[idx].start),
+                    Some(new_starts[idx]),
+                    &sentence_text(idx),
+                    &sentence_text(position),
+                )
+            });
+        }
+
+        input
+    }
+
+    fn convert_to_inner(&self, input: String) -> Doc {
+        Doc::new(&input)
+    }
+
+    fn convert_to_outer(&self, input: Doc) -> String {
+        input.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("A. B. C. D. E.", 0.5, 3 ; "round 2.5 as 3 of 5")]
+    #[test_case("A. B. C. D.", 0.5, 2 ; "2 of 4")]
+    #[test_case("A. B.", 1.0, 2 ; "2 of 2")]
+    #[test_case("Just one sentence", 1.0, 0 ; "single sentence no swaps")]
+    #[test_case("A. B. C.", 0.0, 0 ; "swap probability=0")]
+    fn test_swap(input_text: &str, p: f32, expected_doc_changes: usize) {
+        let aug = SentencesRandomSwapAugmenter::new(TextAugmentParameters::new(p, None, None));
+
+        let doc = aug.augment_inner(Doc::new(input_text), &mut rand::thread_rng());
+        let output = doc.to_string();
+
+        assert_eq!(doc.num_changes, expected_doc_changes);
+        assert_eq!(output.len(), input_text.len());
+        let mut sentences_before = input_text.split(' ').collect::<Vec<&str>>();
+        let mut sentences_after = output.split(' ').collect::<Vec<&str>>();
+        if expected_doc_changes == 0 {
+            assert_eq!(sentences_before, sentences_after);
+        } else {
+            assert_ne!(sentences_before, sentences_after);
+        }
+        sentences_before.sort();
+        sentences_after.sort();
+        assert_eq!(sentences_before, sentences_after);
+    }
+
+    #[test]
+    fn test_swap_keeps_whitespace_and_original_spans() {
+        let aug = SentencesRandomSwapAugmenter::new(TextAugmentParameters::new(1.0, None, None));
+
+        let doc = aug.augment_inner(Doc::new("  Hello world.\n\nBye!  "), &mut rand::thread_rng());
+
+        assert_eq!(doc.to_string(), "  Bye!\n\nHello world.  ");
+        assert_eq!(doc.tokens[1].token(), "Bye");
+        assert_eq!(doc.tokens[1].original_span(), Some((16, 19)));
+    }
+}
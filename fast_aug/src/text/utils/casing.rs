@@ -0,0 +1,239 @@
+use rand::{Rng, RngCore};
+use serde::{Deserialize, Serialize};
+
+/// Casing policy of the new text written into tokens by augmenters (substituted and inserted words and chars)
+///
+/// Casing::Preserve -> Match the casing pattern of the original text: upper ("WORD"), title ("Word"), lower ("word")
+///                     or mixed ("iPhone", matched char by char)
+/// Casing::Random   -> Random casing of each char
+/// Casing::AsIs     -> Keep casing of the new text, e.g. as it is in the vocabulary or the alphabet
+///
+/// Serialized in snake_case, e.g. "as_is"
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Casing {
+    #[default]
+    Preserve,
+    Random,
+    AsIs,
+}
+
+/// Casing pattern of the text, only cased chars are taken into account
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum CasingPattern {
+    Upper,
+    Title,
+    Lower,
+    Mixed,
+    Uncased,
+}
+
+impl CasingPattern {
+    fn of(text: &str) -> Self {
+        let mut cased_chars = text.chars().filter(|c| c.is_uppercase() || c.is_lowercase());
+        let first_is_upper = match cased_chars.next() {
+            Some(first) => first.is_uppercase(),
+            None => return CasingPattern::Uncased,
+        };
+        let (mut rest_has_upper, mut rest_has_lower) = (false, false);
+        for c in cased_chars {
+            rest_has_upper |= c.is_uppercase();
+            rest_has_lower |= c.is_lowercase();
+        }
+
+        match (first_is_upper, rest_has_upper, rest_has_lower) {
+            (true, true, false) => CasingPattern::Upper,
+            (true, false, _) => CasingPattern::Title,
+            (false, false, _) => CasingPattern::Lower,
+            _ => CasingPattern::Mixed,
+        }
+    }
+}
+
+impl Casing {
+    /// Apply casing to the new text substituting the original text
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::text::Casing;
+    ///
+    /// let rng = &mut rand::thread_rng();
+    /// assert_eq!(Casing::Preserve.apply("HELLO", "world", rng), "WORLD");
+    /// assert_eq!(Casing::Preserve.apply("Paris", "london", rng), "London");
+    /// assert_eq!(Casing::Preserve.apply("iPhone", "galaxy", rng), "gAlaxy");
+    /// assert_eq!(Casing::AsIs.apply("HELLO", "world", rng), "world");
+    /// ```
+    pub fn apply(self, original: &str, new_text: &str, rng: &mut dyn RngCore) -> String {
+        match self {
+            Casing::Preserve => match CasingPattern::of(original) {
+                CasingPattern::Upper => new_text.to_uppercase(),
+                CasingPattern::Title => Self::to_title_case(new_text),
+                CasingPattern::Lower => new_text.to_lowercase(),
+                CasingPattern::Mixed => Self::to_mixed_case(original, new_text),
+                CasingPattern::Uncased => new_text.to_string(),
+            },
+            Casing::Random => Self::to_random_case(new_text, rng),
+            Casing::AsIs => new_text.to_string(),
+        }
+    }
+
+    /// Apply casing to the new text inserted next to (or into) the neighbour word
+    /// With `Casing::Preserve` the new text is upper case next to upper case words ("HELLO") and lower case otherwise
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::text::Casing;
+    ///
+    /// let rng = &mut rand::thread_rng();
+    /// assert_eq!(Casing::Preserve.apply_inserted("HELLO", "big", rng), "BIG");
+    /// assert_eq!(Casing::Preserve.apply_inserted("Paris", "Big", rng), "big");
+    /// ```
+    pub fn apply_inserted(self, neighbour: &str, new_text: &str, rng: &mut dyn RngCore) -> String {
+        match self {
+            Casing::Preserve => match CasingPattern::of(neighbour) {
+                CasingPattern::Upper => new_text.to_uppercase(),
+                CasingPattern::Uncased => new_text.to_string(),
+                _ => new_text.to_lowercase(),
+            },
+            _ => self.apply(neighbour, new_text, rng),
+        }
+    }
+
+    /// Apply casing to the new char substituting (or inserted next to) the original char
+    /// The new char is kept as is if the case mapping changes its length (e.g. "ß" -> "SS")
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::text::Casing;
+    ///
+    /// let rng = &mut rand::thread_rng();
+    /// assert_eq!(Casing::Preserve.apply_char("H", "x", rng), "X");
+    /// assert_eq!(Casing::Preserve.apply_char("H", "ß", rng), "ß");
+    /// ```
+    pub fn apply_char(self, original: &str, new_char: &str, rng: &mut dyn RngCore) -> String {
+        let cased = self.apply(original, new_char, rng);
+        if cased.chars().count() == new_char.chars().count() {
+            cased
+        } else {
+            new_char.to_string()
+        }
+    }
+
+    fn to_title_case(text: &str) -> String {
+        let mut chars = text.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+            None => String::new(),
+        }
+    }
+
+    /// Each cased char of the new text takes the case of the cased char of the original text at the same position,
+    /// chars after the end of the original text take the case of its last cased char
+    fn to_mixed_case(original: &str, text: &str) -> String {
+        let original_is_upper = original
+            .chars()
+            .filter(|c| c.is_uppercase() || c.is_lowercase())
+            .map(|c| c.is_uppercase())
+            .collect::<Vec<bool>>();
+        let mut cased_index = 0;
+        let mut new_text = String::with_capacity(text.len());
+        for c in text.chars() {
+            if !c.is_uppercase() && !c.is_lowercase() {
+                new_text.push(c);
+                continue;
+            }
+            let is_upper = original_is_upper[cased_index.min(original_is_upper.len() - 1)];
+            cased_index += 1;
+            if is_upper {
+                new_text.extend(c.to_uppercase());
+            } else {
+                new_text.extend(c.to_lowercase());
+            }
+        }
+        new_text
+    }
+
+    fn to_random_case(text: &str, rng: &mut dyn RngCore) -> String {
+        let mut new_text = String::with_capacity(text.len());
+        for c in text.chars() {
+            if !c.is_uppercase() && !c.is_lowercase() {
+                new_text.push(c);
+            } else if rng.gen_bool(0.5) {
+                new_text.extend(c.to_uppercase());
+            } else {
+                new_text.extend(c.to_lowercase());
+            }
+        }
+        new_text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("HELLO", CasingPattern::Upper ; "upper")]
+    #[test_case("Hello", CasingPattern::Title ; "title")]
+    #[test_case("I", CasingPattern::Title ; "single upper char")]
+    #[test_case("hello", CasingPattern::Lower ; "lower")]
+    #[test_case("iPhone", CasingPattern::Mixed ; "mixed lower first")]
+    #[test_case("McDonald", CasingPattern::Mixed ; "mixed upper first")]
+    #[test_case("DON'T", CasingPattern::Upper ; "upper with apostrophe")]
+    #[test_case("123", CasingPattern::Uncased ; "digits")]
+    #[test_case("假", CasingPattern::Uncased ; "chinese")]
+    fn test_casing_pattern(text: &str, expected: CasingPattern) {
+        assert_eq!(CasingPattern::of(text), expected);
+    }
+
+    #[test_case(Casing::Preserve, "HELLO", "world", "WORLD" ; "preserve upper")]
+    #[test_case(Casing::Preserve, "Hello", "wORLD", "World" ; "preserve title")]
+    #[test_case(Casing::Preserve, "hello", "World", "world" ; "preserve lower")]
+    #[test_case(Casing::Preserve, "McDonald", "burgerking", "BuRgerking" ; "preserve mixed")]
+    #[test_case(Casing::Preserve, "ÉCOLE", "straße", "STRASSE" ; "preserve upper non-english")]
+    #[test_case(Casing::Preserve, "123", "World", "World" ; "preserve uncased original")]
+    #[test_case(Casing::AsIs, "HELLO", "World", "World" ; "as is")]
+    fn test_apply(casing: Casing, original: &str, new_text: &str, expected: &str) {
+        assert_eq!(casing.apply(original, new_text, &mut rand::thread_rng()), expected);
+    }
+
+    #[test_case(Casing::Preserve, "HELLO", "Big", "BIG" ; "preserve upper")]
+    #[test_case(Casing::Preserve, "Hello", "Big", "big" ; "preserve title")]
+    #[test_case(Casing::Preserve, "iPhone", "Big", "big" ; "preserve mixed")]
+    #[test_case(Casing::Preserve, "123", "Big", "Big" ; "preserve uncased")]
+    #[test_case(Casing::AsIs, "HELLO", "Big", "Big" ; "as is")]
+    fn test_apply_inserted(casing: Casing, neighbour: &str, new_text: &str, expected: &str) {
+        assert_eq!(
+            casing.apply_inserted(neighbour, new_text, &mut rand::thread_rng()),
+            expected
+        );
+    }
+
+    #[test_case(Casing::Preserve, "H", "x", "X" ; "preserve upper")]
+    #[test_case(Casing::Preserve, "h", "X", "x" ; "preserve lower")]
+    #[test_case(Casing::Preserve, "H", "ß", "ß" ; "preserve upper expanding char")]
+    #[test_case(Casing::Preserve, "1", "X", "X" ; "preserve uncased")]
+    #[test_case(Casing::AsIs, "H", "x", "x" ; "as is")]
+    fn test_apply_char(casing: Casing, original: &str, new_char: &str, expected: &str) {
+        assert_eq!(casing.apply_char(original, new_char, &mut rand::thread_rng()), expected);
+    }
+
+    #[test]
+    fn test_apply_char_random_keeps_length() {
+        let rng = &mut rand::thread_rng();
+        for _ in 0..20 {
+            assert_eq!(Casing::Random.apply_char("a", "ß", rng), "ß");
+        }
+    }
+
+    #[test]
+    fn test_apply_random() {
+        let rng = &mut rand::thread_rng();
+        let outputs = (0..20)
+            .map(|_| Casing::Random.apply("hello", "some words!", rng))
+            .collect::<Vec<String>>();
+
+        assert!(outputs.iter().all(|output| output.to_lowercase() == "some words!"));
+        assert!(outputs.iter().any(|output| output != "some words!"));
+    }
+}
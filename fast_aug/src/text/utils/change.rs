@@ -0,0 +1,49 @@
+use crate::text::TextAction;
+use serde::{Deserialize, Serialize};
+
+/// Record of a single change made by an augmenter to the Doc tokens
+///
+/// Indexes refer to `Doc::tokens` right before and right after the change was made,
+/// so in pipelines later augmenters (e.g. inserting words) can shift them.
+/// * Insert - `original_index` is None, `new_index` is the index of the inserted token
+/// * Delete - `new_index` is None, deleted token is kept in the Doc as `TokenType::Deleted`
+/// * Substitute - both indexes point to the changed token
+/// * Swap - token moved from `original_index` to `new_index`, `new_text` is the token it was swapped with
+///
+/// Char-level augmenters change tokens in-place, so both indexes point to the changed token
+/// and `operation` is the augmenter action (e.g. Insert for inserted chars).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Change {
+    /// Name of the augmenter that made the change, e.g. "WordsRandomSwapAugmenter"
+    pub augmenter: String,
+    /// Operation performed on the token
+    pub operation: TextAction,
+    /// Index of the token before the change, None for inserted tokens
+    pub original_index: Option<usize>,
+    /// Index of the token after the change, None for deleted tokens
+    pub new_index: Option<usize>,
+    /// Token text before the change
+    pub old_text: String,
+    /// Token text after the change
+    pub new_text: String,
+}
+
+impl Change {
+    pub fn new(
+        augmenter: &str,
+        operation: TextAction,
+        original_index: Option<usize>,
+        new_index: Option<usize>,
+        old_text: &str,
+        new_text: &str,
+    ) -> Self {
+        Change {
+            augmenter: augmenter.to_string(),
+            operation,
+            original_index,
+            new_index,
+            old_text: old_text.to_string(),
+            new_text: new_text.to_string(),
+        }
+    }
+}
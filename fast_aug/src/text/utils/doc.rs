@@ -1,5 +1,10 @@
+use super::change::Change;
+use super::pos_filter::PosFilter;
 use super::token::{Token, TokenType};
+use crate::models::text::PosTagger;
+use crate::text::TextAction;
 use std::collections::HashSet;
+use std::ops::Range;
 use unicode_segmentation::UnicodeSegmentation;
 
 /// Doc struct holds content as a list of tokens.
@@ -7,6 +12,10 @@ use unicode_segmentation::UnicodeSegmentation;
 pub struct Doc {
     pub tokens: Vec<Token>,
     pub num_changes: usize,
+    /// Log of changes made by augmenters, None if changes are not tracked (default)
+    changes: Option<Vec<Change>>,
+    /// Filter of word tokens by POS tags, applied to words selected for augmentation (see `RandomPosAugmenter`)
+    pos_filter: Option<PosFilter>,
 }
 
 impl Doc {
@@ -18,7 +27,12 @@ impl Doc {
     /// * `text` - A string slice that holds the text to be tokenized.
     pub fn new(text: &str) -> Self {
         let tokens = Doc::tokenize(text);
-        Doc { tokens, num_changes: 0 }
+        Doc {
+            tokens,
+            num_changes: 0,
+            changes: None,
+            pos_filter: None,
+        }
     }
 
     /// Create a new Doc from a list of tokens.
@@ -27,18 +41,30 @@ impl Doc {
     /// # Arguments
     /// * `tokens` - A vector of string slices that holds the tokens.
     pub fn from_tokens(tokens: Vec<&str>) -> Self {
-        let tokens = tokens
-            .iter()
-            .map(|&token| Token::from_str(token))
-            .collect::<Vec<Token>>();
-        Doc { tokens, num_changes: 0 }
+        let tokens = Doc::with_original_spans(tokens.into_iter().map(Token::from_str));
+        Doc {
+            tokens,
+            num_changes: 0,
+            changes: None,
+            pos_filter: None,
+        }
     }
 
     /// Tokenize a string slice on word boundaries (words, spaces, and special symbols).
     /// Use "Unicode Standard Annex #29" https://www.unicode.org/reports/tr29/#Word_Boundaries
     fn tokenize(text: &str) -> Vec<Token> {
-        UnicodeSegmentation::split_word_bounds(text)
-            .map(Token::from_str)
+        Doc::with_original_spans(UnicodeSegmentation::split_word_bounds(text).map(Token::from_str))
+    }
+
+    /// Set original char spans of consecutive tokens of the text
+    fn with_original_spans<I: Iterator<Item = Token>>(tokens: I) -> Vec<Token> {
+        let mut offset = 0;
+        tokens
+            .map(|mut token| {
+                token.set_original_span(offset, offset + token.utf8_len());
+                offset += token.utf8_len();
+                token
+            })
             .collect()
     }
 
@@ -77,7 +103,7 @@ impl Doc {
         count
     }
 
-    /// Get only WordTokens original indexes
+    /// Get only WordTokens original indexes, protected tokens and words not matching POS filter are skipped
     ///
     /// # Arguments
     /// * `include_special_char` - Include Special tokens in count
@@ -85,7 +111,12 @@ impl Doc {
     pub fn get_word_indexes(&mut self, include_special_char: bool, stopwords: Option<&HashSet<String>>) -> Vec<usize> {
         let mut word_indexes = Vec::with_capacity(self.tokens.len());
 
-        for (idx, token) in self.tokens.iter().enumerate() {
+        for (idx, token) in self
+            .tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| !token.is_protected())
+        {
             let token_type = token.kind();
             match (token_type, include_special_char) {
                 (TokenType::Word, _) => {
@@ -94,6 +125,11 @@ impl Doc {
                             continue;
                         }
                     }
+                    if let Some(pos_filter) = &self.pos_filter {
+                        if !pos_filter.matches(token.pos()) {
+                            continue;
+                        }
+                    }
                     word_indexes.push(idx);
                 }
                 (TokenType::Special, true) => word_indexes.push(idx),
@@ -104,6 +140,149 @@ impl Doc {
         word_indexes
     }
 
+    /// Get places to insert new tokens, from 0 (before the first token) to len inclusive (after the last token)
+    /// Places between two protected tokens are skipped, so protected spans are not split
+    pub fn get_insert_indexes(&self) -> Vec<usize> {
+        (0..=self.tokens.len())
+            .filter(|&idx| {
+                idx == 0
+                    || idx == self.tokens.len()
+                    || !(self.tokens[idx - 1].is_protected() && self.tokens[idx].is_protected())
+            })
+            .collect()
+    }
+
+    /// Get sentences as ranges of token indexes, sentences are split on sentence boundaries of the current text
+    /// Use "Unicode Standard Annex #29" https://www.unicode.org/reports/tr29/#Sentence_Boundaries
+    /// Ranges are trimmed, so whitespace between sentences (and whitespace-only sentences) is not part of any sentence
+    pub fn get_sentence_ranges(&self) -> Vec<Range<usize>> {
+        let text = self.to_string();
+        let mut bounds = text
+            .split_sentence_bound_indices()
+            .map(|(start, _)| start)
+            .skip(1)
+            .peekable();
+
+        let mut ranges = Vec::new();
+        let mut current: Option<Range<usize>> = None;
+        let mut offset = 0;
+        for (idx, token) in self.tokens.iter().enumerate() {
+            if token.byte_len() == 0 {
+                continue;
+            }
+            // Non-empty token at or after the next boundary starts a new sentence
+            if bounds.peek().is_some_and(|&bound| bound <= offset) {
+                while bounds.peek().is_some_and(|&bound| bound <= offset) {
+                    bounds.next();
+                }
+                ranges.extend(current.take());
+            }
+            if token.kind() != &TokenType::Space {
+                match current.as_mut() {
+                    Some(range) => range.end = idx + 1,
+                    None => current = Some(idx..idx + 1),
+                }
+            }
+            offset += token.byte_len();
+        }
+        ranges.extend(current);
+
+        ranges
+    }
+
+    /// Delete selected sentences with whitespace separating them from the kept ones - in-place
+    /// Whitespace between two kept sentences is kept once, protected tokens are never deleted
+    /// Records one change per deleted sentence
+    ///
+    /// # Arguments
+    /// * `sentences` - Sentences ranges, see `get_sentence_ranges`
+    /// * `is_deleted` - Flag for each sentence if it should be deleted
+    /// * `augmenter` - Name of the augmenter to record changes
+    pub(crate) fn delete_sentences(&mut self, sentences: &[Range<usize>], is_deleted: &[bool], augmenter: &str) {
+        for (idx, sentence) in sentences.iter().enumerate() {
+            if is_deleted[idx] {
+                let old_text = self.tokens[sentence.clone()]
+                    .iter()
+                    .map(|token| token.token().as_str())
+                    .collect::<String>();
+                self.delete_unprotected_tokens(sentence.clone());
+                self.record_change(|_| {
+                    Change::new(augmenter, TextAction::Delete, Some(sentence.start), None, &old_text, "")
+                });
+            }
+
+            // Separator after the sentence is kept only if the sentence and some of the following ones are kept
+            if let Some(next_sentence) = sentences.get(idx + 1) {
+                if is_deleted[idx] || !is_deleted[idx + 1..].contains(&false) {
+                    self.delete_unprotected_tokens(sentence.end..next_sentence.start);
+                }
+            }
+        }
+    }
+
+    fn delete_unprotected_tokens(&mut self, range: Range<usize>) {
+        for token in self.tokens[range].iter_mut().filter(|token| !token.is_protected()) {
+            token.change("", TokenType::Deleted);
+        }
+    }
+
+    /// Start recording changes made by augmenters, see `record_change`
+    pub fn track_changes(&mut self) {
+        if self.changes.is_none() {
+            self.changes = Some(Vec::new());
+        }
+    }
+
+    /// Recorded changes, None if changes are not tracked
+    pub fn changes(&self) -> Option<&[Change]> {
+        self.changes.as_deref()
+    }
+
+    /// Take recorded changes out of the Doc, empty if changes are not tracked
+    pub fn take_changes(&mut self) -> Vec<Change> {
+        self.changes.as_mut().map(std::mem::take).unwrap_or_default()
+    }
+
+    /// Count the change made by augmenter and record it if changes are tracked
+    /// The change is built lazily from the tokens after the change, so untracked Docs do not pay for it
+    ///
+    /// # Arguments
+    /// * `change` - Function to build the change given the Doc tokens after the change
+    pub fn record_change<F: FnOnce(&[Token]) -> Change>(&mut self, change: F) {
+        self.num_changes += 1;
+        if let Some(changes) = self.changes.as_mut() {
+            changes.push(change(&self.tokens));
+        }
+    }
+
+    /// Tag words and special tokens with part-of-speech tags, spaces and deleted tokens are not tagged
+    /// Whole text is tagged as a single sentence, previous tags are overwritten
+    ///
+    /// # Arguments
+    /// * `tagger` - POS tagger
+    pub fn tag_pos(&mut self, tagger: &dyn PosTagger) {
+        let tagged_indexes = self
+            .tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| matches!(token.kind(), TokenType::Word | TokenType::Special))
+            .map(|(idx, _)| idx)
+            .collect::<Vec<usize>>();
+        let words = tagged_indexes
+            .iter()
+            .map(|&idx| self.tokens[idx].token().as_str())
+            .collect::<Vec<&str>>();
+        let tags = tagger.tag(&words);
+        for (idx, tag) in tagged_indexes.into_iter().zip(tags) {
+            self.tokens[idx].set_pos(Some(tag));
+        }
+    }
+
+    /// Set filter of word tokens by POS tags, returns previous filter
+    pub(crate) fn set_pos_filter(&mut self, pos_filter: Option<PosFilter>) -> Option<PosFilter> {
+        std::mem::replace(&mut self.pos_filter, pos_filter)
+    }
+
     /// Swap two tokens in Doc - in-place
     ///
     /// # Arguments
@@ -119,6 +298,54 @@ mod tests {
     use super::*;
     use test_case::test_case;
 
+    #[test_case("Hello world. How are you? Fine!", vec!["Hello world.", "How are you?", "Fine!"] ; "basic sentences")]
+    #[test_case("  One.  Two.\n\nThree  ", vec!["One.", "Two.", "Three"] ; "whitespace around sentences")]
+    #[test_case("No terminal punctuation", vec!["No terminal punctuation"] ; "single sentence")]
+    #[test_case(" \t ", vec![] ; "only whitespace")]
+    #[test_case("", vec![] ; "empty")]
+    fn test_get_sentence_ranges(input_text: &str, expected_sentences: Vec<&str>) {
+        let doc = Doc::new(input_text);
+
+        let sentences = doc
+            .get_sentence_ranges()
+            .into_iter()
+            .map(|range| {
+                doc.tokens[range]
+                    .iter()
+                    .map(|token| token.token().as_str())
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>();
+
+        assert_eq!(sentences, expected_sentences);
+    }
+
+    #[test]
+    fn test_get_sentence_ranges_deleted_tokens() {
+        let mut doc = Doc::new("First one. Second one.");
+        doc.tokens[0].change("", TokenType::Deleted);
+        doc.tokens[7].change("", TokenType::Deleted);
+
+        // Leading deleted tokens are trimmed, inner ones are kept in the sentence
+        assert_eq!(doc.get_sentence_ranges(), vec![2..4, 5..9]);
+    }
+
+    #[test_case(vec![true, false, false], "B. C." ; "first")]
+    #[test_case(vec![false, true, false], "A. C." ; "middle")]
+    #[test_case(vec![false, false, true], "A. B." ; "last")]
+    #[test_case(vec![false, true, true], "A." ; "last two")]
+    #[test_case(vec![true, true, true], "" ; "all")]
+    #[test_case(vec![false, false, false], "A. B. C." ; "none")]
+    fn test_delete_sentences(is_deleted: Vec<bool>, expected_text: &str) {
+        let mut doc = Doc::new("A. B. C.");
+        let sentences = doc.get_sentence_ranges();
+
+        doc.delete_sentences(&sentences, &is_deleted, "Test");
+
+        assert_eq!(doc.to_string(), expected_text);
+        assert_eq!(doc.num_changes, is_deleted.iter().filter(|&&deleted| deleted).count());
+    }
+
     #[test_case("Hello, world!", vec!["Hello", ",", " ", "world", "!"] ; "basic latin script")]
     #[test_case("    Some\t\t    spaces", vec!["    ", "Some", "\t", "\t", "    ", "spaces"] ; "complicated spaces")]
     #[test_case("Hello\u{200A}world", vec!["Hello", "\u{200A}", "world"] ; "unicode spaces")]
@@ -260,6 +487,53 @@ mod tests {
         assert_eq!(word_tokens, expected);
     }
 
+    /// Tags words ending with "ly" as adverbs, other words as nouns and special tokens as punctuation
+    struct SuffixTagger;
+
+    impl PosTagger for SuffixTagger {
+        fn tag(&self, words: &[&str]) -> Vec<String> {
+            words
+                .iter()
+                .map(|word| match word {
+                    word if word.ends_with("ly") => "RB".to_string(),
+                    word if word.chars().any(|c| c.is_alphabetic()) => "NN".to_string(),
+                    _ => ".".to_string(),
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_tag_pos() {
+        let mut doc = Doc::new("Dogs run quickly!");
+
+        doc.tag_pos(&SuffixTagger);
+
+        let tags = doc
+            .tokens
+            .iter()
+            .map(|token| token.pos())
+            .collect::<Vec<Option<&str>>>();
+        assert_eq!(tags, vec![Some("NN"), None, Some("NN"), None, Some("RB"), Some(".")]);
+    }
+
+    #[test_case(Some(vec!["RB"]), None, vec![4] ; "include")]
+    #[test_case(None, Some(vec!["RB"]), vec![0, 2] ; "exclude")]
+    #[test_case(None, None, vec![0, 2, 4] ; "empty filter")]
+    fn test_get_word_indexes_with_pos_filter(
+        include: Option<Vec<&str>>,
+        exclude: Option<Vec<&str>>,
+        expected: Vec<usize>,
+    ) {
+        let to_set = |classes: Vec<&str>| classes.into_iter().map(String::from).collect::<HashSet<String>>();
+        let mut doc = Doc::new("Dogs run quickly!");
+        doc.tag_pos(&SuffixTagger);
+
+        doc.set_pos_filter(Some(PosFilter::new(include.map(to_set), exclude.map(to_set))));
+
+        assert_eq!(doc.get_word_indexes(false, None), expected);
+    }
+
     #[test_case("A B, C D", 0, 2, "B A, C D")]
     #[test_case("A B, C D", 0, 1, " AB, C D")]
     #[test_case("A B, C D", 2, 3, "A ,B C D")]
@@ -269,4 +543,72 @@ mod tests {
         doc.swap_tokens_by_index(idx_a, idx_b);
         assert_eq!(doc.to_string(), expected);
     }
+
+    #[test]
+    fn test_record_change_untracked() {
+        let mut doc = Doc::new("A B");
+        doc.record_change(|_| panic!("change should not be built for untracked doc"));
+
+        assert_eq!(doc.num_changes, 1);
+        assert!(doc.changes().is_none());
+        assert!(doc.take_changes().is_empty());
+    }
+
+    #[test]
+    fn test_record_change_tracked() {
+        let mut doc = Doc::new("A B");
+        doc.track_changes();
+        let old_text = doc.tokens[0].change("C", TokenType::Word);
+        doc.record_change(|tokens| {
+            Change::new(
+                "Test",
+                TextAction::Substitute,
+                Some(0),
+                Some(0),
+                &old_text,
+                tokens[0].token(),
+            )
+        });
+
+        assert_eq!(doc.num_changes, 1);
+        assert_eq!(
+            doc.changes().unwrap(),
+            [Change::new("Test", TextAction::Substitute, Some(0), Some(0), "A", "C")]
+        );
+        assert_eq!(doc.take_changes().len(), 1);
+        assert!(doc.changes().unwrap().is_empty());
+    }
+
+    #[test_case("Hi, мир!", vec![(0, 2), (2, 3), (3, 4), (4, 7), (7, 8)] ; "char offsets")]
+    #[test_case("", vec![] ; "empty")]
+    fn test_original_spans(text: &str, expected: Vec<(usize, usize)>) {
+        let doc = Doc::new(text);
+        let spans = doc
+            .tokens
+            .iter()
+            .map(|token| token.original_span().unwrap())
+            .collect::<Vec<(usize, usize)>>();
+        assert_eq!(spans, expected);
+    }
+
+    #[test_case(vec![], vec![0, 2, 4, 6, 8] ; "no protected")]
+    #[test_case(vec![0, 1, 2], vec![4, 6, 8] ; "protected words")]
+    fn test_protected_tokens(protected: Vec<usize>, expected_word_indexes: Vec<usize>) {
+        let mut doc = Doc::new("A B C D E");
+        for idx in protected {
+            doc.tokens[idx].set_protected(true);
+        }
+        assert_eq!(doc.get_word_indexes(false, None), expected_word_indexes);
+    }
+
+    #[test]
+    fn test_get_insert_indexes() {
+        let mut doc = Doc::new("A B C");
+        assert_eq!(doc.get_insert_indexes(), vec![0, 1, 2, 3, 4, 5]);
+
+        doc.tokens[2].set_protected(true);
+        doc.tokens[3].set_protected(true);
+        doc.tokens[4].set_protected(true);
+        assert_eq!(doc.get_insert_indexes(), vec![0, 1, 2, 5]);
+    }
 }
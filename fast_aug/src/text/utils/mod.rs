@@ -1,7 +1,14 @@
+mod casing;
+mod change;
 mod doc;
 mod parameters;
+mod pos_filter;
 mod token;
 
+pub use casing::Casing;
+pub use change::Change;
 pub use doc::Doc;
 pub use parameters::TextAugmentParameters;
-pub use token::{Token, TokenType};
+pub(crate) use pos_filter::is_pos_of_class;
+pub use pos_filter::PosFilter;
+pub use token::{CharUnit, Token, TokenType};
@@ -1,5 +1,10 @@
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+
 /// Parameters for augmentation
-#[derive(Clone)]
+/// Can be deserialized from the full object, `[p, min_elements, max_elements]` list or just `p` number
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "TextAugmentParametersRepr")]
 pub struct TextAugmentParameters {
     /// Probability of augmentation of single element
     /// Not true probability, but expected percent of elements to be augmented
@@ -13,6 +18,35 @@ pub struct TextAugmentParameters {
     pub max_elements: Option<usize>,
 }
 
+/// Accepted serialized forms of TextAugmentParameters
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TextAugmentParametersRepr {
+    P(f32),
+    Tuple(f32, Option<usize>, Option<usize>),
+    Full {
+        p: f32,
+        #[serde(default)]
+        min_elements: Option<usize>,
+        #[serde(default)]
+        max_elements: Option<usize>,
+    },
+}
+
+impl From<TextAugmentParametersRepr> for TextAugmentParameters {
+    fn from(repr: TextAugmentParametersRepr) -> Self {
+        match repr {
+            TextAugmentParametersRepr::P(p) => TextAugmentParameters::new(p, None, None),
+            TextAugmentParametersRepr::Tuple(p, min_elements, max_elements)
+            | TextAugmentParametersRepr::Full {
+                p,
+                min_elements,
+                max_elements,
+            } => TextAugmentParameters::new(p, min_elements, max_elements),
+        }
+    }
+}
+
 impl Default for TextAugmentParameters {
     fn default() -> Self {
         TextAugmentParameters {
@@ -37,6 +71,29 @@ impl TextAugmentParameters {
         }
     }
 
+    /// Create new AugmentParameters, checking that `p` is between 0 and 1
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::text::TextAugmentParameters;
+    ///
+    /// assert!(TextAugmentParameters::try_new(0.5, None, Some(3)).is_ok());
+    /// assert!(TextAugmentParameters::try_new(1.5, None, None).is_err());
+    /// ```
+    pub fn try_new(p: f32, min_elements: Option<usize>, max_elements: Option<usize>) -> Result<Self> {
+        let params = TextAugmentParameters::new(p, min_elements, max_elements);
+        params.validate()?;
+        Ok(params)
+    }
+
+    /// Check that parameters are valid, `p` should be between 0 and 1
+    pub fn validate(&self) -> Result<()> {
+        if !(0.0..=1.0).contains(&self.p) {
+            return Err(Error::InvalidProbability(self.p as f64));
+        }
+        Ok(())
+    }
+
     /// Calculate number of elements to be augmented from input size
     ///
     /// # Arguments
@@ -97,6 +154,24 @@ mod tests {
         assert_eq!(params.num_elements(input_size), expected);
     }
 
+    #[test_case(0.0, true)]
+    #[test_case(0.3, true)]
+    #[test_case(1.0, true)]
+    #[test_case(-0.1, false)]
+    #[test_case(1.1, false)]
+    #[test_case(f32::NAN, false)]
+    fn test_try_new(p: f32, expected_ok: bool) {
+        assert_eq!(TextAugmentParameters::try_new(p, None, None).is_ok(), expected_ok);
+    }
+
+    #[test_case("0.5", TextAugmentParameters::new(0.5, None, None) ; "number")]
+    #[test_case("[0.5, 1, null]", TextAugmentParameters::new(0.5, Some(1), None) ; "list")]
+    #[test_case(r#"{"p": 0.5, "max_elements": 3}"#, TextAugmentParameters::new(0.5, None, Some(3)) ; "object")]
+    fn test_deserialize(content: &str, expected: TextAugmentParameters) {
+        let params: TextAugmentParameters = serde_json::from_str(content).unwrap();
+        assert_eq!(params, expected);
+    }
+
     #[test]
     fn test_num_elements_defaults() {
         let params = TextAugmentParameters::default();
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Filter of word tokens by their part-of-speech tags
+/// Tag matches a POS class if it starts with it, e.g. class "JJ" matches "JJ", "JJR" and "JJS" tags of Penn Treebank
+/// * `include` - Only words of these classes can be augmented, untagged words are skipped
+/// * `exclude` - Words of these classes can not be augmented
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PosFilter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include: Option<HashSet<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude: Option<HashSet<String>>,
+}
+
+impl PosFilter {
+    pub fn new(include: Option<HashSet<String>>, exclude: Option<HashSet<String>>) -> Self {
+        PosFilter { include, exclude }
+    }
+
+    /// Check if the word with the given POS tag can be augmented
+    pub fn matches(&self, pos: Option<&str>) -> bool {
+        let is_in =
+            |classes: &HashSet<String>| pos.is_some_and(|pos| classes.iter().any(|class| is_pos_of_class(pos, class)));
+        let is_included = self.include.as_ref().is_none_or(is_in);
+        let is_excluded = self.exclude.as_ref().is_some_and(is_in);
+        is_included && !is_excluded
+    }
+}
+
+/// Check if the POS tag belongs to the POS class, i.e. the tag starts with the class
+pub(crate) fn is_pos_of_class(pos: &str, class: &str) -> bool {
+    pos.starts_with(class)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn classes(classes: &[&str]) -> Option<HashSet<String>> {
+        Some(classes.iter().map(|class| class.to_string()).collect())
+    }
+
+    #[test_case(None, None, Some("NN"), true ; "no filter")]
+    #[test_case(classes(&["JJ"]), None, Some("JJR"), true ; "included by prefix")]
+    #[test_case(classes(&["JJ"]), None, Some("NN"), false ; "not included")]
+    #[test_case(classes(&["JJ"]), None, None, false ; "untagged not included")]
+    #[test_case(None, classes(&["VB"]), Some("VBZ"), false ; "excluded by prefix")]
+    #[test_case(None, classes(&["VB"]), None, true ; "untagged not excluded")]
+    #[test_case(classes(&["NN", "JJ"]), classes(&["NNP"]), Some("NNP"), false ; "exclude wins")]
+    fn test_matches(
+        include: Option<HashSet<String>>,
+        exclude: Option<HashSet<String>>,
+        pos: Option<&str>,
+        expected: bool,
+    ) {
+        assert_eq!(PosFilter::new(include, exclude).matches(pos), expected);
+    }
+}
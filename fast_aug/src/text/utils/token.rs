@@ -1,4 +1,6 @@
 // use regex::Regex;
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Token types
 ///
@@ -14,15 +16,65 @@ pub enum TokenType {
     Deleted,
 }
 
+/// Units of text that char augmenters insert, delete, substitute and swap
+///
+/// CharUnit::Grapheme  -> Extended grapheme clusters (UAX#29), what user perceives as a single char:
+///                        letters with combining diacritics, emoji ZWJ sequences, flags, Indic syllables
+/// CharUnit::CodePoint -> Unicode code points (Rust `char`), can break graphemes into invalid fragments
+///
+/// Serialized in snake_case, e.g. "code_point"
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CharUnit {
+    #[default]
+    Grapheme,
+    CodePoint,
+}
+
+impl CharUnit {
+    /// Split text into char units
+    ///
+    /// # Examples
+    /// ```rust
+    /// use fast_aug::text::CharUnit;
+    ///
+    /// assert_eq!(CharUnit::Grapheme.split("🇩🇪e\u{301}"), vec!["🇩🇪", "e\u{301}"]);
+    /// assert_eq!(CharUnit::CodePoint.split("🇩🇪e\u{301}"), vec!["🇩", "🇪", "e", "\u{301}"]);
+    /// ```
+    pub fn split(self, text: &str) -> Vec<&str> {
+        match self {
+            CharUnit::Grapheme => text.graphemes(true).collect(),
+            CharUnit::CodePoint => text
+                .char_indices()
+                .map(|(idx, c)| &text[idx..idx + c.len_utf8()])
+                .collect(),
+        }
+    }
+}
+
 /// Struct that stores token, it's type and it's lexicographic length
+/// Tokens created from the text also keep their original char span, to map labels through augmentations
 ///
 /// Note: lexicographic length != bytes length, as a lot of non-english chars encode to more than 1 byte
 /// TODO: Make lazy token ownership, while not edited - just store the string slice
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Token {
     kind: TokenType,
     token: String,
     token_len: usize,
+    /// Char span [start, end) of the token in the original text, None for inserted tokens
+    original_span: Option<(usize, usize)>,
+    /// Protected tokens are not selected for augmentation (e.g. entity mentions)
+    protected: bool,
+    /// Part-of-speech tag, None if the token is not tagged (see `Doc::tag_pos`)
+    pos: Option<String>,
+}
+
+/// Tokens are equal if their content and type are equal, original span, protection and POS tag are not compared
+impl PartialEq for Token {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.token == other.token
+    }
 }
 
 impl Token {
@@ -39,6 +91,9 @@ impl Token {
             kind,
             token: token.to_string(),
             token_len,
+            original_span: None,
+            protected: false,
+            pos: None,
         }
     }
 
@@ -49,14 +104,20 @@ impl Token {
             kind,
             token: token.to_string(),
             token_len,
+            original_span: None,
+            protected: false,
+            pos: None,
         }
     }
 
-    /// Change token content
-    pub fn change(&mut self, token: &str, kind: TokenType) {
-        self.token = token.to_string();
+    /// Change token content, returns previous content
+    /// Original span is kept, so the changed token is still aligned with the original text
+    /// POS tag is cleared, as the new content can have other part of speech, re-tag the Doc if needed
+    pub fn change(&mut self, token: &str, kind: TokenType) -> String {
         self.token_len = token.chars().count();
         self.kind = kind;
+        self.pos = None;
+        std::mem::replace(&mut self.token, token.to_string())
     }
 
     /// Classify token on TokenTypes. Check any char is:
@@ -94,12 +155,12 @@ impl Token {
         }
     }
 
-    /// Classify token on TokenTypes. Check token matches:
-    ///   empty -> Deleted
-    ///   word regex -> Word
-    ///   space regex -> Space
-    ///   else -> Special
-    /// Slowest speed
+    // Classify token on TokenTypes. Check token matches:
+    //   empty -> Deleted
+    //   word regex -> Word
+    //   space regex -> Space
+    //   else -> Special
+    // Slowest speed
     // #[allow(dead_code, unused_variables, unreachable_code)]
     // fn classify_token_by_regex(token: &str, re_word: &Regex, re_space: &Regex) -> TokenType {
     //     panic!("Not implemented");
@@ -129,10 +190,53 @@ impl Token {
         self.token_len
     }
 
+    /// Get number of extended grapheme clusters, it's computed on each call unlike lexicographic length
+    pub fn grapheme_len(&self) -> usize {
+        self.token.graphemes(true).count()
+    }
+
+    /// Get number of char units, see `CharUnit`
+    pub fn char_len(&self, char_unit: CharUnit) -> usize {
+        match char_unit {
+            CharUnit::Grapheme => self.grapheme_len(),
+            CharUnit::CodePoint => self.utf8_len(),
+        }
+    }
+
     /// Get bytes length
     pub fn byte_len(&self) -> usize {
         self.token.len()
     }
+
+    /// Get char span [start, end) of the token in the original text, None for inserted tokens
+    pub fn original_span(&self) -> Option<(usize, usize)> {
+        self.original_span
+    }
+
+    /// Set char span [start, end) of the token in the original text
+    pub fn set_original_span(&mut self, start: usize, end: usize) {
+        self.original_span = Some((start, end));
+    }
+
+    /// Is token protected from augmentation
+    pub fn is_protected(&self) -> bool {
+        self.protected
+    }
+
+    /// Protect token from (or allow) augmentation
+    pub fn set_protected(&mut self, protected: bool) {
+        self.protected = protected;
+    }
+
+    /// Get part-of-speech tag, None if the token is not tagged
+    pub fn pos(&self) -> Option<&str> {
+        self.pos.as_deref()
+    }
+
+    /// Set part-of-speech tag
+    pub fn set_pos(&mut self, pos: Option<String>) {
+        self.pos = pos;
+    }
 }
 
 #[cfg(test)]
@@ -153,6 +257,21 @@ mod tests {
         assert_eq!(token_obj.byte_len(), byte_len);
     }
 
+    #[test_case("naïve", 5, 5 ; "precomposed diacritic")]
+    #[test_case("nai\u{308}ve", 6, 5 ; "combining diacritic")]
+    #[test_case("नमस्ते", 6, 3 ; "devanagari conjuncts")]
+    #[test_case("สวัสดี", 6, 4 ; "thai vowel and tone marks")]
+    #[test_case("👨‍👩‍👧🇩🇪👍🏽", 9, 3 ; "emoji zwj sequence, flag and skin tone")]
+    fn test_token_grapheme_len(token: &str, utf8_len: usize, grapheme_len: usize) {
+        let token_obj = Token::from_str(token);
+        assert_eq!(token_obj.utf8_len(), utf8_len);
+        assert_eq!(token_obj.grapheme_len(), grapheme_len);
+        assert_eq!(token_obj.char_len(CharUnit::CodePoint), utf8_len);
+        assert_eq!(token_obj.char_len(CharUnit::Grapheme), grapheme_len);
+        assert_eq!(CharUnit::Grapheme.split(token).concat(), token);
+        assert_eq!(CharUnit::CodePoint.split(token).len(), utf8_len);
+    }
+
     #[test_case("123", TokenType::Word, "456", TokenType::Word ; "digits to digits")]
     #[test_case("hello", TokenType::Word, "world", TokenType::Word ; "alphabetic to alphabetic")]
     #[test_case("don't", TokenType::Word, "", TokenType::Deleted ; "word with apostrophe to empty")]
@@ -160,8 +279,12 @@ mod tests {
     fn test_change_token(token: &str, kind: TokenType, new_token: &str, new_kind: TokenType) {
         let target_token = Token::new(new_token, new_kind);
         let mut token_obj = Token::new(token, kind);
-        token_obj.change(new_token, new_kind);
+        token_obj.set_original_span(0, token.chars().count());
+        token_obj.set_pos(Some("NN".to_string()));
+        assert_eq!(token_obj.change(new_token, new_kind), token);
         assert_eq!(token_obj, target_token);
+        assert_eq!(token_obj.original_span(), Some((0, token.chars().count())));
+        assert_eq!(token_obj.pos(), None);
     }
 
     #[test_case("6", TokenType::Special ; "single digit")]
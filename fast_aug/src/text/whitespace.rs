@@ -0,0 +1,338 @@
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Change, CharUnit, Doc, TextAugmentParameters, Token, TokenType};
+use crate::base::BaseAugmenter;
+use crate::error::Result;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Unicode spaces looking like (or close to) the ASCII space, but breaking naive tokenizers
+/// No-break space, en space, em space, thin space, hair space, narrow no-break space, ideographic space
+const UNICODE_SPACES: [char; 7] = [
+    '\u{00A0}', '\u{2002}', '\u{2003}', '\u{2009}', '\u{200A}', '\u{202F}', '\u{3000}',
+];
+
+/// Modes of whitespace noise, e.g. from OCR or scraped html
+///
+/// WhitespaceMode::Merge     -> Merge adjacent words by removing the space between them ("New York" -> "NewYork")
+/// WhitespaceMode::Split     -> Split words by inserting a space at random position ("York" -> "Yo rk")
+/// WhitespaceMode::Duplicate -> Duplicate whitespace ("New York" -> "New  York")
+/// WhitespaceMode::Normalize -> Normalize whitespace to a single ASCII space ("New \n York" -> "New York")
+/// WhitespaceMode::Unicode   -> Replace ASCII spaces with unicode spaces, e.g. no-break or thin space
+/// WhitespaceMode::LineBreak -> Replace whitespace with a line break ("New York" -> "New\nYork")
+///
+/// Serialized in snake_case, e.g. "line_break"
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WhitespaceMode {
+    #[default]
+    Merge,
+    Split,
+    Duplicate,
+    Normalize,
+    Unicode,
+    LineBreak,
+}
+
+/// Augmenter that adds whitespace noise to the text, see `WhitespaceMode`
+/// Number of augmented tokens is calculated with whitespace parameters from the number of candidates of the mode:
+/// words for `WhitespaceMode::Split`, spaces between words for `WhitespaceMode::Merge`, spaces otherwise
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::text::{TextAugmentParameters, WhitespaceAugmenter, WhitespaceMode};
+///
+/// let rng = &mut rand::thread_rng();
+/// let augmenter = WhitespaceAugmenter::new(WhitespaceMode::Merge, TextAugmentParameters::new(1.0, None, None));
+/// assert_eq!(augmenter.augment("New York, USA".to_string(), rng), "NewYork, USA");
+/// ```
+pub struct WhitespaceAugmenter {
+    /// Mode of whitespace noise
+    mode: WhitespaceMode,
+    /// Parameters to calculate number of tokens that will be augmented
+    whitespace_params: TextAugmentParameters,
+}
+
+impl WhitespaceAugmenter {
+    /// Create augmenter, parameters are not validated, see `try_new`
+    pub fn new(mode: WhitespaceMode, whitespace_params: TextAugmentParameters) -> Self {
+        WhitespaceAugmenter {
+            mode,
+            whitespace_params,
+        }
+    }
+
+    /// Create augmenter, returns error if whitespace parameters are invalid
+    pub fn try_new(mode: WhitespaceMode, whitespace_params: TextAugmentParameters) -> Result<Self> {
+        whitespace_params.validate()?;
+        Ok(Self::new(mode, whitespace_params))
+    }
+
+    /// Check if the token can be augmented with the mode
+    fn is_candidate(&self, tokens: &[Token], index: usize) -> bool {
+        let token = &tokens[index];
+        if token.is_protected() {
+            return false;
+        }
+        let is_word = |index: Option<usize>| {
+            index
+                .and_then(|index| tokens.get(index))
+                .is_some_and(|token| token.kind() == &TokenType::Word && !token.is_protected())
+        };
+
+        match self.mode {
+            WhitespaceMode::Merge => {
+                token.kind() == &TokenType::Space && is_word(index.checked_sub(1)) && is_word(Some(index + 1))
+            }
+            WhitespaceMode::Split => token.kind() == &TokenType::Word && token.grapheme_len() > 1,
+            WhitespaceMode::Duplicate => token.kind() == &TokenType::Space,
+            WhitespaceMode::Normalize => {
+                // Only the first token of consecutive Space tokens (e.g. "\t" and " ") is a candidate
+                token.kind() == &TokenType::Space
+                    && (index == 0 || tokens[index - 1].kind() != &TokenType::Space)
+                    && (token.token() != " "
+                        || tokens
+                            .get(index + 1)
+                            .is_some_and(|next| next.kind() == &TokenType::Space))
+            }
+            WhitespaceMode::Unicode => token.kind() == &TokenType::Space && token.token().chars().all(|c| c == ' '),
+            WhitespaceMode::LineBreak => token.kind() == &TokenType::Space && !token.token().contains('\n'),
+        }
+    }
+
+    /// Select random candidate tokens, returns indexes in ascending order
+    fn select_indexes(&self, doc: &Doc, rng: &mut dyn rand::RngCore) -> Vec<usize> {
+        let candidate_indexes = (0..doc.tokens.len())
+            .filter(|&index| self.is_candidate(&doc.tokens, index))
+            .collect::<Vec<usize>>();
+        let num_tokens_to_change = self.whitespace_params.num_elements(candidate_indexes.len());
+        let mut selected_indexes = self.select_random_element_indexes(rng, candidate_indexes, num_tokens_to_change);
+        selected_indexes.sort_unstable();
+        selected_indexes
+    }
+
+    fn merge(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        for index in self.select_indexes(&doc, rng) {
+            let old_token = doc.tokens[index].change("", TokenType::Deleted);
+            doc.record_change(|_| {
+                Change::new(
+                    "WhitespaceAugmenter",
+                    TextAction::Delete,
+                    Some(index),
+                    None,
+                    &old_token,
+                    "",
+                )
+            });
+        }
+
+        doc
+    }
+
+    fn split(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        // The first part stays in the original token, space and the second part are inserted after it,
+        // so each split shifts the following tokens by 2
+        for (num_split_before, selected_index) in self.select_indexes(&doc, rng).into_iter().enumerate() {
+            let index = selected_index + 2 * num_split_before;
+            let word = doc.tokens[index].token().clone();
+            let graphemes = CharUnit::Grapheme.split(&word);
+            let split_at = rng.gen_range(1..graphemes.len());
+            let (first, second) = (graphemes[..split_at].concat(), graphemes[split_at..].concat());
+
+            doc.tokens[index].change(&first, TokenType::Word);
+            doc.tokens.splice(
+                index + 1..index + 1,
+                [Token::new(" ", TokenType::Space), Token::new(&second, TokenType::Word)],
+            );
+
+            // Truncation of the word and insertion of the space with the second part are recorded separately
+            doc.record_change(|_| {
+                Change::new(
+                    "WhitespaceAugmenter",
+                    TextAction::Substitute,
+                    Some(index),
+                    Some(index),
+                    &word,
+                    &first,
+                )
+            });
+            doc.record_change(|_| {
+                Change::new(
+                    "WhitespaceAugmenter",
+                    TextAction::Insert,
+                    None,
+                    Some(index + 1),
+                    "",
+                    &format!(" {}", second),
+                )
+            });
+        }
+
+        doc
+    }
+
+    fn substitute(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        for index in self.select_indexes(&doc, rng) {
+            let token = doc.tokens[index].token();
+            let new_token = match self.mode {
+                WhitespaceMode::Duplicate => token.repeat(2),
+                WhitespaceMode::Normalize => " ".to_string(),
+                WhitespaceMode::Unicode => UNICODE_SPACES.choose(rng).unwrap().to_string().repeat(token.len()),
+                WhitespaceMode::LineBreak => "\n".to_string(),
+                _ => unreachable!("{:?} is not a substitution mode", self.mode),
+            };
+            let mut old_token = doc.tokens[index].change(&new_token, TokenType::Space);
+            if self.mode == WhitespaceMode::Normalize {
+                // Following Space tokens of the run are merged into the normalized one
+                for next_index in index + 1..doc.tokens.len() {
+                    if doc.tokens[next_index].kind() != &TokenType::Space || doc.tokens[next_index].is_protected() {
+                        break;
+                    }
+                    old_token.push_str(&doc.tokens[next_index].change("", TokenType::Deleted));
+                }
+            }
+
+            doc.record_change(|tokens| {
+                Change::new(
+                    "WhitespaceAugmenter",
+                    TextAction::Substitute,
+                    Some(index),
+                    Some(index),
+                    &old_token,
+                    tokens[index].token(),
+                )
+            });
+        }
+
+        doc
+    }
+}
+
+impl BaseTextAugmenter for WhitespaceAugmenter {}
+
+impl BaseAugmenter<String, Doc> for WhitespaceAugmenter {
+    fn augment_inner(&self, input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        match self.mode {
+            WhitespaceMode::Merge => self.merge(input, rng),
+            WhitespaceMode::Split => self.split(input, rng),
+            _ => self.substitute(input, rng),
+        }
+    }
+
+    fn convert_to_inner(&self, input: String) -> Doc {
+        Doc::new(&input)
+    }
+
+    fn convert_to_outer(&self, input: Doc) -> String {
+        input.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::AugmentWithChanges;
+    use test_case::test_case;
+
+    fn augmenter(mode: WhitespaceMode, p: f32) -> WhitespaceAugmenter {
+        WhitespaceAugmenter::new(mode, TextAugmentParameters::new(p, None, None))
+    }
+
+    #[test_case("New York, USA", "NewYork, USA" ; "only spaces between words")]
+    #[test_case("a b c", "abc" ; "all spaces")]
+    #[test_case("Hi, you", "Hi, you" ; "no spaces between words")]
+    #[test_case("", "" ; "empty text")]
+    fn test_merge(text: &str, expected: &str) {
+        let aug = augmenter(WhitespaceMode::Merge, 1.0);
+
+        assert_eq!(aug.augment(text.to_string(), &mut rand::thread_rng()), expected);
+    }
+
+    #[test_case("New York, USA", 3 ; "every word")]
+    #[test_case("I am", 1 ; "single char words are not split")]
+    #[test_case("नमस्ते", 1 ; "graphemes are kept")]
+    fn test_split(text: &str, expected_splits: usize) {
+        let aug = augmenter(WhitespaceMode::Split, 1.0);
+
+        let doc = aug.augment_inner(Doc::new(text), &mut rand::thread_rng());
+
+        assert_eq!(doc.num_changes, 2 * expected_splits);
+        assert_eq!(doc.tokens.len(), Doc::new(text).tokens.len() + 2 * expected_splits);
+        let output = doc.to_string();
+        assert_eq!(
+            output.split_whitespace().count(),
+            text.split_whitespace().count() + expected_splits
+        );
+        assert_eq!(output.replace(' ', ""), text.replace(' ', ""));
+        for word in output.split_whitespace() {
+            assert!(!word.starts_with('\u{94d}'), "grapheme split in {}", output);
+        }
+    }
+
+    #[test_case(WhitespaceMode::Duplicate, "a b\n\nc", "a  b\n\n\n\nc" ; "duplicate")]
+    #[test_case(WhitespaceMode::Normalize, "a  b\t c\nd e", "a b c d e" ; "normalize")]
+    #[test_case(WhitespaceMode::Normalize, "\t\t a ", " a " ; "normalize at edges")]
+    #[test_case(WhitespaceMode::LineBreak, "a b  c\nd", "a\nb\nc\nd" ; "line break")]
+    fn test_substitute(mode: WhitespaceMode, text: &str, expected: &str) {
+        let aug = augmenter(mode, 1.0);
+
+        assert_eq!(aug.augment(text.to_string(), &mut rand::thread_rng()), expected);
+    }
+
+    #[test]
+    fn test_unicode() {
+        let aug = augmenter(WhitespaceMode::Unicode, 1.0);
+        let text = "New  York,\nUSA";
+
+        let output = aug.augment(text.to_string(), &mut rand::thread_rng());
+
+        assert!(!output.contains(' '));
+        assert_eq!(output.chars().count(), text.chars().count());
+        assert!(output.contains('\n'));
+        assert_eq!(
+            output.split_whitespace().collect::<Vec<&str>>(),
+            vec!["New", "York,", "USA"]
+        );
+    }
+
+    #[test_case(WhitespaceMode::Merge ; "merge")]
+    #[test_case(WhitespaceMode::Split ; "split")]
+    #[test_case(WhitespaceMode::Duplicate ; "duplicate")]
+    fn test_probability_zero(mode: WhitespaceMode) {
+        let aug = augmenter(mode, 0.0);
+        let text = "New York, USA";
+
+        assert_eq!(aug.augment(text.to_string(), &mut rand::thread_rng()), text);
+    }
+
+    #[test]
+    fn test_augment_with_changes() {
+        let aug = augmenter(WhitespaceMode::Split, 1.0);
+
+        let (output, changes) = aug.augment_with_changes("ab cd".to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, "a b c d");
+        let changes = changes
+            .iter()
+            .map(|change| {
+                (
+                    change.operation,
+                    change.original_index,
+                    change.new_index,
+                    change.old_text.as_str(),
+                    change.new_text.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                (TextAction::Substitute, Some(0), Some(0), "ab", "a"),
+                (TextAction::Insert, None, Some(1), "", " b"),
+                (TextAction::Substitute, Some(4), Some(4), "cd", "c"),
+                (TextAction::Insert, None, Some(5), "", " d"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,420 @@
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Casing, Change, Doc, TextAugmentParameters, Token, TokenType};
+use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
+use crate::models::text::MaskedLmModel;
+use rand::seq::SliceRandom;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Augmenter that substitutes or inserts words predicted by masked language model from the context
+/// Words are selected the same way as in random words augmenters, then masked and filled by the model,
+/// so new words fit the context better than random vocabulary words.
+/// * `TextAction::Substitute` - mask selected words and replace them with predicted words
+/// * `TextAction::Insert` - insert masks before selected words and fill them with predicted words
+///
+/// Replacement is sampled from `top_k` candidates of the model, restricted to the smallest set of candidates
+/// with cumulative probability of at least `top_p` (nucleus sampling). Candidates equal to the original word are skipped.
+/// If the model fails, the text is returned unchanged.
+///
+/// # Examples
+/// ```rust
+/// use std::sync::Arc;
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::models::text::MaskedLmModel;
+/// use fast_aug::text::{ContextualWordsAugmenter, TextAction, TextAugmentParameters};
+///
+/// struct AlwaysCat;
+/// impl MaskedLmModel for AlwaysCat {
+///     fn fill_masks(&self, tokens: &[Option<&str>], _top_k: usize) -> fast_aug::Result<Vec<Vec<(String, f32)>>> {
+///         Ok(tokens.iter().filter(|token| token.is_none()).map(|_| vec![("cat".to_string(), 1.0)]).collect())
+///     }
+/// }
+///
+/// let rng = &mut rand::thread_rng();
+/// let augmenter = ContextualWordsAugmenter::new(
+///     Arc::new(AlwaysCat),
+///     TextAction::Substitute,
+///     TextAugmentParameters::new(1.0, None, None),
+///     None,
+///     10,
+///     0.9,
+/// );
+/// assert_eq!(augmenter.augment("Some dog!".to_string(), rng), "Cat cat!");
+/// ```
+pub struct ContextualWordsAugmenter {
+    /// Masked language model to fill masked words
+    model: Arc<dyn MaskedLmModel>,
+    /// Action to augmentation, set of values {'substitute', 'insert'}
+    action: TextAction,
+    /// Parameters to calculate number of words that will be augmented
+    word_params: TextAugmentParameters,
+    /// Filter, Set of words that cannot be augmented
+    stopwords: Option<HashSet<String>>,
+    /// Number of model candidates to sample from, at least 1
+    top_k: usize,
+    /// Cumulative probability of candidates to sample from
+    top_p: f32,
+    /// Casing of the new words, see `Casing`
+    casing: Casing,
+}
+
+impl ContextualWordsAugmenter {
+    /// Create augmenter, action and parameters are not validated, see `try_new`
+    pub fn new(
+        model: Arc<dyn MaskedLmModel>,
+        action: TextAction,
+        word_params: TextAugmentParameters,
+        stopwords: Option<HashSet<String>>,
+        top_k: usize,
+        top_p: f32,
+    ) -> Self {
+        ContextualWordsAugmenter {
+            model,
+            action,
+            word_params,
+            stopwords,
+            top_k: top_k.max(1),
+            top_p,
+            casing: Casing::Preserve,
+        }
+    }
+
+    /// Create augmenter, returns error if action is not Substitute or Insert, or parameters are invalid
+    pub fn try_new(
+        model: Arc<dyn MaskedLmModel>,
+        action: TextAction,
+        word_params: TextAugmentParameters,
+        stopwords: Option<HashSet<String>>,
+        top_k: usize,
+        top_p: f32,
+    ) -> Result<Self> {
+        if action != TextAction::Substitute && action != TextAction::Insert {
+            return Err(Error::UnsupportedAction(format!(
+                "ContextualWordsAugmenter supports only Substitute and Insert, got {:?}",
+                action
+            )));
+        }
+        word_params.validate()?;
+        if !(top_p > 0.0 && top_p <= 1.0) {
+            return Err(Error::InvalidProbability(top_p as f64));
+        }
+        Ok(Self::new(model, action, word_params, stopwords, top_k, top_p))
+    }
+
+    /// Set casing of the new words, matches casing of the original words by default
+    pub fn with_casing(mut self, casing: Casing) -> Self {
+        self.casing = casing;
+        self
+    }
+
+    /// Select random word tokens to mask, sorted by index
+    fn select_word_indexes(&self, doc: &mut Doc, rng: &mut dyn rand::RngCore) -> Vec<usize> {
+        let word_tokens_indexes = doc.get_word_indexes(false, self.stopwords.as_ref());
+        let num_tokens_to_change = self.word_params.num_elements(word_tokens_indexes.len());
+        let mut selected_tokens_indexes =
+            self.select_random_element_indexes(rng, word_tokens_indexes, num_tokens_to_change);
+        selected_tokens_indexes.sort_unstable();
+        selected_tokens_indexes
+    }
+
+    /// Fill masks with the model, None if the model fails or returns wrong number of predictions
+    fn fill_masks(&self, tokens: &[Option<&str>]) -> Option<Vec<Vec<(String, f32)>>> {
+        let num_masks = tokens.iter().filter(|token| token.is_none()).count();
+        match self.model.fill_masks(tokens, self.top_k) {
+            Ok(candidates) if candidates.len() == num_masks => Some(candidates),
+            _ => None,
+        }
+    }
+
+    /// Sample new word from the candidates with top-p (nucleus) sampling, skipping the original word
+    fn sample_candidate(
+        &self,
+        candidates: Vec<(String, f32)>,
+        original: &str,
+        rng: &mut dyn rand::RngCore,
+    ) -> Option<String> {
+        let original = original.to_lowercase();
+        let mut candidates = candidates
+            .into_iter()
+            .filter(|(word, probability)| {
+                *probability > 0.0 && word.chars().any(|c| c.is_alphabetic()) && word.to_lowercase() != original
+            })
+            .take(self.top_k)
+            .collect::<Vec<(String, f32)>>();
+
+        // Keep the most probable candidates until cumulative probability reaches top_p
+        let total_probability = candidates.iter().map(|(_, probability)| probability).sum::<f32>();
+        let mut cumulative_probability = 0.0;
+        let num_candidates = candidates
+            .iter()
+            .take_while(|(_, probability)| {
+                let is_needed = cumulative_probability < self.top_p * total_probability;
+                cumulative_probability += probability;
+                is_needed
+            })
+            .count();
+        candidates.truncate(num_candidates.max(1));
+
+        candidates
+            .choose_weighted(rng, |(_, probability)| *probability)
+            .ok()
+            .map(|(word, _)| word.clone())
+    }
+
+    fn substitute(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        let selected_tokens_indexes = self.select_word_indexes(&mut doc, rng);
+        if selected_tokens_indexes.is_empty() {
+            return doc;
+        }
+
+        let masked_tokens = doc
+            .tokens
+            .iter()
+            .enumerate()
+            .map(|(idx, token)| match selected_tokens_indexes.binary_search(&idx) {
+                Ok(_) => None,
+                Err(_) => Some(token.token().as_str()),
+            })
+            .collect::<Vec<Option<&str>>>();
+        let Some(candidates) = self.fill_masks(&masked_tokens) else {
+            return doc;
+        };
+
+        // For all selected tokens sample new word from the model candidates and substitute
+        for (index, candidates) in selected_tokens_indexes.into_iter().zip(candidates) {
+            let original = doc.tokens[index].token();
+            if let Some(new_word) = self.sample_candidate(candidates, original, rng) {
+                let new_word = self.casing.apply(original, &new_word, rng);
+                let old_token = doc.tokens[index].change(&new_word, TokenType::Word);
+                doc.record_change(|tokens| {
+                    Change::new(
+                        "ContextualWordsAugmenter",
+                        TextAction::Substitute,
+                        Some(index),
+                        Some(index),
+                        &old_token,
+                        tokens[index].token(),
+                    )
+                });
+            }
+        }
+
+        doc
+    }
+
+    fn insert(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        let selected_tokens_indexes = self.select_word_indexes(&mut doc, rng);
+        if selected_tokens_indexes.is_empty() {
+            return doc;
+        }
+
+        // Mask and space are placed before each selected token
+        let mut masked_tokens: Vec<Option<&str>> =
+            Vec::with_capacity(doc.tokens.len() + 2 * selected_tokens_indexes.len());
+        for (idx, token) in doc.tokens.iter().enumerate() {
+            if selected_tokens_indexes.binary_search(&idx).is_ok() {
+                masked_tokens.push(None);
+                masked_tokens.push(Some(" "));
+            }
+            masked_tokens.push(Some(token.token().as_str()));
+        }
+        let Some(candidates) = self.fill_masks(&masked_tokens) else {
+            return doc;
+        };
+
+        // Insert new word and space before each selected token, going from the end to keep indexes valid
+        let mut inserted_tokens_indexes: Vec<usize> = Vec::with_capacity(selected_tokens_indexes.len());
+        for (index, candidates) in selected_tokens_indexes.into_iter().zip(candidates).rev() {
+            if let Some(new_word) = self.sample_candidate(candidates, "", rng) {
+                let new_word = self.casing.apply_inserted(doc.tokens[index].token(), &new_word, rng);
+                doc.tokens.splice(
+                    index..index,
+                    [
+                        Token::new(&new_word, TokenType::Word),
+                        Token::new(" ", TokenType::Space),
+                    ],
+                );
+                inserted_tokens_indexes.push(index);
+            }
+        }
+
+        // Each insertion shifts the words inserted after it by 2 tokens (word and space)
+        for (num_inserted_before, index) in inserted_tokens_indexes.into_iter().rev().enumerate() {
+            let new_index = index + 2 * num_inserted_before;
+            doc.record_change(|tokens| {
+                Change::new(
+                    "ContextualWordsAugmenter",
+                    TextAction::Insert,
+                    None,
+                    Some(new_index),
+                    "",
+                    tokens[new_index].token(),
+                )
+            });
+        }
+
+        doc
+    }
+}
+
+impl BaseTextAugmenter for ContextualWordsAugmenter {}
+
+impl BaseAugmenter<String, Doc> for ContextualWordsAugmenter {
+    fn augment_inner(&self, input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        match self.action {
+            TextAction::Substitute => self.substitute(input, rng),
+            TextAction::Insert => self.insert(input, rng),
+            _ => panic!("Action not implemented"),
+        }
+    }
+
+    fn convert_to_inner(&self, input: String) -> Doc {
+        Doc::new(&input)
+    }
+
+    fn convert_to_outer(&self, input: Doc) -> String {
+        input.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use test_case::test_case;
+
+    /// Mock model returning fixed candidates for each mask and remembering the masked inputs
+    struct MockModel {
+        candidates: Vec<(String, f32)>,
+        inputs: Mutex<Vec<String>>,
+    }
+
+    impl MockModel {
+        fn new(candidates: &[(&str, f32)]) -> Arc<Self> {
+            Arc::new(MockModel {
+                candidates: candidates.iter().map(|(word, p)| (word.to_string(), *p)).collect(),
+                inputs: Mutex::new(Vec::new()),
+            })
+        }
+    }
+
+    impl MaskedLmModel for MockModel {
+        fn fill_masks(&self, tokens: &[Option<&str>], top_k: usize) -> Result<Vec<Vec<(String, f32)>>> {
+            let input = tokens.iter().map(|token| token.unwrap_or("[MASK]")).collect::<String>();
+            self.inputs.lock().unwrap().push(input);
+            let candidates = self.candidates.iter().take(top_k).cloned().collect::<Vec<_>>();
+            Ok(tokens
+                .iter()
+                .filter(|token| token.is_none())
+                .map(|_| candidates.clone())
+                .collect())
+        }
+    }
+
+    struct BrokenModel;
+
+    impl MaskedLmModel for BrokenModel {
+        fn fill_masks(&self, _tokens: &[Option<&str>], _top_k: usize) -> Result<Vec<Vec<(String, f32)>>> {
+            Ok(vec![])
+        }
+    }
+
+    fn augmenter(model: Arc<dyn MaskedLmModel>, action: TextAction, p: f32, top_p: f32) -> ContextualWordsAugmenter {
+        ContextualWordsAugmenter::new(
+            model,
+            action,
+            TextAugmentParameters::new(p, None, None),
+            Some(HashSet::from(["The".to_string()])),
+            5,
+            top_p,
+        )
+    }
+
+    #[test_case("The quick fox jumps", 1.0, "The [MASK] [MASK] [MASK]", "The cat cat cat", 3 ; "all words except stopword")]
+    #[test_case("The Quick fox", 1.0, "The [MASK] [MASK]", "The Cat cat", 2 ; "casing of the original word")]
+    #[test_case("The quick fox", 0.0, "", "The quick fox", 0 ; "substitute probability=0")]
+    #[test_case("! ...", 1.0, "", "! ...", 0 ; "no words")]
+    fn test_substitute(input: &str, p: f32, expected_masked: &str, expected: &str, expected_doc_changes: usize) {
+        let model = MockModel::new(&[("cat", 0.9), ("dog", 0.1)]);
+        let aug = augmenter(model.clone(), TextAction::Substitute, p, 0.5);
+
+        let doc = aug.augment_inner(Doc::new(input), &mut rand::thread_rng());
+
+        assert_eq!(doc.to_string(), expected);
+        assert_eq!(doc.num_changes, expected_doc_changes);
+        let inputs = model.inputs.lock().unwrap();
+        assert_eq!(inputs.first().map(String::as_str).unwrap_or(""), expected_masked);
+    }
+
+    #[test]
+    fn test_substitute_skips_original_word() {
+        let aug = augmenter(
+            MockModel::new(&[("fox", 0.9), ("dog", 0.1)]),
+            TextAction::Substitute,
+            1.0,
+            0.5,
+        );
+
+        let output = aug.augment("quick fox".to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, "fox dog");
+    }
+
+    #[test]
+    fn test_insert() {
+        let model = MockModel::new(&[("very", 1.0)]);
+        let aug = augmenter(model.clone(), TextAction::Insert, 1.0, 1.0);
+
+        let doc = aug.augment_inner(Doc::new("The quick fox"), &mut rand::thread_rng());
+
+        assert_eq!(model.inputs.lock().unwrap()[0], "The [MASK] quick [MASK] fox");
+        assert_eq!(doc.to_string(), "The very quick very fox");
+        assert_eq!(doc.num_changes, 2);
+    }
+
+    #[test_case(0.5, vec!["cat"] ; "top-p keeps most probable")]
+    #[test_case(0.85, vec!["cat", "dog"] ; "top-p keeps two")]
+    #[test_case(1.0, vec!["cat", "dog", "cow"] ; "top-p keeps all")]
+    fn test_sample_candidate_top_p(top_p: f32, expected_words: Vec<&str>) {
+        let aug = augmenter(MockModel::new(&[]), TextAction::Substitute, 1.0, top_p);
+        let candidates = vec![
+            ("cat".to_string(), 0.6),
+            ("dog".to_string(), 0.3),
+            ("cow".to_string(), 0.1),
+            ("##s".to_string(), 0.0),
+        ];
+
+        let sampled = (0..200)
+            .filter_map(|_| aug.sample_candidate(candidates.clone(), "fox", &mut rand::thread_rng()))
+            .collect::<HashSet<String>>();
+
+        assert_eq!(sampled, expected_words.into_iter().map(String::from).collect());
+    }
+
+    #[test]
+    fn test_broken_model_unchanged() {
+        let aug = augmenter(Arc::new(BrokenModel), TextAction::Substitute, 1.0, 1.0);
+
+        assert_eq!(
+            aug.augment("quick fox".to_string(), &mut rand::thread_rng()),
+            "quick fox"
+        );
+    }
+
+    #[test_case(TextAction::Swap, 0.9 ; "unsupported action")]
+    #[test_case(TextAction::Substitute, 0.0 ; "zero top_p")]
+    #[test_case(TextAction::Insert, 1.5 ; "top_p > 1")]
+    fn test_try_new_invalid(action: TextAction, top_p: f32) {
+        let result = ContextualWordsAugmenter::try_new(
+            MockModel::new(&[]),
+            action,
+            TextAugmentParameters::new(0.5, None, None),
+            None,
+            5,
+            top_p,
+        );
+
+        assert!(result.is_err());
+    }
+}
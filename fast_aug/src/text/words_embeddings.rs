@@ -1,21 +1,61 @@
-#![allow(dead_code, unused_variables, unreachable_code)]
-
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Casing, Change, Doc, TextAugmentParameters};
+use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
+use finalfusion::prelude::{
+    Embeddings, ReadEmbeddings, ReadFastText, ReadText, ReadTextDims, ReadWord2Vec, StorageViewWrap, VocabWrap,
+};
+use finalfusion::similarity::WordSimilarity;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs::File;
 use std::io::BufReader;
 use std::sync::Arc;
-use finalfusion::prelude::{Embeddings, ReadEmbeddings, ReadFastText, ReadWord2Vec, StorageWrap, VocabWrap};
-use finalfusion::storage::NdArray;
-use finalfusion::vocab::FastTextSubwordVocab;
-use crate::base::BaseAugmenter;
-use super::utils::{Doc, TextAugmentParameters};
-use super::base::{BaseTextAugmenter, TextAction};
 
+/// Embeddings file formats supported by finalfusion readers
+///
+/// EmbeddingsFormat::FinalFusion    -> finalfusion native format (.fifu)
+/// EmbeddingsFormat::FastText       -> fastText binary format (.bin)
+/// EmbeddingsFormat::Word2VecBinary -> word2vec binary format (.bin)
+/// EmbeddingsFormat::Text           -> text format, word followed by vector components, without header
+/// EmbeddingsFormat::TextDims       -> text format with "<num_words> <dims>" header (word2vec/GloVe text)
+/// Serialized in snake_case, e.g. "word2vec_binary"
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmbeddingsFormat {
+    #[serde(rename = "finalfusion")]
+    FinalFusion,
+    #[serde(rename = "fasttext")]
+    FastText,
+    #[serde(rename = "word2vec_binary")]
+    Word2VecBinary,
+    Text,
+    TextDims,
+}
 
+/// Augmenter that substitutes random words with one of the top k most similar words by embeddings
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::text::{EmbeddingsFormat, EmbeddingsWordsAugmenter, TextAugmentParameters};
+///
+/// let rng = &mut rand::thread_rng();
+/// let augmenter = EmbeddingsWordsAugmenter::from_path(
+///     "../test_data/embeddings_en.txt",
+///     EmbeddingsFormat::TextDims,
+///     TextAugmentParameters::new(0.5, None, None),
+///     None,
+///     3,
+/// ).unwrap();
+/// augmenter.augment("The king has a dog!".to_string(), rng);
+/// augmenter.augment_batch(vec!["The king has a dog!".to_string()], rng);
+/// ```
 pub struct EmbeddingsWordsAugmenter {
     /// Finalfusion embeddings
-    embeddings: Arc<Embeddings<FastTextSubwordVocab, NdArray>>,
-    /// Action to augmentation, set of values {'substitute', 'swap', 'delete'}
+    embeddings: Arc<Embeddings<VocabWrap, StorageViewWrap>>,
+    /// Action to augmentation, set of values {'substitute'}
     action: TextAction,
     /// Parameters to calculate number of words that will be augmented
     word_params: TextAugmentParameters,
@@ -23,53 +63,123 @@ pub struct EmbeddingsWordsAugmenter {
     stopwords: Option<HashSet<String>>,
     /// top k similar words to substitute
     top_k: usize,
+    /// Casing of the new words, see `Casing`
+    casing: Casing,
 }
 
-
 impl EmbeddingsWordsAugmenter {
-    pub fn new(
+    /// Create augmenter loading embeddings from the file
+    /// Returns error if word parameters are invalid or embeddings can not be read
+    ///
+    /// # Arguments
+    /// * `embeddings_path` - Path to the embeddings file
+    /// * `format` - Format of the embeddings file
+    /// * `word_params` - Parameters to calculate number of words that will be augmented
+    /// * `stopwords` - Filter, Set of words that cannot be augmented
+    /// * `top_k` - Number of most similar words to select substitution from
+    pub fn from_path(
         embeddings_path: &str,
+        format: EmbeddingsFormat,
         word_params: TextAugmentParameters,
         stopwords: Option<HashSet<String>>,
         top_k: usize,
-    ) -> Self {
-        // .expect("Cannot open embeddings file")
-        let mut reader = BufReader::new(File::open(embeddings_path).unwrap());
-
-        // let embeddings_0 = Embeddings::read_embeddings(&mut reader).unwrap();
-        let embeddings_1 = Embeddings::read_fasttext(&mut reader).unwrap();
-        // let embeddings_2 = Embeddings::read_word2vec_binary(&mut reader).unwrap();
+    ) -> Result<Self> {
+        word_params.validate()?;
+        let embeddings = Self::load_embeddings(embeddings_path, format)?;
+        Self::from_embeddings(Arc::new(embeddings), word_params, stopwords, top_k)
+    }
 
-        EmbeddingsWordsAugmenter {
-            embeddings: Arc::new(embeddings_1),
+    /// Create augmenter from already loaded embeddings, so they can be shared between augmenters
+    /// Returns error if word parameters are invalid
+    ///
+    /// # Arguments
+    /// * `embeddings` - Loaded embeddings, see `load_embeddings`
+    /// * `word_params` - Parameters to calculate number of words that will be augmented
+    /// * `stopwords` - Filter, Set of words that cannot be augmented
+    /// * `top_k` - Number of most similar words to select substitution from
+    pub fn from_embeddings(
+        embeddings: Arc<Embeddings<VocabWrap, StorageViewWrap>>,
+        word_params: TextAugmentParameters,
+        stopwords: Option<HashSet<String>>,
+        top_k: usize,
+    ) -> Result<Self> {
+        word_params.validate()?;
+        Ok(EmbeddingsWordsAugmenter {
+            embeddings,
             action: TextAction::Substitute,
             word_params,
             stopwords,
             top_k,
-        }
+            casing: Casing::Preserve,
+        })
+    }
+
+    /// Set casing of the new words, matches casing of the original words by default
+    pub fn with_casing(mut self, casing: Casing) -> Self {
+        self.casing = casing;
+        self
+    }
+
+    /// Load embeddings from file using respective finalfusion reader
+    ///
+    /// # Arguments
+    /// * `embeddings_path` - Path to the embeddings file
+    /// * `format` - Format of the embeddings file
+    pub fn load_embeddings(
+        embeddings_path: &str,
+        format: EmbeddingsFormat,
+    ) -> Result<Embeddings<VocabWrap, StorageViewWrap>> {
+        let file = File::open(embeddings_path).map_err(Error::Io)?;
+        let mut reader = BufReader::new(file);
+
+        let embeddings = match format {
+            EmbeddingsFormat::FinalFusion => Embeddings::read_embeddings(&mut reader)?,
+            EmbeddingsFormat::FastText => Embeddings::read_fasttext(&mut reader)?.into(),
+            EmbeddingsFormat::Word2VecBinary => Embeddings::read_word2vec_binary(&mut reader)?.into(),
+            EmbeddingsFormat::Text => Embeddings::read_text(&mut reader)?.into(),
+            EmbeddingsFormat::TextDims => Embeddings::read_text_dims(&mut reader)?.into(),
+        };
+        Ok(embeddings)
     }
 
     fn substitute(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
         // Select random word tokens
         let word_tokens_indexes = doc.get_word_indexes(false, self.stopwords.as_ref());
         let num_tokens_to_change = self.word_params.num_elements(word_tokens_indexes.len());
-        let selected_tokens_indexes = self.select_random_element_indexes(rng, word_tokens_indexes, num_tokens_to_change);
+        let selected_tokens_indexes =
+            self.select_random_element_indexes(rng, word_tokens_indexes, num_tokens_to_change);
 
         // For all selected tokens select some random similar word and substitute
+        // Words without embeddings (and without subwords for fastText) are left unchanged
         for index in selected_tokens_indexes {
-            todo!();
-            doc.num_changes += 1;
+            let token = &mut doc.tokens[index];
+            let similar_words = match self.embeddings.word_similarity(token.token(), self.top_k, None) {
+                Some(similar_words) => similar_words,
+                None => continue,
+            };
+            if let Some(similar_word) = similar_words.choose(rng) {
+                let new_token = self.casing.apply(token.token(), similar_word.word(), rng);
+                let old_token = token.change(&new_token, *token.kind());
+                doc.record_change(|tokens| {
+                    Change::new(
+                        "EmbeddingsWordsAugmenter",
+                        TextAction::Substitute,
+                        Some(index),
+                        Some(index),
+                        &old_token,
+                        tokens[index].token(),
+                    )
+                });
+            }
         }
 
         doc
     }
 }
 
+impl BaseTextAugmenter for EmbeddingsWordsAugmenter {}
 
-impl BaseTextAugmenter for EmbeddingsWordsAugmenter{}
-
-
-impl BaseAugmenter<String,Doc> for EmbeddingsWordsAugmenter {
+impl BaseAugmenter<String, Doc> for EmbeddingsWordsAugmenter {
     fn augment_inner(&self, input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
         match self.action {
             TextAction::Substitute => self.substitute(input, rng),
@@ -86,21 +196,102 @@ impl BaseAugmenter<String,Doc> for EmbeddingsWordsAugmenter {
     }
 }
 
-
 #[cfg(test)]
 mod tests {
-    use test_case::test_case;
     use super::*;
+    use finalfusion::vocab::Vocab;
+    use test_case::test_case;
 
-    // #[test_case("test_data/cc.en.100.bin" ; "fasttext")]
-    // fn test_load_embeddings(embeddings_path: &str) {
-    //     let embeddings_augmenter = EmbeddingsWordsAugmenter::new(
-    //         embeddings_path,
-    //         TextAugmentParameters::default(),
-    //         None,
-    //         5,
-    //     );
-    // }
+    const EMBEDDINGS_TEXT_DIMS_PATH: &str = "../test_data/embeddings_en.txt";
+    const EMBEDDINGS_WORD2VEC_PATH: &str = "../test_data/embeddings_en.bin";
+    const EMBEDDINGS_FINALFUSION_PATH: &str = "../test_data/embeddings_en.fifu";
+    const EMBEDDINGS_FASTTEXT_PATH: &str = "../test_data/embeddings_en_fasttext.bin";
 
-}
+    #[test_case(EMBEDDINGS_TEXT_DIMS_PATH, EmbeddingsFormat::TextDims ; "text with dims")]
+    #[test_case(EMBEDDINGS_WORD2VEC_PATH, EmbeddingsFormat::Word2VecBinary ; "word2vec binary")]
+    #[test_case(EMBEDDINGS_FINALFUSION_PATH, EmbeddingsFormat::FinalFusion ; "finalfusion")]
+    fn test_load_embeddings(embeddings_path: &str, format: EmbeddingsFormat) {
+        let embeddings = EmbeddingsWordsAugmenter::load_embeddings(embeddings_path, format).unwrap();
+
+        assert!(embeddings.embedding("king").is_some());
+        assert!(embeddings.embedding("not_existing_word").is_none());
+    }
+
+    #[test]
+    fn test_load_embeddings_fasttext() {
+        let embeddings =
+            EmbeddingsWordsAugmenter::load_embeddings(EMBEDDINGS_FASTTEXT_PATH, EmbeddingsFormat::FastText).unwrap();
+
+        assert_eq!(embeddings.vocab().words_len(), 16);
+        assert!(embeddings.embedding("king").is_some());
+        // Unknown words are embedded with their subwords
+        assert!(embeddings.embedding("not_existing_word").is_some());
+    }
+
+    #[test_case("../test_data/not_existing_file.txt", EmbeddingsFormat::TextDims ; "not existing file")]
+    #[test_case(EMBEDDINGS_TEXT_DIMS_PATH, EmbeddingsFormat::FinalFusion ; "text as finalfusion")]
+    #[test_case(EMBEDDINGS_TEXT_DIMS_PATH, EmbeddingsFormat::FastText ; "text as fasttext")]
+    fn test_load_embeddings_error(embeddings_path: &str, format: EmbeddingsFormat) {
+        let result =
+            EmbeddingsWordsAugmenter::from_path(embeddings_path, format, TextAugmentParameters::default(), None, 3);
+        assert!(result.is_err());
+    }
+
+    #[test_case(TextAugmentParameters::new(1.5, None, None) ; "probability above 1")]
+    #[test_case(TextAugmentParameters::new(-0.1, None, None) ; "negative probability")]
+    fn test_from_embeddings_invalid_params(word_params: TextAugmentParameters) {
+        let embeddings =
+            EmbeddingsWordsAugmenter::load_embeddings(EMBEDDINGS_TEXT_DIMS_PATH, EmbeddingsFormat::TextDims).unwrap();
+
+        let result = EmbeddingsWordsAugmenter::from_embeddings(Arc::new(embeddings), word_params, None, 3);
+
+        assert!(result.is_err());
+    }
 
+    #[test_case(vec!["king", " ", "cat", " ", "car", " ", "happy"], 0.5, 2 ; "2 of 4 words")]
+    #[test_case(vec!["king", " ", "cat", " ", "car", " ", "happy"], 1.0, 4 ; "all words")]
+    #[test_case(vec!["king", " ", "cat", " ", "car", " ", "happy"], 0.0, 0 ; "substitute probability=0")]
+    #[test_case(vec!["unknown", " ", "words", "!"], 1.0, 0 ; "no words in embeddings")]
+    fn test_substitute(input_tokens: Vec<&str>, p: f32, expected_doc_changes: usize) {
+        let mut doc = Doc::from_tokens(input_tokens);
+        let params = TextAugmentParameters::new(p, None, None);
+        let aug =
+            EmbeddingsWordsAugmenter::from_path(EMBEDDINGS_TEXT_DIMS_PATH, EmbeddingsFormat::TextDims, params, None, 3)
+                .unwrap();
+
+        let doc_tokens_before = doc.tokens.clone();
+
+        doc = aug.augment_inner(doc, &mut rand::thread_rng());
+
+        let doc_tokens_after = doc.tokens.clone();
+
+        assert_eq!(doc_tokens_before.len(), doc_tokens_after.len());
+        assert_eq!(doc.num_changes, expected_doc_changes);
+        let num_changed_words = doc_tokens_before
+            .iter()
+            .zip(doc_tokens_after.iter())
+            .filter(|(before, after)| before != after)
+            .count();
+        assert_eq!(num_changed_words, expected_doc_changes);
+    }
+
+    #[test_case(EMBEDDINGS_TEXT_DIMS_PATH, EmbeddingsFormat::TextDims, "king", vec!["queen", "prince", "princess"] ; "text king")]
+    #[test_case(EMBEDDINGS_TEXT_DIMS_PATH, EmbeddingsFormat::TextDims, "cat", vec!["dog", "puppy", "kitten"] ; "text cat")]
+    #[test_case(EMBEDDINGS_TEXT_DIMS_PATH, EmbeddingsFormat::TextDims, "happy", vec!["glad", "joyful", "cheerful"] ; "text happy")]
+    #[test_case(EMBEDDINGS_FASTTEXT_PATH, EmbeddingsFormat::FastText, "king", vec!["queen", "prince", "princess"] ; "fasttext king")]
+    #[test_case(EMBEDDINGS_FASTTEXT_PATH, EmbeddingsFormat::FastText, "cat", vec!["dog", "puppy", "kitten"] ; "fasttext cat")]
+    fn test_substitute_top_k_similar(
+        embeddings_path: &str,
+        format: EmbeddingsFormat,
+        word: &str,
+        expected_words: Vec<&str>,
+    ) {
+        let params = TextAugmentParameters::new(1.0, None, None);
+        let aug = EmbeddingsWordsAugmenter::from_path(embeddings_path, format, params, None, 3).unwrap();
+
+        for _ in 0..20 {
+            let output = aug.augment(word.to_string(), &mut rand::thread_rng());
+            assert!(expected_words.contains(&output.as_str()));
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Change, Doc, TextAugmentParameters, Token, TokenType};
+use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
+use crate::models::text::EmojiModel;
+use rand::seq::SliceRandom;
+use std::collections::HashSet;
+
+/// Augmenter that inserts emoji after random words or substitutes random words with emoji
+/// Emoji are selected by CLDR annotation keywords, e.g. "pizza" can be followed or replaced with "🍕".
+/// Only words which are keywords of some emoji in the emoji model can be augmented.
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::models::text::EmojiModel;
+/// use fast_aug::text::{TextAction, TextAugmentParameters, WordsEmojiAugmenter};
+///
+/// let rng = &mut rand::thread_rng();
+/// let augmenter = WordsEmojiAugmenter::new(
+///     TextAction::Insert,
+///     TextAugmentParameters::new(1.0, None, None),
+///     EmojiModel::from_locale_str("en"),
+///     None,
+/// );
+/// assert_eq!(augmenter.augment("I want pizza".to_string(), rng), "I want pizza 🍕");
+/// ```
+pub struct WordsEmojiAugmenter {
+    /// Action to augmentation, set of values {'insert', 'substitute'}
+    action: TextAction,
+    /// Parameters to calculate number of words that will be augmented
+    word_params: TextAugmentParameters,
+    /// Emoji Model with CLDR annotations
+    emoji_model: EmojiModel,
+    /// Filter, Set of words that cannot be augmented
+    stopwords: Option<HashSet<String>>,
+}
+
+impl WordsEmojiAugmenter {
+    /// Create augmenter, action and word parameters are not validated, see `try_new`
+    pub fn new(
+        action: TextAction,
+        word_params: TextAugmentParameters,
+        emoji_model: EmojiModel,
+        stopwords: Option<HashSet<String>>,
+    ) -> Self {
+        WordsEmojiAugmenter {
+            action,
+            word_params,
+            emoji_model,
+            stopwords,
+        }
+    }
+
+    /// Create augmenter, returns error if action is not insert or substitute, or word parameters are invalid
+    pub fn try_new(
+        action: TextAction,
+        word_params: TextAugmentParameters,
+        emoji_model: EmojiModel,
+        stopwords: Option<HashSet<String>>,
+    ) -> Result<Self> {
+        if action != TextAction::Insert && action != TextAction::Substitute {
+            return Err(Error::UnsupportedAction(format!(
+                "WordsEmojiAugmenter supports insert and substitute, got {:?}",
+                action
+            )));
+        }
+        word_params.validate()?;
+        Ok(Self::new(action, word_params, emoji_model, stopwords))
+    }
+
+    /// Select random word tokens, only keywords of some emoji can be selected
+    /// Returns indexes in ascending order
+    fn select_word_indexes(&self, doc: &mut Doc, rng: &mut dyn rand::RngCore) -> Vec<usize> {
+        let word_tokens_indexes = doc.get_word_indexes(false, self.stopwords.as_ref());
+        let num_tokens_to_change = self.word_params.num_elements(word_tokens_indexes.len());
+        let candidate_tokens_indexes = word_tokens_indexes
+            .into_iter()
+            .filter(|&idx| !self.emoji_model.get_emojis(doc.tokens[idx].token()).is_empty())
+            .collect::<Vec<usize>>();
+        let mut selected_tokens_indexes =
+            self.select_random_element_indexes(rng, candidate_tokens_indexes, num_tokens_to_change);
+        selected_tokens_indexes.sort_unstable();
+        selected_tokens_indexes
+    }
+
+    fn choose_emoji(&self, word: &str, rng: &mut dyn rand::RngCore) -> String {
+        self.emoji_model.get_emojis(word).choose(rng).unwrap().to_string()
+    }
+
+    fn substitute(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        for index in self.select_word_indexes(&mut doc, rng) {
+            let emoji = self.choose_emoji(doc.tokens[index].token(), rng);
+            let old_token = doc.tokens[index].change(&emoji, TokenType::Special);
+
+            doc.record_change(|tokens| {
+                Change::new(
+                    "WordsEmojiAugmenter",
+                    TextAction::Substitute,
+                    Some(index),
+                    Some(index),
+                    &old_token,
+                    tokens[index].token(),
+                )
+            });
+        }
+
+        doc
+    }
+
+    fn insert(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        // Space and emoji are placed after each selected word, each insertion shifts the following words by 2 tokens
+        for (num_inserted_before, selected_index) in self.select_word_indexes(&mut doc, rng).into_iter().enumerate() {
+            let index = selected_index + 2 * num_inserted_before;
+            let emoji = self.choose_emoji(doc.tokens[index].token(), rng);
+            doc.tokens.splice(
+                index + 1..index + 1,
+                [
+                    Token::new(" ", TokenType::Space),
+                    Token::new(&emoji, TokenType::Special),
+                ],
+            );
+
+            let new_index = index + 2;
+            doc.record_change(|tokens| {
+                Change::new(
+                    "WordsEmojiAugmenter",
+                    TextAction::Insert,
+                    None,
+                    Some(new_index),
+                    "",
+                    tokens[new_index].token(),
+                )
+            });
+        }
+
+        doc
+    }
+}
+
+impl BaseTextAugmenter for WordsEmojiAugmenter {}
+
+impl BaseAugmenter<String, Doc> for WordsEmojiAugmenter {
+    fn augment_inner(&self, input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        match self.action {
+            TextAction::Insert => self.insert(input, rng),
+            TextAction::Substitute => self.substitute(input, rng),
+            _ => panic!("Action not implemented"),
+        }
+    }
+
+    fn convert_to_inner(&self, input: String) -> Doc {
+        Doc::new(&input)
+    }
+
+    fn convert_to_outer(&self, input: Doc) -> String {
+        input.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::AugmentWithChanges;
+    use test_case::test_case;
+
+    fn augmenter(action: TextAction, p: f32) -> WordsEmojiAugmenter {
+        WordsEmojiAugmenter::new(
+            action,
+            TextAugmentParameters::new(p, None, None),
+            EmojiModel::from_locale_str("en"),
+            None,
+        )
+    }
+
+    #[test_case(TextAction::Insert, "Pizza and beer!", "Pizza 🍕 and beer 🍺!" ; "insert")]
+    #[test_case(TextAction::Substitute, "Pizza and beer!", "🍕 and 🍺!" ; "substitute")]
+    #[test_case(TextAction::Insert, "and or", "and or" ; "no keywords")]
+    fn test_augment(action: TextAction, input: &str, expected: &str) {
+        let aug = augmenter(action, 1.0);
+
+        let output = aug.augment(input.to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, expected);
+    }
+
+    #[test_case(TextAction::Insert ; "insert")]
+    #[test_case(TextAction::Substitute ; "substitute")]
+    fn test_augment_emoji_share_keyword(action: TextAction) {
+        let aug = augmenter(action, 1.0);
+        let emoji_model = EmojiModel::from_locale_str("en");
+
+        let doc = aug.augment_inner(Doc::new("love"), &mut rand::thread_rng());
+
+        let emoji = doc.tokens.last().unwrap().token();
+        assert!(emoji_model.get_keywords(emoji).contains(&&"love".to_string()));
+    }
+
+    #[test_case(TextAction::Insert, 0.5, 2 ; "insert round 2 of 4 words")]
+    #[test_case(TextAction::Substitute, 0.5, 2 ; "substitute round 2 of 4 words")]
+    #[test_case(TextAction::Insert, 0.0, 0 ; "probability=0")]
+    fn test_augment_num_changes(action: TextAction, p: f32, expected_doc_changes: usize) {
+        let aug = augmenter(action, p);
+
+        let doc = aug.augment_inner(Doc::new("pizza beer coffee fire"), &mut rand::thread_rng());
+
+        assert_eq!(doc.num_changes, expected_doc_changes);
+    }
+
+    #[test]
+    fn test_insert_with_changes() {
+        let aug = augmenter(TextAction::Insert, 1.0);
+
+        let (output, changes) = aug.augment_with_changes("pizza or beer".to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, "pizza 🍕 or beer 🍺");
+        assert_eq!(changes.len(), 2);
+        assert_eq!((changes[0].new_index, changes[0].new_text.as_str()), (Some(2), "🍕"));
+        assert_eq!((changes[1].new_index, changes[1].new_text.as_str()), (Some(8), "🍺"));
+    }
+
+    #[test]
+    fn test_augment_with_stopwords() {
+        let stopwords = HashSet::from(["pizza".to_string()]);
+        let aug = WordsEmojiAugmenter::new(
+            TextAction::Substitute,
+            TextAugmentParameters::new(1.0, None, None),
+            EmojiModel::from_locale_str("en"),
+            Some(stopwords),
+        );
+
+        let output = aug.augment("pizza beer".to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, "pizza 🍺");
+    }
+
+    #[test_case(TextAction::Delete ; "delete")]
+    #[test_case(TextAction::Swap ; "swap")]
+    fn test_try_new_unsupported_action(action: TextAction) {
+        let params = TextAugmentParameters::new(0.5, None, None);
+        assert!(matches!(
+            WordsEmojiAugmenter::try_new(action, params, EmojiModel::from_locale_str("en"), None),
+            Err(Error::UnsupportedAction(_))
+        ));
+    }
+}
@@ -1,6 +1,7 @@
-use super::base::BaseTextAugmenter;
-use super::utils::{Doc, TextAugmentParameters, TokenType};
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Change, Doc, TextAugmentParameters, TokenType};
 use crate::base::BaseAugmenter;
+use crate::error::Result;
 use std::collections::HashSet;
 
 /// Augmenter that deletes random words in text
@@ -29,6 +30,12 @@ impl WordsRandomDeleteAugmenter {
     pub fn new(word_params: TextAugmentParameters, stopwords: Option<HashSet<String>>) -> Self {
         WordsRandomDeleteAugmenter { word_params, stopwords }
     }
+
+    /// Create augmenter, returns error if word parameters are invalid
+    pub fn try_new(word_params: TextAugmentParameters, stopwords: Option<HashSet<String>>) -> Result<Self> {
+        word_params.validate()?;
+        Ok(WordsRandomDeleteAugmenter::new(word_params, stopwords))
+    }
 }
 
 impl BaseTextAugmenter for WordsRandomDeleteAugmenter {}
@@ -43,8 +50,17 @@ impl BaseAugmenter<String, Doc> for WordsRandomDeleteAugmenter {
 
         // For all selected tokens set TokenType::Deleted
         for index in selected_tokens_indexes {
-            input.tokens[index].change("", TokenType::Deleted);
-            input.num_changes += 1;
+            let old_token = input.tokens[index].change("", TokenType::Deleted);
+            input.record_change(|_| {
+                Change::new(
+                    "WordsRandomDeleteAugmenter",
+                    TextAction::Delete,
+                    Some(index),
+                    None,
+                    &old_token,
+                    "",
+                )
+            });
         }
 
         input
@@ -1,21 +1,24 @@
-use super::base::BaseTextAugmenter;
-use super::utils::{Doc, TextAugmentParameters};
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Casing, Change, Doc, TextAugmentParameters};
 use crate::base::BaseAugmenter;
-use crate::text::Token;
-use rand::prelude::SliceRandom;
+use crate::error::{Error, Result};
+use crate::models::text::VocabModel;
+use crate::text::{Token, TokenType};
 use std::collections::HashSet;
 
 /// Augmenter that substitutes random words with random words from vocabulary
+/// Vocabulary is either a plain list of words or `VocabModel` with word weights (e.g. frequencies embedded for the language)
 ///
 /// # Examples
 /// ```rust
 /// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::models::text::VocabModel;
 /// use fast_aug::text::{WordsRandomInsertAugmenter, TextAugmentParameters};
 ///
 /// let rng = &mut rand::thread_rng();
 /// let augmenter = WordsRandomInsertAugmenter::new(
 ///     TextAugmentParameters::new(0.5, None, None),
-///     vec!["A", "B", "C", "D", "E"].into_iter().map(|s| s.to_string()).collect(),
+///     VocabModel::from_locale_str("en"),
 ///     None,
 /// );
 /// augmenter.augment("Some text!".to_string(), rng);
@@ -24,25 +27,48 @@ use std::collections::HashSet;
 pub struct WordsRandomInsertAugmenter {
     /// Parameters to calculate number of words that will be augmented
     word_params: TextAugmentParameters,
-    /// Set of words that can be used to replace or insert
-    vocabulary: Vec<String>,
+    /// Words that can be used to replace or insert, sampled proportionally to their weights
+    vocabulary: VocabModel,
     /// Filter, Set of words that cannot be augmented
     #[allow(dead_code)]
     stopwords: Option<HashSet<String>>,
+    /// Casing of the new words, see `Casing`
+    casing: Casing,
 }
 
 impl WordsRandomInsertAugmenter {
     pub fn new(
         word_params: TextAugmentParameters,
-        vocabulary: Vec<String>,
+        vocabulary: impl Into<VocabModel>,
         stopwords: Option<HashSet<String>>,
     ) -> Self {
         WordsRandomInsertAugmenter {
             word_params,
-            vocabulary,
+            vocabulary: vocabulary.into(),
             stopwords,
+            casing: Casing::Preserve,
         }
     }
+
+    /// Create augmenter, returns error if word parameters are invalid or vocabulary is empty
+    pub fn try_new(
+        word_params: TextAugmentParameters,
+        vocabulary: impl Into<VocabModel>,
+        stopwords: Option<HashSet<String>>,
+    ) -> Result<Self> {
+        word_params.validate()?;
+        let vocabulary = vocabulary.into();
+        if vocabulary.is_empty() {
+            return Err(Error::EmptyVocabulary);
+        }
+        Ok(WordsRandomInsertAugmenter::new(word_params, vocabulary, stopwords))
+    }
+
+    /// Set casing of the new words, matches casing of the original words by default
+    pub fn with_casing(mut self, casing: Casing) -> Self {
+        self.casing = casing;
+        self
+    }
 }
 
 impl BaseTextAugmenter for WordsRandomInsertAugmenter {}
@@ -52,18 +78,24 @@ impl BaseAugmenter<String, Doc> for WordsRandomInsertAugmenter {
         // Select random places to insert tokens from 0 (before the first token) to len inclusive (after last token)
         let num_tokens_to_insert = self.word_params.num_elements(input.tokens.len());
         let mut selected_places_to_insert_indexes =
-            self.select_random_element_indexes(rng, (0..=input.tokens.len()).collect(), num_tokens_to_insert);
+            self.select_random_element_indexes(rng, input.get_insert_indexes(), num_tokens_to_insert);
         selected_places_to_insert_indexes.sort();
 
         // Select random words from vocabulary - with replacement
-        let mut tokens_to_insert: Vec<Token> = (0..num_tokens_to_insert)
-            .map(|_| self.vocabulary.choose(rng).map(|word| Token::from_str(word)).unwrap())
+        let mut tokens_to_insert: Vec<Token> = (0..selected_places_to_insert_indexes.len())
+            .map(|_| {
+                self.vocabulary
+                    .get_random_word(rng)
+                    .map(|word| Token::from_str(word))
+                    .unwrap()
+            })
             .collect();
         assert_eq!(selected_places_to_insert_indexes.len(), tokens_to_insert.len());
 
         // Insert tokens in selected places
         // Go through all positions to insert, if have original doc token insert it else insert new token
         let mut new_tokens: Vec<Token> = Vec::with_capacity(input.tokens.len() + tokens_to_insert.len());
+        let mut inserted_tokens_indexes: Vec<usize> = Vec::with_capacity(num_tokens_to_insert);
         let mut current_doc_index = 0;
         for place_to_insert_index in selected_places_to_insert_indexes {
             // Add tokens until current place
@@ -73,7 +105,19 @@ impl BaseAugmenter<String, Doc> for WordsRandomInsertAugmenter {
             }
             // If current place < than current doc index, add inserted token
             if place_to_insert_index <= current_doc_index {
-                new_tokens.push(tokens_to_insert.pop().unwrap());
+                // Casing follows the closest word, the next one or the previous one at the end of the text
+                let neighbour = input.tokens[current_doc_index..]
+                    .iter()
+                    .chain(input.tokens[..current_doc_index].iter().rev())
+                    .find(|token| token.kind() == &TokenType::Word)
+                    .map_or("", |token| token.token());
+                let token = tokens_to_insert.pop().unwrap();
+                inserted_tokens_indexes.push(new_tokens.len());
+                new_tokens.push(Token::from_str(&self.casing.apply_inserted(
+                    neighbour,
+                    token.token(),
+                    rng,
+                )));
             }
         }
         // Insert remaining tokens
@@ -81,8 +125,19 @@ impl BaseAugmenter<String, Doc> for WordsRandomInsertAugmenter {
             new_tokens.push(input.tokens[current_doc_index].clone());
             current_doc_index += 1;
         }
-        input.num_changes = num_tokens_to_insert;
         input.tokens = new_tokens;
+        for index in inserted_tokens_indexes {
+            input.record_change(|tokens| {
+                Change::new(
+                    "WordsRandomInsertAugmenter",
+                    TextAction::Insert,
+                    None,
+                    Some(index),
+                    "",
+                    tokens[index].token(),
+                )
+            });
+        }
 
         input
     }
@@ -115,7 +170,7 @@ mod tests {
     ) {
         let mut doc = Doc::from_tokens(input_tokens);
         let params = TextAugmentParameters::new(p, None, None);
-        let vocab = vocab.into_iter().map(|s| s.to_string()).collect();
+        let vocab: Vec<String> = vocab.into_iter().map(|s| s.to_string()).collect();
         let aug = WordsRandomInsertAugmenter::new(params, vocab, None);
 
         let doc_tokens_before = doc.tokens.clone();
@@ -132,4 +187,30 @@ mod tests {
             assert_eq!(doc.num_changes, expected_doc_changes);
         }
     }
+
+    #[test_case(0.5, vec![] ; "empty vocabulary")]
+    #[test_case(1.5, vec!["A"] ; "invalid probability")]
+    fn test_try_new_invalid(p: f32, vocab: Vec<&str>) {
+        let params = TextAugmentParameters::new(p, None, None);
+        let vocab: Vec<String> = vocab.into_iter().map(|s| s.to_string()).collect();
+        assert!(WordsRandomInsertAugmenter::try_new(params, vocab, None).is_err());
+    }
+
+    #[test_case("HELLO WORLD", Casing::Preserve, "BIG" ; "preserve upper")]
+    #[test_case("Hello world", Casing::Preserve, "big" ; "preserve title")]
+    #[test_case("HELLO WORLD", Casing::AsIs, "Big" ; "as is")]
+    fn test_insert_casing(text: &str, casing: Casing, expected_word: &str) {
+        let aug = WordsRandomInsertAugmenter::new(
+            TextAugmentParameters::new(0.5, None, None),
+            vec!["Big".to_string()],
+            None,
+        )
+        .with_casing(casing);
+
+        let doc = aug.augment_inner(Doc::new(text), &mut rand::thread_rng());
+
+        let inserted_words = doc.tokens.iter().filter(|token| token.token().to_lowercase() == "big");
+        assert!(inserted_words.clone().count() > 0);
+        assert!(inserted_words.into_iter().all(|token| token.token() == expected_word));
+    }
 }
@@ -1,11 +1,15 @@
-use super::base::BaseTextAugmenter;
-use super::utils::{Doc, TextAugmentParameters};
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{is_pos_of_class, Casing, Change, Doc, TextAugmentParameters};
 use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
+use crate::models::text::VocabModel;
 use crate::text::Token;
-use rand::prelude::SliceRandom;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /// Augmenter that substitutes random words with random words from vocabulary
+/// With POS vocabulary (see `with_pos_vocabulary`) words tagged with POS tags (e.g. by `RandomPosAugmenter`)
+/// are substituted with vocabulary words of their POS class, other words with words from the main vocabulary
+/// Tag belongs to a class if it starts with it, as in `PosFilter` (e.g. "JJR" uses vocabulary of "JJ" or "JJR")
 ///
 /// # Examples
 /// ```rust
@@ -15,7 +19,7 @@ use std::collections::HashSet;
 /// let rng = &mut rand::thread_rng();
 /// let augmenter = WordsRandomSubstituteAugmenter::new(
 ///     TextAugmentParameters::new(0.5, None, None),
-///     vec!["A", "B", "C", "D", "E"].into_iter().map(|s| s.to_string()).collect(),
+///     vec!["A", "B", "C", "D", "E"].into_iter().map(|s| s.to_string()).collect::<Vec<String>>(),
 ///     None,
 /// );
 /// augmenter.augment("Some text!".to_string(), rng);
@@ -24,22 +28,85 @@ use std::collections::HashSet;
 pub struct WordsRandomSubstituteAugmenter {
     /// Parameters to calculate number of words that will be augmented
     word_params: TextAugmentParameters,
-    /// Set of words that can be used to replace or insert
-    vocabulary: Vec<String>,
+    /// Words that can be used to replace or insert, sampled proportionally to their weights
+    vocabulary: VocabModel,
     /// Filter, Set of words that cannot be augmented
     stopwords: Option<HashSet<String>>,
+    /// Words that can be used to replace words of each POS tag
+    pos_vocabulary: Option<HashMap<String, VocabModel>>,
+    /// Casing of the new words, see `Casing`
+    casing: Casing,
 }
 
 impl WordsRandomSubstituteAugmenter {
     pub fn new(
         word_params: TextAugmentParameters,
-        vocabulary: Vec<String>,
+        vocabulary: impl Into<VocabModel>,
         stopwords: Option<HashSet<String>>,
     ) -> Self {
         WordsRandomSubstituteAugmenter {
             word_params,
-            vocabulary,
+            vocabulary: vocabulary.into(),
             stopwords,
+            pos_vocabulary: None,
+            casing: Casing::Preserve,
+        }
+    }
+
+    /// Create augmenter, returns error if word parameters are invalid or vocabulary is empty
+    pub fn try_new(
+        word_params: TextAugmentParameters,
+        vocabulary: impl Into<VocabModel>,
+        stopwords: Option<HashSet<String>>,
+    ) -> Result<Self> {
+        word_params.validate()?;
+        let vocabulary = vocabulary.into();
+        if vocabulary.is_empty() {
+            return Err(Error::EmptyVocabulary);
+        }
+        Ok(WordsRandomSubstituteAugmenter::new(word_params, vocabulary, stopwords))
+    }
+
+    /// Set casing of the new words, matches casing of the original words by default
+    pub fn with_casing(mut self, casing: Casing) -> Self {
+        self.casing = casing;
+        self
+    }
+
+    /// Set vocabulary for words of each POS class, classes without words are ignored
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use fast_aug::text::{WordsRandomSubstituteAugmenter, TextAugmentParameters};
+    ///
+    /// let augmenter = WordsRandomSubstituteAugmenter::new(
+    ///     TextAugmentParameters::new(0.5, None, None),
+    ///     vec!["thing".to_string()],
+    ///     None,
+    /// )
+    /// .with_pos_vocabulary(HashMap::from([("JJ".to_string(), vec!["big".to_string(), "small".to_string()])]));
+    /// ```
+    pub fn with_pos_vocabulary(mut self, pos_vocabulary: HashMap<String, impl Into<VocabModel>>) -> Self {
+        let pos_vocabulary = pos_vocabulary
+            .into_iter()
+            .map(|(pos, vocabulary)| (pos, vocabulary.into()))
+            .filter(|(_, vocabulary): &(String, VocabModel)| !vocabulary.is_empty())
+            .collect();
+        self.pos_vocabulary = Some(pos_vocabulary);
+        self
+    }
+
+    /// Get vocabulary for the word with the given POS tag, the longest class the tag belongs to is used
+    /// Main vocabulary if there are no words for the tag
+    fn get_vocabulary(&self, pos: Option<&str>) -> &VocabModel {
+        match (&self.pos_vocabulary, pos) {
+            (Some(pos_vocabulary), Some(pos)) => pos_vocabulary
+                .iter()
+                .filter(|(class, _)| is_pos_of_class(pos, class))
+                .max_by_key(|(class, _)| class.len())
+                .map_or(&self.vocabulary, |(_, vocabulary)| vocabulary),
+            _ => &self.vocabulary,
         }
     }
 }
@@ -54,16 +121,24 @@ impl BaseAugmenter<String, Doc> for WordsRandomSubstituteAugmenter {
         let selected_tokens_indexes =
             self.select_random_element_indexes(rng, word_tokens_indexes, num_tokens_to_change);
 
-        // Select random words from vocabulary - with replacement
-        let tokens_to_insert: Vec<Token> = (0..num_tokens_to_change)
-            .map(|_| self.vocabulary.choose(rng).map(|word| Token::from_str(word)).unwrap())
-            .collect();
-        assert_eq!(selected_tokens_indexes.len(), tokens_to_insert.len());
-
-        // Substitute tokens in selected places
-        for (index, token) in selected_tokens_indexes.into_iter().zip(tokens_to_insert.into_iter()) {
-            input.tokens[index] = token;
-            input.num_changes += 1;
+        // Substitute tokens in selected places with random words from vocabulary - with replacement
+        for index in selected_tokens_indexes {
+            let token = self
+                .get_vocabulary(input.tokens[index].pos())
+                .get_random_word(rng)
+                .map(|word| Token::from_str(&self.casing.apply(input.tokens[index].token(), word, rng)))
+                .unwrap();
+            let old_token = input.tokens[index].change(token.token(), *token.kind());
+            input.record_change(|tokens| {
+                Change::new(
+                    "WordsRandomSubstituteAugmenter",
+                    TextAction::Substitute,
+                    Some(index),
+                    Some(index),
+                    &old_token,
+                    tokens[index].token(),
+                )
+            });
         }
 
         input
@@ -92,7 +167,7 @@ mod tests {
     fn test_substitute(input_tokens: Vec<&str>, vocab: Vec<&str>, p: f32, expected_doc_changes: usize) {
         let mut doc = Doc::from_tokens(input_tokens);
         let params = TextAugmentParameters::new(p, None, None);
-        let vocab = vocab.into_iter().map(|s| s.to_string()).collect();
+        let vocab: Vec<String> = vocab.into_iter().map(|s| s.to_string()).collect();
         let aug = WordsRandomSubstituteAugmenter::new(params, vocab, None);
 
         let doc_tokens_before = doc.tokens.clone();
@@ -109,4 +184,51 @@ mod tests {
             assert_eq!(doc.num_changes, expected_doc_changes);
         }
     }
+
+    #[test_case(Some("JJ"), "Big" ; "tag with vocabulary")]
+    #[test_case(Some("JJR"), "Big" ; "tag of class with vocabulary")]
+    #[test_case(Some("JJS"), "Biggest" ; "longest class wins")]
+    #[test_case(Some("NN"), "A" ; "tag without vocabulary")]
+    #[test_case(Some("VB"), "A" ; "tag with empty vocabulary")]
+    #[test_case(None, "A" ; "untagged word")]
+    fn test_substitute_with_pos_vocabulary(pos: Option<&str>, expected: &str) {
+        let mut doc = Doc::from_tokens(vec!["B"]);
+        doc.tokens[0].set_pos(pos.map(String::from));
+        let params = TextAugmentParameters::new(1.0, None, None);
+        let pos_vocabulary = HashMap::from([
+            ("JJ".to_string(), vec!["big".to_string()]),
+            ("JJS".to_string(), vec!["biggest".to_string()]),
+            ("VB".to_string(), vec![]),
+        ]);
+        let aug = WordsRandomSubstituteAugmenter::new(params, vec!["A".to_string()], None)
+            .with_pos_vocabulary(pos_vocabulary);
+
+        doc = aug.augment_inner(doc, &mut rand::thread_rng());
+
+        assert_eq!(doc.to_string(), expected);
+    }
+
+    #[test_case(0.5, vec![] ; "empty vocabulary")]
+    #[test_case(1.5, vec!["A"] ; "invalid probability")]
+    fn test_try_new_invalid(p: f32, vocab: Vec<&str>) {
+        let params = TextAugmentParameters::new(p, None, None);
+        let vocab: Vec<String> = vocab.into_iter().map(|s| s.to_string()).collect();
+        assert!(WordsRandomSubstituteAugmenter::try_new(params, vocab, None).is_err());
+    }
+
+    #[test_case("HELLO There", Casing::Preserve, "WORLD World" ; "preserve upper and title")]
+    #[test_case("hello iPhone", Casing::Preserve, "world wOrld" ; "preserve lower and mixed")]
+    #[test_case("HELLO there", Casing::AsIs, "World World" ; "as is")]
+    fn test_substitute_casing(text: &str, casing: Casing, expected: &str) {
+        let aug = WordsRandomSubstituteAugmenter::new(
+            TextAugmentParameters::new(1.0, None, None),
+            vec!["World".to_string()],
+            None,
+        )
+        .with_casing(casing);
+
+        let output = aug.augment(text.to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, expected);
+    }
 }
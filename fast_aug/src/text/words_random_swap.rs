@@ -1,6 +1,7 @@
-use super::base::BaseTextAugmenter;
-use super::utils::{Doc, TextAugmentParameters};
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Change, Doc, TextAugmentParameters};
 use crate::base::BaseAugmenter;
+use crate::error::Result;
 use std::collections::HashSet;
 
 /// Augmenter that swaps random words in text
@@ -29,6 +30,12 @@ impl WordsRandomSwapAugmenter {
     pub fn new(word_params: TextAugmentParameters, stopwords: Option<HashSet<String>>) -> Self {
         WordsRandomSwapAugmenter { word_params, stopwords }
     }
+
+    /// Create augmenter, returns error if word parameters are invalid
+    pub fn try_new(word_params: TextAugmentParameters, stopwords: Option<HashSet<String>>) -> Result<Self> {
+        word_params.validate()?;
+        Ok(WordsRandomSwapAugmenter::new(word_params, stopwords))
+    }
 }
 
 impl BaseTextAugmenter for WordsRandomSwapAugmenter {}
@@ -47,15 +54,33 @@ impl BaseAugmenter<String, Doc> for WordsRandomSwapAugmenter {
             let idx_a = indexes.first().unwrap();
             let idx_b = indexes.last().unwrap();
             input.swap_tokens_by_index(*idx_a, *idx_b);
-            input.num_changes += 1;
+            input.record_change(|tokens| {
+                Change::new(
+                    "WordsRandomSwapAugmenter",
+                    TextAction::Swap,
+                    Some(*idx_a),
+                    Some(*idx_b),
+                    tokens[*idx_b].token(),
+                    tokens[*idx_a].token(),
+                )
+            });
         }
 
         // If odd number of tokens, swap last with first
-        if selected_tokens_indexes.len() % 2 != 0 {
+        if !selected_tokens_indexes.len().is_multiple_of(2) {
             let last_idx = selected_tokens_indexes.last().unwrap();
             let first_idx = selected_tokens_indexes.first().unwrap();
             input.swap_tokens_by_index(*last_idx, *first_idx);
-            input.num_changes += 1;
+            input.record_change(|tokens| {
+                Change::new(
+                    "WordsRandomSwapAugmenter",
+                    TextAction::Swap,
+                    Some(*last_idx),
+                    Some(*first_idx),
+                    tokens[*first_idx].token(),
+                    tokens[*last_idx].token(),
+                )
+            });
         }
 
         input
@@ -1,39 +1,101 @@
-#![allow(dead_code, unused_variables, unreachable_code, unused_imports)]
-
 use super::base::{BaseTextAugmenter, TextAction};
-use super::utils::{Doc, TextAugmentParameters};
+use super::utils::{Casing, Change, Doc, TextAugmentParameters};
 use crate::base::BaseAugmenter;
+use crate::error::Result;
+use crate::models::text::SpellingModel;
 use std::collections::HashSet;
 
+/// Augmenter that substitutes random words with their common misspellings
+/// Only words present in the spelling model dictionary can be changed, original casing is kept
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::models::text::SpellingModel;
+/// use fast_aug::text::{WordsSpellingAugmenter, TextAugmentParameters};
+///
+/// let rng = &mut rand::thread_rng();
+/// let spelling_model = SpellingModel::from_txt_str("because becuase becasue\nwhich wich");
+/// let augmenter = WordsSpellingAugmenter::new(
+///     TextAugmentParameters::new(0.5, None, None),
+///     spelling_model,
+///     None,
+/// );
+/// augmenter.augment("Because of some text!".to_string(), rng);
+/// augmenter.augment_batch(vec!["Because of some text!".to_string()], rng);
+/// ```
 pub struct WordsSpellingAugmenter {
-    /// Action to augmentation, set of values {'substitute', 'swap', 'delete'}
+    /// Action to augmentation, set of values {'substitute'}
     action: TextAction,
     /// Parameters to calculate number of words that will be augmented
     word_params: TextAugmentParameters,
+    /// Spelling Model with misspellings dictionary
+    spelling_model: SpellingModel,
     /// Filter, Set of words that cannot be augmented
     stopwords: Option<HashSet<String>>,
+    /// Casing of the new words, see `Casing`
+    casing: Casing,
 }
 
 impl WordsSpellingAugmenter {
-    pub fn new(word_params: TextAugmentParameters, stopwords: Option<HashSet<String>>) -> Self {
+    pub fn new(
+        word_params: TextAugmentParameters,
+        spelling_model: SpellingModel,
+        stopwords: Option<HashSet<String>>,
+    ) -> Self {
         WordsSpellingAugmenter {
             action: TextAction::Substitute,
             word_params,
+            spelling_model,
             stopwords,
+            casing: Casing::Preserve,
         }
     }
 
+    /// Create augmenter, returns error if word parameters are invalid
+    pub fn try_new(
+        word_params: TextAugmentParameters,
+        spelling_model: SpellingModel,
+        stopwords: Option<HashSet<String>>,
+    ) -> Result<Self> {
+        word_params.validate()?;
+        Ok(WordsSpellingAugmenter::new(word_params, spelling_model, stopwords))
+    }
+
+    /// Set casing of the new words, matches casing of the original words by default
+    pub fn with_casing(mut self, casing: Casing) -> Self {
+        self.casing = casing;
+        self
+    }
+
     fn substitute(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
-        // Select random word tokens
+        // Select random word tokens, only words with known misspellings can be selected
         let word_tokens_indexes = doc.get_word_indexes(false, self.stopwords.as_ref());
         let num_tokens_to_change = self.word_params.num_elements(word_tokens_indexes.len());
+        let candidate_tokens_indexes = word_tokens_indexes
+            .into_iter()
+            .filter(|&idx| self.spelling_model.get_misspellings(doc.tokens[idx].token()).is_some())
+            .collect::<Vec<usize>>();
         let selected_tokens_indexes =
-            self.select_random_element_indexes(rng, word_tokens_indexes, num_tokens_to_change);
+            self.select_random_element_indexes(rng, candidate_tokens_indexes, num_tokens_to_change);
 
-        // For all selected tokens randomly introduce spelling mistakes
+        // For all selected tokens substitute with random misspelling keeping the casing
         for index in selected_tokens_indexes {
-            todo!();
-            doc.num_changes += 1;
+            let token = &mut doc.tokens[index];
+            if let Some(misspelling) = self.spelling_model.get_random_misspelling(token.token(), rng) {
+                let new_token = self.casing.apply(token.token(), misspelling, rng);
+                let old_token = token.change(&new_token, *token.kind());
+                doc.record_change(|tokens| {
+                    Change::new(
+                        "WordsSpellingAugmenter",
+                        TextAction::Substitute,
+                        Some(index),
+                        Some(index),
+                        &old_token,
+                        tokens[index].token(),
+                    )
+                });
+            }
         }
 
         doc
@@ -63,4 +125,61 @@ impl BaseAugmenter<String, Doc> for WordsSpellingAugmenter {
 mod tests {
     use super::*;
     use test_case::test_case;
+
+    const SPELLING_DICTIONARY: &str = "because becuase\nwhich wich\nthe teh\nuntil untill";
+
+    #[test_case(vec!["because", " ", "which", " ", "the", " ", "until"], 0.5, 2 ; "round 2 of 4 words")]
+    #[test_case(vec!["because", " ", "which", " ", "the", " ", "until"], 1.0, 4 ; "all words")]
+    #[test_case(vec!["because", " ", "which", " ", "the", " ", "until"], 0.0, 0 ; "substitute probability=0")]
+    #[test_case(vec!["because", " ", "car", " ", "tree", " ", "house"], 1.0, 1 ; "only 1 word in dictionary")]
+    #[test_case(vec!["car", " ", "tree", " ", "house", "!"], 1.0, 0 ; "no words in dictionary")]
+    fn test_substitute(input_tokens: Vec<&str>, p: f32, expected_doc_changes: usize) {
+        let mut doc = Doc::from_tokens(input_tokens);
+        let params = TextAugmentParameters::new(p, None, None);
+        let aug = WordsSpellingAugmenter::new(params, SpellingModel::from_txt_str(SPELLING_DICTIONARY), None);
+
+        let doc_tokens_before = doc.tokens.clone();
+
+        doc = aug.augment_inner(doc, &mut rand::thread_rng());
+
+        let doc_tokens_after = doc.tokens.clone();
+
+        assert_eq!(doc_tokens_before.len(), doc_tokens_after.len());
+        assert_eq!(doc.num_changes, expected_doc_changes);
+        let num_changed_words = doc_tokens_before
+            .iter()
+            .zip(doc_tokens_after.iter())
+            .filter(|(before, after)| before != after)
+            .count();
+        assert_eq!(num_changed_words, expected_doc_changes);
+    }
+
+    #[test]
+    fn test_substitute_with_stopwords() {
+        let params = TextAugmentParameters::new(1.0, None, None);
+        let stopwords = HashSet::from(["the".to_string()]);
+        let aug = WordsSpellingAugmenter::new(
+            params,
+            SpellingModel::from_txt_str(SPELLING_DICTIONARY),
+            Some(stopwords),
+        );
+
+        let output = aug.augment("the because".to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, "the becuase");
+    }
+
+    #[test_case("because", "becuase" ; "lowercase")]
+    #[test_case("Because", "Becuase" ; "title case")]
+    #[test_case("BECAUSE", "BECUASE" ; "uppercase")]
+    #[test_case("The", "Teh" ; "short title case")]
+    #[test_case("THE", "TEH" ; "short uppercase")]
+    fn test_substitute_keeps_casing(text: &str, expected: &str) {
+        let params = TextAugmentParameters::new(1.0, None, None);
+        let aug = WordsSpellingAugmenter::new(params, SpellingModel::from_txt_str(SPELLING_DICTIONARY), None);
+
+        let output = aug.augment(text.to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, expected);
+    }
 }
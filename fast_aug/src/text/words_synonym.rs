@@ -0,0 +1,265 @@
+use super::base::{BaseTextAugmenter, TextAction};
+use super::utils::{Casing, Change, Doc, TextAugmentParameters, Token};
+use crate::base::BaseAugmenter;
+use crate::error::Result;
+use crate::models::text::WordNetModel;
+use rand::seq::SliceRandom;
+use std::collections::HashSet;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Augmenter that substitutes random words with their synonyms (or antonyms) from WordNet
+/// Only words present in the WordNet model can be changed, original casing is kept.
+/// Multi-word lemmas (e.g. "hot dog") are split into word and space tokens.
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::models::text::WordNetModel;
+/// use fast_aug::text::{WordsSynonymAugmenter, TextAugmentParameters};
+///
+/// let rng = &mut rand::thread_rng();
+/// let wordnet_model = WordNetModel::new(vec![vec!["quick".to_string(), "fast".to_string()]], vec![]);
+/// let augmenter = WordsSynonymAugmenter::new(
+///     TextAugmentParameters::new(1.0, None, None),
+///     wordnet_model,
+///     None,
+///     false,
+/// );
+/// assert_eq!(augmenter.augment("Quick fox!".to_string(), rng), "Fast fox!");
+/// ```
+pub struct WordsSynonymAugmenter {
+    /// Action to augmentation, set of values {'substitute'}
+    action: TextAction,
+    /// Parameters to calculate number of words that will be augmented
+    word_params: TextAugmentParameters,
+    /// WordNet Model with synsets and antonyms
+    wordnet_model: WordNetModel,
+    /// Filter, Set of words that cannot be augmented
+    stopwords: Option<HashSet<String>>,
+    /// Substitute words with antonyms instead of synonyms
+    antonyms: bool,
+    /// Casing of the new words, see `Casing`
+    casing: Casing,
+}
+
+impl WordsSynonymAugmenter {
+    pub fn new(
+        word_params: TextAugmentParameters,
+        wordnet_model: WordNetModel,
+        stopwords: Option<HashSet<String>>,
+        antonyms: bool,
+    ) -> Self {
+        WordsSynonymAugmenter {
+            action: TextAction::Substitute,
+            word_params,
+            wordnet_model,
+            stopwords,
+            antonyms,
+            casing: Casing::Preserve,
+        }
+    }
+
+    /// Create augmenter, returns error if word parameters are invalid
+    pub fn try_new(
+        word_params: TextAugmentParameters,
+        wordnet_model: WordNetModel,
+        stopwords: Option<HashSet<String>>,
+        antonyms: bool,
+    ) -> Result<Self> {
+        word_params.validate()?;
+        Ok(WordsSynonymAugmenter::new(
+            word_params,
+            wordnet_model,
+            stopwords,
+            antonyms,
+        ))
+    }
+
+    /// Set casing of the new words, matches casing of the original words by default
+    pub fn with_casing(mut self, casing: Casing) -> Self {
+        self.casing = casing;
+        self
+    }
+
+    /// Get synonyms or antonyms of the word, depending on the augmenter settings
+    fn get_candidates(&self, word: &str) -> Vec<&String> {
+        if self.antonyms {
+            self.wordnet_model.get_antonyms(word)
+        } else {
+            self.wordnet_model.get_synonyms(word)
+        }
+    }
+
+    fn substitute(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        // Select random word tokens, only words with known synonyms (antonyms) can be selected
+        let word_tokens_indexes = doc.get_word_indexes(false, self.stopwords.as_ref());
+        let num_tokens_to_change = self.word_params.num_elements(word_tokens_indexes.len());
+        let candidate_tokens_indexes = word_tokens_indexes
+            .into_iter()
+            .filter(|&idx| !self.get_candidates(doc.tokens[idx].token()).is_empty())
+            .collect::<Vec<usize>>();
+        let mut selected_tokens_indexes =
+            self.select_random_element_indexes(rng, candidate_tokens_indexes, num_tokens_to_change);
+        selected_tokens_indexes.sort_unstable();
+
+        // Multi-word lemmas add tokens, so following selected tokens are shifted by the number of added tokens
+        let mut num_added_tokens = 0;
+        for selected_index in selected_tokens_indexes {
+            let index = selected_index + num_added_tokens;
+            let old_token = doc.tokens[index].token().clone();
+            let Some(lemma) = self
+                .get_candidates(&old_token)
+                .choose(rng)
+                .map(|lemma| lemma.to_string())
+            else {
+                continue;
+            };
+            let new_word = self.casing.apply(&old_token, &lemma, rng);
+
+            // New tokens are aligned with the original span of the substituted word
+            let original_span = doc.tokens[index].original_span();
+            let new_tokens = new_word
+                .split_word_bounds()
+                .map(|token| {
+                    let mut token = Token::from_str(token);
+                    if let Some((start, end)) = original_span {
+                        token.set_original_span(start, end);
+                    }
+                    token
+                })
+                .collect::<Vec<Token>>();
+            num_added_tokens += new_tokens.len() - 1;
+            doc.tokens.splice(index..=index, new_tokens);
+
+            doc.record_change(|_| {
+                Change::new(
+                    "WordsSynonymAugmenter",
+                    TextAction::Substitute,
+                    Some(index),
+                    Some(index),
+                    &old_token,
+                    &new_word,
+                )
+            });
+        }
+
+        doc
+    }
+}
+
+impl BaseTextAugmenter for WordsSynonymAugmenter {}
+
+impl BaseAugmenter<String, Doc> for WordsSynonymAugmenter {
+    fn augment_inner(&self, input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        match self.action {
+            TextAction::Substitute => self.substitute(input, rng),
+            _ => panic!("Action not implemented"),
+        }
+    }
+
+    fn convert_to_inner(&self, input: String) -> Doc {
+        Doc::new(&input)
+    }
+
+    fn convert_to_outer(&self, input: Doc) -> String {
+        input.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::{AugmentWithChanges, TokenType};
+    use test_case::test_case;
+
+    const WORDNET_EN_PATH: &str = "../test_data/wordnet_en";
+
+    fn augmenter(p: f32, antonyms: bool) -> WordsSynonymAugmenter {
+        let params = TextAugmentParameters::new(p, None, None);
+        WordsSynonymAugmenter::new(
+            params,
+            WordNetModel::from_path(WORDNET_EN_PATH).unwrap(),
+            None,
+            antonyms,
+        )
+    }
+
+    #[test_case("cat", false, "true cat" ; "synonym")]
+    #[test_case("Cat", false, "True cat" ; "title case")]
+    #[test_case("CAT", false, "TRUE CAT" ; "uppercase")]
+    #[test_case("Good day", true, "Bad day" ; "antonym")]
+    #[test_case("slow woods", true, "fast woods" ; "antonym only for words with antonyms")]
+    #[test_case("table", false, "table" ; "unknown word")]
+    fn test_substitute(input: &str, antonyms: bool, expected: &str) {
+        let aug = augmenter(1.0, antonyms);
+
+        let output = aug.augment(input.to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_substitute_multi_word_lemma() {
+        let aug = augmenter(1.0, false);
+
+        let doc = aug.augment_inner(Doc::new("cat and hotdog"), &mut rand::thread_rng());
+
+        assert_eq!(doc.to_string(), "true cat and hot dog");
+        assert_eq!(
+            doc.tokens.iter().map(|token| *token.kind()).collect::<Vec<TokenType>>(),
+            vec![
+                TokenType::Word,
+                TokenType::Space,
+                TokenType::Word,
+                TokenType::Space,
+                TokenType::Word,
+                TokenType::Space,
+                TokenType::Word,
+                TokenType::Space,
+                TokenType::Word,
+            ]
+        );
+        assert_eq!(doc.tokens[2].original_span(), Some((0, 3)));
+        assert_eq!(doc.tokens[8].original_span(), Some((8, 14)));
+    }
+
+    #[test]
+    fn test_augment_with_changes() {
+        let aug = augmenter(1.0, false);
+
+        let (output, changes) = aug.augment_with_changes("cat or hotdog".to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, "true cat or hot dog");
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            (changes[0].original_index, changes[0].new_text.as_str()),
+            (Some(0), "true cat")
+        );
+        assert_eq!(
+            (changes[1].original_index, changes[1].new_text.as_str()),
+            (Some(6), "hot dog")
+        );
+    }
+
+    #[test_case(0.5, 2 ; "round 2 of 4 words")]
+    #[test_case(0.0, 0 ; "substitute probability=0")]
+    fn test_substitute_num_changes(p: f32, expected_doc_changes: usize) {
+        let aug = augmenter(p, false);
+
+        let doc = aug.augment_inner(Doc::new("dog cat forest quick"), &mut rand::thread_rng());
+
+        assert_eq!(doc.num_changes, expected_doc_changes);
+    }
+
+    #[test]
+    fn test_substitute_with_stopwords() {
+        let params = TextAugmentParameters::new(1.0, None, None);
+        let stopwords = HashSet::from(["cat".to_string()]);
+        let wordnet_model = WordNetModel::from_path(WORDNET_EN_PATH).unwrap();
+        let aug = WordsSynonymAugmenter::new(params, wordnet_model, Some(stopwords), false);
+
+        let output = aug.augment("cat quick".to_string(), &mut rand::thread_rng());
+
+        assert_eq!(output, "cat fast");
+    }
+}
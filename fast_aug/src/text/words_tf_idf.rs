@@ -1,42 +1,172 @@
-#![allow(dead_code, unused_variables, unreachable_code, unused_imports)]
-
 use super::base::{BaseTextAugmenter, TextAction};
-use super::utils::{Doc, TextAugmentParameters};
+use super::utils::{Casing, Change, Doc, TextAugmentParameters, Token, TokenType};
 use crate::base::BaseAugmenter;
+use crate::error::{Error, Result};
+use crate::models::text::TfIdfModel;
+use rand::seq::SliceRandom;
 use std::collections::HashSet;
 
+/// Augmenter that substitutes or inserts uninformative (low TF-IDF) words, following UDA paper
+/// Words with low TF-IDF score are more likely to be changed, so the words carrying the label stay untouched.
+/// New words are sampled from TF-IDF model vocabulary, with preference for low IDF words.
+/// * `TextAction::Substitute` - replace low TF-IDF words with sampled words
+/// * `TextAction::Insert` - insert sampled words before low TF-IDF words
+///
+/// # Examples
+/// ```rust
+/// use fast_aug::base::BaseAugmenter;
+/// use fast_aug::models::text::TfIdfModel;
+/// use fast_aug::text::{WordsTfIdfAugmenter, TextAction, TextAugmentParameters};
+///
+/// let rng = &mut rand::thread_rng();
+/// let tf_idf_model = TfIdfModel::fit(vec!["Some text!", "Some other text.", "One more document"]);
+/// let augmenter = WordsTfIdfAugmenter::new(
+///     TextAction::Substitute,
+///     TextAugmentParameters::new(0.5, None, None),
+///     tf_idf_model,
+///     None,
+/// );
+/// augmenter.augment("Some text!".to_string(), rng);
+/// augmenter.augment_batch(vec!["Some text!".to_string()], rng);
+/// ```
 pub struct WordsTfIdfAugmenter {
-    /// Action to augmentation, set of values {'substitute', 'swap', 'delete'}
+    /// Action to augmentation, set of values {'substitute', 'insert'}
     action: TextAction,
     /// Parameters to calculate number of words that will be augmented
     word_params: TextAugmentParameters,
+    /// TF-IDF Model to score words and sample new ones
+    tf_idf_model: TfIdfModel,
     /// Filter, Set of words that cannot be augmented
     stopwords: Option<HashSet<String>>,
-    /// top k similar words to substitute
-    top_k: usize,
+    /// Casing of the new words, see `Casing`
+    casing: Casing,
 }
 
 impl WordsTfIdfAugmenter {
-    pub fn new(word_params: TextAugmentParameters, stopwords: Option<HashSet<String>>, top_k: usize) -> Self {
+    /// Create augmenter, action and parameters are not validated, see `try_new`
+    pub fn new(
+        action: TextAction,
+        word_params: TextAugmentParameters,
+        tf_idf_model: TfIdfModel,
+        stopwords: Option<HashSet<String>>,
+    ) -> Self {
         WordsTfIdfAugmenter {
-            action: TextAction::Substitute,
+            action,
             word_params,
+            tf_idf_model,
             stopwords,
-            top_k,
+            casing: Casing::Preserve,
         }
     }
 
-    fn substitute(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
-        // Select random word tokens
+    /// Create augmenter, returns error if action is not Substitute or Insert, or parameters are invalid
+    pub fn try_new(
+        action: TextAction,
+        word_params: TextAugmentParameters,
+        tf_idf_model: TfIdfModel,
+        stopwords: Option<HashSet<String>>,
+    ) -> Result<Self> {
+        if action != TextAction::Substitute && action != TextAction::Insert {
+            return Err(Error::UnsupportedAction(format!(
+                "WordsTfIdfAugmenter supports only Substitute and Insert, got {:?}",
+                action
+            )));
+        }
+        word_params.validate()?;
+        Ok(Self::new(action, word_params, tf_idf_model, stopwords))
+    }
+
+    /// Set casing of the new words, matches casing of the original words by default
+    pub fn with_casing(mut self, casing: Casing) -> Self {
+        self.casing = casing;
+        self
+    }
+
+    /// Select word tokens to augment, probability of selection is proportional to `max_score - score`
+    fn select_low_tf_idf_word_indexes(&self, doc: &mut Doc, rng: &mut dyn rand::RngCore) -> Vec<usize> {
         let word_tokens_indexes = doc.get_word_indexes(false, self.stopwords.as_ref());
         let num_tokens_to_change = self.word_params.num_elements(word_tokens_indexes.len());
-        let selected_tokens_indexes =
-            self.select_random_element_indexes(rng, word_tokens_indexes, num_tokens_to_change);
+        if num_tokens_to_change == 0 || self.tf_idf_model.is_empty() {
+            return Vec::new();
+        }
+
+        let scores = self.tf_idf_model.get_tf_idf_scores(doc, &word_tokens_indexes);
+        let max_score = scores.iter().copied().fold(0.0, f32::max);
+        let weighted_indexes = word_tokens_indexes
+            .into_iter()
+            .zip(scores)
+            .map(|(idx, score)| (idx, max_score - score + 1e-6))
+            .collect::<Vec<(usize, f32)>>();
+
+        // Scores of models with invalid idf values (see `TfIdfModel::try_new`) can not be used as weights,
+        // words are selected uniformly then
+        match weighted_indexes.choose_multiple_weighted(rng, num_tokens_to_change, |(_, weight)| *weight) {
+            Ok(selected) => selected.map(|(idx, _)| *idx).collect(),
+            Err(_) => {
+                let indexes = weighted_indexes.into_iter().map(|(idx, _)| idx).collect();
+                self.select_random_element_indexes(rng, indexes, num_tokens_to_change)
+            }
+        }
+    }
+
+    fn substitute(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        let selected_tokens_indexes = self.select_low_tf_idf_word_indexes(&mut doc, rng);
 
-        // For all selected tokens randomly select one word with closest tf-idf and substitute
+        // For all selected tokens sample new word from the vocabulary and substitute
         for index in selected_tokens_indexes {
-            todo!();
-            doc.num_changes += 1;
+            if let Some(new_word) = self.tf_idf_model.get_random_word(rng) {
+                let token = &mut doc.tokens[index];
+                let new_word = self.casing.apply(token.token(), new_word, rng);
+                let old_token = token.change(&new_word, *token.kind());
+                doc.record_change(|tokens| {
+                    Change::new(
+                        "WordsTfIdfAugmenter",
+                        TextAction::Substitute,
+                        Some(index),
+                        Some(index),
+                        &old_token,
+                        tokens[index].token(),
+                    )
+                });
+            }
+        }
+
+        doc
+    }
+
+    fn insert(&self, mut doc: Doc, rng: &mut dyn rand::RngCore) -> Doc {
+        let mut selected_tokens_indexes = self.select_low_tf_idf_word_indexes(&mut doc, rng);
+
+        // Insert new word and space before each selected token, going from the end to keep indexes valid
+        selected_tokens_indexes.sort_unstable_by(|a, b| b.cmp(a));
+        let mut inserted_tokens_indexes: Vec<usize> = Vec::with_capacity(selected_tokens_indexes.len());
+        for index in selected_tokens_indexes {
+            if let Some(new_word) = self.tf_idf_model.get_random_word(rng) {
+                let new_word = self.casing.apply_inserted(doc.tokens[index].token(), new_word, rng);
+                doc.tokens.splice(
+                    index..index,
+                    [
+                        Token::new(&new_word, TokenType::Word),
+                        Token::new(" ", TokenType::Space),
+                    ],
+                );
+                inserted_tokens_indexes.push(index);
+            }
+        }
+
+        // Each insertion shifts the words inserted after it by 2 tokens (word and space)
+        for (num_inserted_before, index) in inserted_tokens_indexes.into_iter().rev().enumerate() {
+            let new_index = index + 2 * num_inserted_before;
+            doc.record_change(|tokens| {
+                Change::new(
+                    "WordsTfIdfAugmenter",
+                    TextAction::Insert,
+                    None,
+                    Some(new_index),
+                    "",
+                    tokens[new_index].token(),
+                )
+            });
         }
 
         doc
@@ -49,6 +179,7 @@ impl BaseAugmenter<String, Doc> for WordsTfIdfAugmenter {
     fn augment_inner(&self, input: Doc, rng: &mut dyn rand::RngCore) -> Doc {
         match self.action {
             TextAction::Substitute => self.substitute(input, rng),
+            TextAction::Insert => self.insert(input, rng),
             _ => panic!("Action not implemented"),
         }
     }
@@ -65,5 +196,107 @@ impl BaseAugmenter<String, Doc> for WordsTfIdfAugmenter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
     use test_case::test_case;
+
+    const CORPUS: [&str; 5] = [
+        "the movie was great",
+        "the movie was awful",
+        "the plot is a mess",
+        "the actors are great",
+        "a film to remember",
+    ];
+
+    #[test_case(vec!["the", " ", "movie", " ", "was", " ", "great"], 0.5, 2 ; "2 of 4 words")]
+    #[test_case(vec!["the", " ", "movie", " ", "was", " ", "great"], 1.0, 4 ; "all words")]
+    #[test_case(vec!["the", " ", "movie", " ", "was", " ", "great"], 0.0, 0 ; "substitute probability=0")]
+    #[test_case(vec!["!", " ", "...", "?"], 1.0, 0 ; "no words")]
+    fn test_substitute(input_tokens: Vec<&str>, p: f32, expected_doc_changes: usize) {
+        let mut doc = Doc::from_tokens(input_tokens);
+        let params = TextAugmentParameters::new(p, None, None);
+        let aug = WordsTfIdfAugmenter::new(TextAction::Substitute, params, TfIdfModel::fit(CORPUS), None);
+
+        let doc_tokens_before = doc.tokens.clone();
+
+        doc = aug.augment_inner(doc, &mut rand::thread_rng());
+
+        let doc_tokens_after = doc.tokens.clone();
+
+        assert_eq!(doc_tokens_before.len(), doc_tokens_after.len());
+        assert_eq!(doc.num_changes, expected_doc_changes);
+        for token in doc_tokens_after.iter().filter(|token| token.kind() == &TokenType::Word) {
+            assert!(CORPUS.iter().any(|document| document.contains(token.token().as_str())));
+        }
+    }
+
+    #[test_case(vec!["the", " ", "movie", " ", "was", " ", "great"], 0.5, 11, 2 ; "2 of 4 words")]
+    #[test_case(vec!["the", " ", "movie", " ", "was", " ", "great"], 1.0, 15, 4 ; "all words")]
+    #[test_case(vec!["the", " ", "movie", " ", "was", " ", "great"], 0.0, 7, 0 ; "insert probability=0")]
+    #[test_case(vec!["!", " ", "...", "?"], 1.0, 4, 0 ; "no words")]
+    fn test_insert(input_tokens: Vec<&str>, p: f32, expected_len: usize, expected_doc_changes: usize) {
+        let mut doc = Doc::from_tokens(input_tokens);
+        let params = TextAugmentParameters::new(p, None, None);
+        let aug = WordsTfIdfAugmenter::new(TextAction::Insert, params, TfIdfModel::fit(CORPUS), None);
+
+        let text_before = doc.to_string();
+
+        doc = aug.augment_inner(doc, &mut rand::thread_rng());
+
+        assert_eq!(doc.tokens.len(), expected_len);
+        assert_eq!(doc.num_changes, expected_doc_changes);
+        let text_after = doc.to_string();
+        for word in text_before.split_whitespace() {
+            assert!(text_after.split_whitespace().any(|w| w == word));
+        }
+    }
+
+    #[test]
+    fn test_substitute_prefers_uninformative_words() {
+        let params = TextAugmentParameters::new(0.5, None, None);
+        let aug = WordsTfIdfAugmenter::new(TextAction::Substitute, params, TfIdfModel::fit(CORPUS), None);
+        let rng = &mut rand::thread_rng();
+
+        let num_kept_informative = (0..200)
+            .filter(|_| aug.augment("the remember".to_string(), rng).ends_with("remember"))
+            .count();
+
+        assert!(num_kept_informative > 100);
+    }
+
+    #[test]
+    fn test_substitute_with_stopwords() {
+        let params = TextAugmentParameters::new(1.0, None, None);
+        let stopwords = HashSet::from(["movie".to_string()]);
+        let aug = WordsTfIdfAugmenter::new(TextAction::Substitute, params, TfIdfModel::fit(CORPUS), Some(stopwords));
+
+        let output = aug.augment("the movie".to_string(), &mut rand::thread_rng());
+
+        assert!(output.ends_with(" movie"));
+    }
+
+    #[test]
+    fn test_select_with_invalid_idf_is_uniform() {
+        let idf = HashMap::from([("the".to_string(), 1.0), ("movie".to_string(), f32::NAN)]);
+        let params = TextAugmentParameters::new(0.5, None, None);
+        let aug = WordsTfIdfAugmenter::new(TextAction::Substitute, params, TfIdfModel::new(idf, 2), None);
+        let mut doc = Doc::new("the movie was great");
+
+        let selected_indexes = aug.select_low_tf_idf_word_indexes(&mut doc, &mut rand::thread_rng());
+
+        assert_eq!(selected_indexes.len(), 2);
+        assert!(selected_indexes
+            .iter()
+            .all(|&idx| doc.tokens[idx].kind() == &TokenType::Word));
+    }
+
+    #[test]
+    fn test_unsupported_action() {
+        let result = WordsTfIdfAugmenter::try_new(
+            TextAction::Swap,
+            TextAugmentParameters::default(),
+            TfIdfModel::fit(CORPUS),
+            None,
+        );
+        assert!(matches!(result, Err(Error::UnsupportedAction(_))));
+    }
 }